@@ -0,0 +1,17 @@
+-- Long-term NPC memories. Shared by the SQLite and Postgres stores, so only
+-- types both understand are used: ids are UUID strings, times are sim seconds.
+CREATE TABLE IF NOT EXISTS npc_memories (
+    id TEXT PRIMARY KEY,
+    npc_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    content TEXT NOT NULL,
+    importance REAL NOT NULL,
+    emotional_value REAL NOT NULL,
+    created_at REAL NOT NULL,
+    -- JSON array of linked memory ids
+    links TEXT NOT NULL,
+    -- The full memory as JSON; the columns above are for querying
+    data TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS npc_memories_by_npc ON npc_memories (npc_id, created_at);
@@ -0,0 +1,6 @@
+-- What each NPC has learned makes a memory important: a JSON object of
+-- term to weight, replaced whole on every save.
+CREATE TABLE IF NOT EXISTS npc_importance_weights (
+    npc_id TEXT PRIMARY KEY,
+    weights TEXT NOT NULL
+);
@@ -0,0 +1,141 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+/// Biases weaker than this don't colour anything
+const ACTIVE_THRESHOLD: f32 = 0.1;
+/// How fast a triggered bias settles back to its baseline, per second
+const SETTLE_RATE: f32 = 0.05;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BiasSystem {
+    biases: Vec<CognitiveBias>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CognitiveBias {
+    name: String,
+    /// Words that set it off when perceived
+    triggers: Vec<String>,
+    /// Context values it pushes, and by how much at full strength
+    context_shift: HashMap<String, f32>,
+    baseline: f32,
+    strength: f32,
+}
+
+impl CognitiveBias {
+    pub fn new(name: &str, baseline: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            triggers: Vec::new(),
+            context_shift: HashMap::new(),
+            baseline: baseline.clamp(0.0, 1.0),
+            strength: baseline.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn triggered_by(mut self, word: &str) -> Self {
+        self.triggers.push(word.to_lowercase());
+        self
+    }
+
+    pub fn shifts(mut self, key: &str, amount: f32) -> Self {
+        self.context_shift.insert(key.to_string(), amount);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    fn active(&self) -> bool {
+        self.strength >= ACTIVE_THRESHOLD
+    }
+}
+
+impl Default for BiasSystem {
+    fn default() -> Self {
+        Self {
+            biases: vec![
+                CognitiveBias::new("negativity", 0.2)
+                    .triggered_by("danger")
+                    .triggered_by("threat")
+                    .triggered_by("loss")
+                    .shifts("risk", 0.3),
+                CognitiveBias::new("optimism", 0.1)
+                    .triggered_by("success")
+                    .triggered_by("gift")
+                    .shifts("risk", -0.2),
+                CognitiveBias::new("availability", 0.1)
+                    .triggered_by("recent")
+                    .shifts("urgency", 0.2),
+            ],
+        }
+    }
+}
+
+impl BiasSystem {
+    pub fn update(&mut self, delta_time: f32) {
+        for bias in &mut self.biases {
+            let settle = (SETTLE_RATE * delta_time).min(1.0);
+            bias.strength += (bias.baseline - bias.strength) * settle;
+        }
+    }
+
+    pub fn add_bias(&mut self, bias: CognitiveBias) {
+        self.biases.push(bias);
+    }
+
+    /// Strengthens biases the input triggers and notes which ones coloured it.
+    pub fn apply_biases(&mut self, input: &str) -> String {
+        let lowered = input.to_lowercase();
+        let mut coloured = Vec::new();
+        for bias in &mut self.biases {
+            if bias.triggers.iter().any(|trigger| lowered.contains(trigger.as_str())) {
+                bias.strength = (bias.strength + 0.2).clamp(0.0, 1.0);
+                coloured.push(bias.name.clone());
+            }
+        }
+
+        if coloured.is_empty() {
+            input.to_string()
+        } else {
+            format!("{} ({})", input, coloured.join(", "))
+        }
+    }
+
+    /// Pushes decision context the way active biases lean.
+    pub fn influence_context(&self, mut context: HashMap<String, f32>) -> HashMap<String, f32> {
+        for bias in self.biases.iter().filter(|bias| bias.active()) {
+            for (key, amount) in &bias.context_shift {
+                let value = context.entry(key.clone()).or_insert(0.0);
+                *value = (*value + amount * bias.strength).clamp(-1.0, 1.0);
+            }
+        }
+        context
+    }
+
+    pub fn get_total_bias_influence(&self) -> f32 {
+        let active: Vec<f32> = self.biases
+            .iter()
+            .filter(|bias| bias.active())
+            .map(|bias| bias.strength)
+            .collect();
+        if active.is_empty() {
+            return 0.0;
+        }
+        (active.iter().sum::<f32>() / active.len() as f32 * 0.5).clamp(0.0, 1.0)
+    }
+
+    pub fn get_dominant_bias(&self) -> String {
+        self.biases
+            .iter()
+            .filter(|bias| bias.active())
+            .max_by(|a, b| a.strength.total_cmp(&b.strength))
+            .map(|bias| bias.name.clone())
+            .unwrap_or_else(|| "none".to_string())
+    }
+}
\ No newline at end of file
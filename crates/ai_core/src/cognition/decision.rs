@@ -0,0 +1,489 @@
+use serde::{Serialize, Deserialize};
+use rand::Rng;
+use std::collections::{HashMap, VecDeque};
+
+use super::learning::{situation, LearningSystem};
+use super::trace::{Contribution, DecisionTrace, FactorScore, OptionTrace};
+use super::utility::{UtilityDefinitions, UtilityInputs};
+use crate::personality::PersonalityTraits;
+
+/// Decisions kept with their full reasoning
+const MAX_TRACES: usize = 20;
+/// Pull of a reasoning prior; a certain one (0 or 1) moves the score by 0.2
+const PRIOR_WEIGHT: f32 = 0.4;
+/// How much learned value, -1..1, moves an option's score
+const EXPERIENCE_WEIGHT: f32 = 0.3;
+/// How much the feeling of memories about an option, -1..1, moves its score
+const MEMORY_WEIGHT: f32 = 0.2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionMaker {
+    decision_history: Vec<Decision>,
+    decision_weights: HashMap<String, f32>,
+    current_strategy: DecisionStrategy,
+    uncertainty_threshold: f32,
+    processing_load: f32,
+    /// Options and considerations for utility-based choices
+    #[serde(default)]
+    utility: UtilityDefinitions,
+    #[serde(default)]
+    traces: VecDeque<DecisionTrace>,
+    /// Option values learned from outcomes
+    #[serde(default)]
+    learning: LearningSystem,
+    /// Seconds since the NPC started deciding things
+    #[serde(default)]
+    elapsed: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Decision {
+    options: Vec<String>,
+    chosen: String,
+    context: HashMap<String, f32>,
+    confidence: f32,
+    outcome: Option<DecisionOutcome>,
+    timestamp: f32,
+    /// Coarse context the decision was made in, for learning
+    #[serde(default)]
+    situation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionOutcome {
+    success: bool,
+    impact: f32,
+    feedback: String,
+}
+
+impl DecisionOutcome {
+    /// `impact` is how much it mattered, -1..1.
+    pub fn new(success: bool, impact: f32, feedback: &str) -> Self {
+        Self {
+            success,
+            impact: impact.clamp(-1.0, 1.0),
+            feedback: feedback.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DecisionStrategy {
+    Rational,
+    Intuitive,
+    RiskAverse,
+    RiskSeeking,
+    Balanced,
+}
+
+impl Default for DecisionMaker {
+    fn default() -> Self {
+        Self {
+            decision_history: Vec::new(),
+            decision_weights: HashMap::new(),
+            current_strategy: DecisionStrategy::Balanced,
+            uncertainty_threshold: 0.3,
+            processing_load: 0.0,
+            utility: UtilityDefinitions::default(),
+            traces: VecDeque::new(),
+            learning: LearningSystem::default(),
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl DecisionStrategy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DecisionStrategy::Rational => "rational",
+            DecisionStrategy::Intuitive => "intuitive",
+            DecisionStrategy::RiskAverse => "risk-averse",
+            DecisionStrategy::RiskSeeking => "risk-seeking",
+            DecisionStrategy::Balanced => "balanced",
+        }
+    }
+}
+
+impl DecisionMaker {
+    pub fn update(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+
+        // Decay processing load
+        self.processing_load *= 0.95f32.powf(delta_time);
+        
+        // Adjust strategy based on recent outcomes
+        self.adjust_strategy();
+    }
+
+    pub fn decide(&mut self, options: Vec<String>, context: &HashMap<String, f32>) -> String {
+        self.decide_with_priors(options, context, &HashMap::new(), &HashMap::new())
+    }
+
+    /// Like `decide`, with a prior per option (0..1, 0.5 neutral) from
+    /// reasoning about it nudging its score, and how memories of it feel
+    /// (-1..1) leaning it.
+    pub fn decide_with_priors(
+        &mut self,
+        options: Vec<String>,
+        context: &HashMap<String, f32>,
+        priors: &HashMap<String, f32>,
+        memories: &HashMap<String, f32>,
+    ) -> String {
+        self.processing_load += 0.1;
+
+        let traced: Vec<OptionTrace> = options
+            .iter()
+            .map(|option| {
+                let mut trace = self.trace_option(option, context);
+                if let Some(prior) = priors.get(option) {
+                    let nudge = (prior - 0.5) * PRIOR_WEIGHT;
+                    trace.factors.push(FactorScore::add("reasoning", *prior, nudge));
+                    trace.score = (trace.score + nudge * trace.weight).max(0.0);
+                }
+                trace
+            })
+            .collect();
+        let situation = situation(context);
+        self.choose(self.current_strategy.label(), situation, traced, context.clone(), memories)
+            .unwrap_or_default()
+    }
+
+    /// Picks the option with the highest utility, after what was learned
+    /// from past outcomes. `None` when nothing scores above zero. Need
+    /// urgency is already a consideration, so the need weights used by
+    /// `decide` don't apply here.
+    pub fn decide_by_utility(&mut self, inputs: &UtilityInputs, traits: &PersonalityTraits) -> Option<String> {
+        self.processing_load += 0.1;
+
+        let traced: Vec<OptionTrace> = self.utility
+            .options()
+            .iter()
+            .map(|option| OptionTrace {
+                option: option.name().to_string(),
+                factors: option
+                    .factors(inputs, traits)
+                    .into_iter()
+                    .map(|(factor, value)| FactorScore::scale(&factor, value, value))
+                    .collect(),
+                weight: 1.0,
+                score: option.score(inputs, traits),
+            })
+            .collect();
+        if traced.iter().all(|option| option.score <= 0.0) {
+            return None;
+        }
+
+        let circumstances = inputs.situation();
+        self.choose("utility", situation(&circumstances), traced, circumstances, inputs.memories())
+    }
+
+    /// Adds what memories and experience say about each option, picks one
+    /// (now and then exploring instead of taking the best) and records the
+    /// decision with its trace.
+    fn choose(
+        &mut self,
+        method: &str,
+        situation: String,
+        mut traced: Vec<OptionTrace>,
+        context: HashMap<String, f32>,
+        memories: &HashMap<String, f32>,
+    ) -> Option<String> {
+        self.learning.enter(&situation);
+        for option in &mut traced {
+            if let Some(lean) = memories.get(&option.option) {
+                let amount = lean * MEMORY_WEIGHT;
+                option.factors.push(FactorScore::add("memory", *lean, amount));
+                option.score = (option.score + amount * option.weight).max(0.0);
+            }
+            if self.learning.tries(&situation, &option.option) == 0 {
+                continue;
+            }
+            let learned = self.learning.value(&situation, &option.option);
+            let amount = learned * EXPERIENCE_WEIGHT;
+            option.factors.push(FactorScore::add("experience", learned, amount));
+            option.score = (option.score + amount * option.weight).max(0.0);
+        }
+        // Stable, so ties go to the option listed first
+        traced.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let exploring = traced.len() > 1 && self.learning.should_explore();
+        let index = if exploring { rand::thread_rng().gen_range(1..traced.len()) } else { 0 };
+        let chosen = traced.get(index)?;
+        let (chosen, confidence) = (chosen.option.clone(), self.calculate_confidence(chosen.score.min(1.0)));
+
+        // Record decision
+        let decision = Decision {
+            options: traced.iter().map(|o| o.option.clone()).collect(),
+            chosen: chosen.clone(),
+            context,
+            confidence,
+            outcome: None,
+            timestamp: self.elapsed,
+            situation,
+        };
+        self.decision_history.push(decision);
+        self.record_trace(DecisionTrace {
+            timestamp: self.elapsed,
+            method: if exploring { format!("{}, exploring", method) } else { method.to_string() },
+            options: traced,
+            chosen: chosen.clone(),
+            confidence,
+            thoughts: Vec::new(),
+            memories: Vec::new(),
+            beliefs: Vec::new(),
+        });
+
+        Some(chosen)
+    }
+
+    pub fn set_utility(&mut self, utility: UtilityDefinitions) {
+        self.utility = utility;
+    }
+
+    pub fn utility(&self) -> &UtilityDefinitions {
+        &self.utility
+    }
+
+    /// Recent decisions with their reasoning, oldest first.
+    pub fn traces(&self) -> impl Iterator<Item = &DecisionTrace> {
+        self.traces.iter()
+    }
+
+    pub fn last_trace(&self) -> Option<&DecisionTrace> {
+        self.traces.back()
+    }
+
+    /// Notes the reasoning the last decision was made with.
+    pub fn note_last_thoughts(&mut self, thoughts: Vec<String>) {
+        if let Some(trace) = self.traces.back_mut() {
+            trace.thoughts.extend(thoughts);
+        }
+    }
+
+    /// Notes what was recalled and believed while making the last decision.
+    pub fn annotate_last_trace(&mut self, memories: Vec<String>, beliefs: Vec<(String, f32)>) {
+        if let Some(trace) = self.traces.back_mut() {
+            trace.memories.extend(memories);
+            trace.beliefs.extend(beliefs);
+        }
+    }
+
+    fn record_trace(&mut self, trace: DecisionTrace) {
+        if self.traces.len() >= MAX_TRACES {
+            self.traces.pop_front();
+        }
+        self.traces.push_back(trace);
+    }
+
+    pub fn evaluate_option(&self, option: &str, context: &HashMap<String, f32>) -> f32 {
+        self.trace_option(option, context).score
+    }
+
+    /// Scores an option under the current strategy, keeping each factor's
+    /// part in the score.
+    fn trace_option(&self, option: &str, context: &HashMap<String, f32>) -> OptionTrace {
+        let (base_score, factors) = match self.current_strategy {
+            DecisionStrategy::Rational => self.rational_evaluation(option, context),
+            DecisionStrategy::Intuitive => self.intuitive_evaluation(option, context),
+            DecisionStrategy::RiskAverse => self.risk_averse_evaluation(option, context),
+            DecisionStrategy::RiskSeeking => self.risk_seeking_evaluation(option, context),
+            DecisionStrategy::Balanced => {
+                let (rational, rational_factors) = self.rational_evaluation(option, context);
+                let (intuitive, intuitive_factors) = self.intuitive_evaluation(option, context);
+                let factors = rational_factors
+                    .into_iter()
+                    .chain(intuitive_factors)
+                    .map(|mut factor| {
+                        if let Contribution::Add(amount) = factor.effect {
+                            factor.effect = Contribution::Add(amount / 2.0);
+                        }
+                        factor
+                    })
+                    .collect();
+                ((rational + intuitive) / 2.0, factors)
+            }
+        };
+
+        // Apply decision weights
+        let weight = self.decision_weights.get(option).copied().unwrap_or(1.0);
+        OptionTrace {
+            option: option.to_string(),
+            factors,
+            weight,
+            score: base_score * weight,
+        }
+    }
+
+    /// Multiplies the score of `option` in future decisions.
+    pub fn set_weight(&mut self, option: &str, weight: f32) {
+        self.decision_weights.insert(option.to_string(), weight.max(0.0));
+    }
+
+    /// Attaches how the last decision turned out and learns from it: its
+    /// impact counts as reward, or as penalty when it failed.
+    pub fn record_outcome(&mut self, outcome: DecisionOutcome) {
+        if let Some(last_decision) = self.decision_history.last_mut() {
+            let reward = if outcome.success { outcome.impact } else { -outcome.impact.abs() };
+            self.learning.reward(&last_decision.situation, &last_decision.chosen, reward);
+            last_decision.outcome = Some(outcome);
+        }
+    }
+
+    pub fn learning(&self) -> &LearningSystem {
+        &self.learning
+    }
+
+    pub fn learning_mut(&mut self) -> &mut LearningSystem {
+        &mut self.learning
+    }
+
+    fn rational_evaluation(&self, _option: &str, context: &HashMap<String, f32>) -> (f32, Vec<FactorScore>) {
+        let mut score = 0.5; // Base score
+        let mut factors = Vec::new();
+
+        // Consider context factors
+        for (factor, value) in context {
+            let amount = match factor.as_str() {
+                "risk" => -value * 0.2,
+                "benefit" => value * 0.3,
+                "cost" => -value * 0.25,
+                "time" => -value * 0.15,
+                _ => value * 0.1,
+            };
+            score += amount;
+            factors.push(FactorScore::add(factor, *value, amount));
+        }
+
+        (score.clamp(0.0, 1.0), factors)
+    }
+
+    fn intuitive_evaluation(&self, option: &str, context: &HashMap<String, f32>) -> (f32, Vec<FactorScore>) {
+        let mut score = 0.5;
+        let mut factors = Vec::new();
+
+        // Quick pattern matching from past decisions
+        if let Some(similar_decision) = self.find_similar_decision(option, context) {
+            if let Some(outcome) = &similar_decision.outcome {
+                let amount = if outcome.success { 0.3 } else { -0.2 };
+                score += amount;
+                factors.push(FactorScore::add("past outcome", if outcome.success { 1.0 } else { 0.0 }, amount));
+            }
+        }
+
+        // Consider "gut feeling" factors
+        if let Some(familiarity) = context.get("familiarity") {
+            score += familiarity * 0.2;
+            factors.push(FactorScore::add("familiarity", *familiarity, familiarity * 0.2));
+        }
+
+        (score.clamp(0.0, 1.0), factors)
+    }
+
+    fn risk_averse_evaluation(&self, option: &str, context: &HashMap<String, f32>) -> (f32, Vec<FactorScore>) {
+        let (base_score, mut factors) = self.rational_evaluation(option, context);
+        let risk_factor = context.get("risk").unwrap_or(&0.5);
+        factors.push(FactorScore::scale("risk aversion", *risk_factor, 1.0 - risk_factor));
+
+        (base_score * (1.0 - risk_factor), factors)
+    }
+
+    fn risk_seeking_evaluation(&self, option: &str, context: &HashMap<String, f32>) -> (f32, Vec<FactorScore>) {
+        let (base_score, mut factors) = self.rational_evaluation(option, context);
+        let potential_gain = context.get("benefit").unwrap_or(&0.5);
+        factors.push(FactorScore::scale("appetite for gain", *potential_gain, 1.0 + potential_gain));
+
+        (base_score * (1.0 + potential_gain), factors)
+    }
+
+    fn find_similar_decision(&self, option: &str, context: &HashMap<String, f32>) -> Option<&Decision> {
+        self.decision_history.iter()
+            .filter(|d| d.chosen == option)
+            .max_by(|a, b| {
+                let a_similarity = self.calculate_context_similarity(&a.context, context);
+                let b_similarity = self.calculate_context_similarity(&b.context, context);
+                a_similarity.partial_cmp(&b_similarity).unwrap()
+            })
+    }
+
+    fn calculate_context_similarity(&self, context1: &HashMap<String, f32>, context2: &HashMap<String, f32>) -> f32 {
+        let mut similarity = 0.0;
+        let mut count = 0;
+
+        for (key, value1) in context1 {
+            if let Some(value2) = context2.get(key) {
+                similarity += 1.0 - (value1 - value2).abs();
+                count += 1;
+            }
+        }
+
+        if count == 0 {
+            0.0
+        } else {
+            similarity / count as f32
+        }
+    }
+
+    fn calculate_confidence(&self, score: f32) -> f32 {
+        let uncertainty = 1.0 - score.abs();
+        if uncertainty > self.uncertainty_threshold {
+            0.5 * (1.0 - (uncertainty - self.uncertainty_threshold))
+        } else {
+            score
+        }
+    }
+
+    fn adjust_strategy(&mut self) {
+        let recent_outcomes: Vec<_> = self.decision_history.iter()
+            .rev()
+            .take(5)
+            .filter_map(|d| d.outcome.as_ref())
+            .collect();
+
+        if recent_outcomes.len() < 3 {
+            return;
+        }
+
+        let success_rate = recent_outcomes.iter()
+            .filter(|o| o.success)
+            .count() as f32 / recent_outcomes.len() as f32;
+
+        self.current_strategy = match success_rate {
+            x if x < 0.3 => DecisionStrategy::RiskAverse,
+            x if x < 0.5 => DecisionStrategy::Rational,
+            x if x > 0.8 => DecisionStrategy::RiskSeeking,
+            _ => DecisionStrategy::Balanced,
+        };
+    }
+
+    pub fn get_load(&self) -> f32 {
+        self.processing_load
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::needs::Needs;
+
+    #[test]
+    fn utility_learning_is_keyed_on_circumstances_not_scores() {
+        let mut maker = DecisionMaker::default();
+        let (needs, traits) = (Needs::default(), PersonalityTraits::default());
+        let inputs = UtilityInputs::new(9.0).with_needs(&needs);
+        // Sated, a third of the way through the day
+        let key = "energy=low,hour=mid,hunger=low,hygiene=low,social=low";
+
+        let first = maker.decide_by_utility(&inputs, &traits).unwrap();
+        maker.record_outcome(DecisionOutcome::new(true, 1.0, "fine"));
+        // Learning reshapes the scores, but not the situation
+        let second = maker.decide_by_utility(&inputs, &traits).unwrap();
+        maker.record_outcome(DecisionOutcome::new(true, 1.0, "fine"));
+
+        let tries = if first == second {
+            maker.learning().tries(key, &first)
+        } else {
+            maker.learning().tries(key, &first) + maker.learning().tries(key, &second)
+        };
+        assert_eq!(tries, 2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,296 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Exploration rate of the least and most open NPCs
+const MIN_EXPLORATION: f32 = 0.02;
+const MAX_EXPLORATION: f32 = 0.3;
+/// Q-learning step size and discount on the next situation's value
+const LEARNING_RATE: f32 = 0.2;
+const DISCOUNT: f32 = 0.8;
+/// Context values below / above these read as low / high
+const LOW: f32 = 1.0 / 3.0;
+const HIGH: f32 = 2.0 / 3.0;
+/// Situation key for decisions made without any context
+const ANY_SITUATION: &str = "any";
+
+/// Learns how well options turn out in different situations from the
+/// impact of their outcomes: a contextual bandit (average reward per
+/// situation and option) and tabular Q-learning (which also credits the
+/// situation an option leads to).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningSystem {
+    method: LearningMethod,
+    bandit: HashMap<String, HashMap<String, ArmStats>>,
+    q_values: HashMap<String, HashMap<String, f32>>,
+    /// Chance of trying something other than the best-known option
+    exploration: f32,
+    /// Last rewarded choice, waiting for the situation it led to
+    pending: Option<Transition>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LearningMethod {
+    Bandit,
+    QLearning,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArmStats {
+    /// Mean reward, -1..1
+    value: f32,
+    pulls: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Transition {
+    situation: String,
+    option: String,
+    reward: f32,
+}
+
+/// One row of the learned tables, for inspection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearnedValue {
+    pub situation: String,
+    pub option: String,
+    pub bandit_value: f32,
+    pub q_value: f32,
+    pub tries: u32,
+}
+
+impl Default for LearningSystem {
+    fn default() -> Self {
+        Self {
+            method: LearningMethod::Bandit,
+            bandit: HashMap::new(),
+            q_values: HashMap::new(),
+            exploration: exploration_for(0.5),
+            pending: None,
+        }
+    }
+}
+
+/// Exploration rate for an openness of 0..1.
+pub fn exploration_for(openness: f32) -> f32 {
+    MIN_EXPLORATION + openness.clamp(0.0, 1.0) * (MAX_EXPLORATION - MIN_EXPLORATION)
+}
+
+/// Coarse key for a decision context, e.g. "benefit=high,risk=low", so
+/// what's learned in one situation carries over to similar ones.
+pub fn situation(context: &HashMap<String, f32>) -> String {
+    let buckets: BTreeMap<&str, &str> = context
+        .iter()
+        .map(|(factor, value)| {
+            let bucket = if *value < LOW { "low" } else if *value > HIGH { "high" } else { "mid" };
+            (factor.as_str(), bucket)
+        })
+        .collect();
+    if buckets.is_empty() {
+        return ANY_SITUATION.to_string();
+    }
+    buckets
+        .iter()
+        .map(|(factor, bucket)| format!("{}={}", factor, bucket))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl LearningSystem {
+    pub fn set_method(&mut self, method: LearningMethod) {
+        self.method = method;
+    }
+
+    pub fn method(&self) -> LearningMethod {
+        self.method
+    }
+
+    /// Open NPCs try new things more often.
+    pub fn set_openness(&mut self, openness: f32) {
+        self.exploration = exploration_for(openness);
+    }
+
+    pub fn exploration(&self) -> f32 {
+        self.exploration
+    }
+
+    pub fn should_explore(&self) -> bool {
+        rand::random::<f32>() < self.exploration
+    }
+
+    /// What the NPC has learned to expect from `option` here, -1..1; 0 when
+    /// it has no experience of it.
+    pub fn value(&self, situation: &str, option: &str) -> f32 {
+        match self.method {
+            LearningMethod::Bandit => self.bandit
+                .get(situation)
+                .and_then(|arms| arms.get(option))
+                .map_or(0.0, |arm| arm.value),
+            // Discounted returns top out at 1 / (1 - DISCOUNT); scale back to -1..1
+            LearningMethod::QLearning => self.q_value(situation, option) * (1.0 - DISCOUNT),
+        }
+    }
+
+    pub fn tries(&self, situation: &str, option: &str) -> u32 {
+        self.bandit
+            .get(situation)
+            .and_then(|arms| arms.get(option))
+            .map_or(0, |arm| arm.pulls)
+    }
+
+    /// A decision is being made in `situation`; completes the Q update for
+    /// the last rewarded choice now that we know where it led.
+    pub fn enter(&mut self, situation: &str) {
+        if let Some(transition) = self.pending.take() {
+            let next_best = self.q_values
+                .get(situation)
+                .and_then(|options| options.values().copied().reduce(f32::max))
+                .unwrap_or(0.0);
+            self.update_q(&transition, next_best);
+        }
+    }
+
+    /// Learns from how `option` turned out in `situation`; `reward` is -1..1.
+    pub fn reward(&mut self, situation: &str, option: &str, reward: f32) {
+        let reward = reward.clamp(-1.0, 1.0);
+
+        // Step 1: bandit arm keeps a running mean
+        let arm = self.bandit
+            .entry(situation.to_string())
+            .or_default()
+            .entry(option.to_string())
+            .or_default();
+        arm.pulls += 1;
+        arm.value += (reward - arm.value) / arm.pulls as f32;
+
+        // Step 2: Q-learning waits for the next situation; an earlier
+        // transition still waiting is closed off as if nothing followed
+        if let Some(previous) = self.pending.take() {
+            self.update_q(&previous, 0.0);
+        }
+        self.pending = Some(Transition {
+            situation: situation.to_string(),
+            option: option.to_string(),
+            reward,
+        });
+    }
+
+    /// Every learned situation and option, most tried first.
+    pub fn table(&self) -> Vec<LearnedValue> {
+        let mut rows: Vec<LearnedValue> = self.bandit
+            .iter()
+            .flat_map(|(situation, arms)| {
+                arms.iter().map(move |(option, arm)| LearnedValue {
+                    situation: situation.clone(),
+                    option: option.clone(),
+                    bandit_value: arm.value,
+                    q_value: self.q_value(situation, option),
+                    tries: arm.pulls,
+                })
+            })
+            .collect();
+        rows.sort_by(|a, b| b.tries.cmp(&a.tries).then_with(|| a.situation.cmp(&b.situation)));
+        rows
+    }
+
+    fn q_value(&self, situation: &str, option: &str) -> f32 {
+        self.q_values
+            .get(situation)
+            .and_then(|options| options.get(option))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    fn update_q(&mut self, transition: &Transition, next_best: f32) {
+        let q = self.q_values
+            .entry(transition.situation.clone())
+            .or_default()
+            .entry(transition.option.clone())
+            .or_insert(0.0);
+        *q += LEARNING_RATE * (transition.reward + DISCOUNT * next_best - *q);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cognition::decision::{DecisionMaker, DecisionOutcome};
+    use crate::cognition::CognitionSystem;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "expected {}, got {}", expected, actual);
+    }
+
+    /// One decision with a single option, so exploring can't change it.
+    fn decide(maker: &mut DecisionMaker, option: &str, outcome: DecisionOutcome) {
+        maker.decide(vec![option.to_string()], &HashMap::new());
+        maker.record_outcome(outcome);
+    }
+
+    #[test]
+    fn outcomes_teach_their_impact() {
+        let mut maker = DecisionMaker::default();
+
+        decide(&mut maker, "rest", DecisionOutcome::new(true, 0.6, "refreshed"));
+        assert_close(maker.learning().value(ANY_SITUATION, "rest"), 0.6);
+        // A failure counts against the option however big its impact
+        decide(&mut maker, "rest", DecisionOutcome::new(false, 0.4, "overslept"));
+        assert_close(maker.learning().value(ANY_SITUATION, "rest"), 0.1);
+        assert_eq!(maker.learning().tries(ANY_SITUATION, "rest"), 2);
+        assert_eq!(maker.learning().value(ANY_SITUATION, "work"), 0.0);
+    }
+
+    #[test]
+    fn q_learning_credits_where_a_choice_led() {
+        let mut learning = LearningSystem::default();
+        learning.set_method(LearningMethod::QLearning);
+
+        learning.reward("tired=high", "sleep", 1.0);
+        learning.enter("tired=low");
+        assert_close(learning.q_value("tired=high", "sleep"), LEARNING_RATE);
+
+        learning.reward("tired=low", "work", 0.5);
+        learning.enter("tired=high");
+        // 0.2 * (0.5 + 0.8 * 0.2)
+        assert_close(learning.q_value("tired=low", "work"), LEARNING_RATE * (0.5 + DISCOUNT * LEARNING_RATE));
+        assert_close(learning.value("tired=low", "work"), learning.q_value("tired=low", "work") * (1.0 - DISCOUNT));
+    }
+
+    #[test]
+    fn openness_sets_how_often_npcs_explore() {
+        assert_close(exploration_for(0.0), MIN_EXPLORATION);
+        assert_close(exploration_for(1.0), MAX_EXPLORATION);
+        assert_close(exploration_for(2.0), MAX_EXPLORATION);
+
+        let mut learning = LearningSystem::default();
+        learning.set_openness(0.2);
+        let cautious = learning.exploration();
+        learning.set_openness(0.9);
+        assert!(learning.exploration() > cautious);
+        assert_close(learning.exploration(), exploration_for(0.9));
+    }
+
+    #[test]
+    fn learned_table_lists_most_tried_first() {
+        let mut cognition = CognitionSystem::default();
+        let mut bustling = HashMap::new();
+        bustling.insert("crowd".to_string(), 0.9);
+        for _ in 0..2 {
+            cognition.make_decision(vec!["chat".to_string()], bustling.clone(), &HashMap::new());
+            cognition.record_outcome(DecisionOutcome::new(true, 0.5, "good talk"));
+        }
+        cognition.make_decision(vec!["leave".to_string()], HashMap::new(), &HashMap::new());
+        cognition.record_outcome(DecisionOutcome::new(false, 0.3, "missed out"));
+
+        let table = cognition.learned_values();
+        let rows: Vec<(&str, &str, u32)> = table
+            .iter()
+            .map(|row| (row.situation.as_str(), row.option.as_str(), row.tries))
+            .collect();
+        // Biases add how risky and urgent things seem to every situation
+        assert_eq!(rows, [("crowd=high,risk=low,urgency=low", "chat", 2), ("risk=low,urgency=low", "leave", 1)]);
+        assert_close(table[0].bandit_value, 0.5);
+        assert_close(table[1].bandit_value, -0.3);
+        // The second chat closed the first one's Q update
+        assert!(table[0].q_value > 0.0);
+    }
+}
\ No newline at end of file
@@ -1,18 +1,26 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use uuid::Uuid;
 
 pub mod decision;
 pub mod reasoning;
 pub mod perception;
 pub mod bias;
+pub mod utility;
+pub mod trace;
+pub mod learning;
 
-use decision::DecisionMaker;
-use reasoning::ReasoningEngine;
+use decision::{DecisionMaker, DecisionOutcome};
+use learning::LearnedValue;
+use reasoning::{InferenceResult, LogicalRule, ReasoningEngine};
 use perception::PerceptionSystem;
 use bias::BiasSystem;
+use utility::{UtilityDefinitions, UtilityInputs};
+use trace::DecisionTrace;
+use crate::knowledge::KnowledgeBase;
+use crate::personality::PersonalityTraits;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
 pub struct CognitionSystem {
     decision_maker: DecisionMaker,
     reasoning: ReasoningEngine,
@@ -89,17 +97,100 @@ impl CognitionSystem {
         Some(thought)
     }
 
-    pub fn make_decision(&mut self, options: Vec<String>, context: HashMap<String, f32>) -> String {
+    /// Decides between `options`; `memories` gives how memories of each
+    /// feel, -1..1, for those the NPC recalled anything about.
+    pub fn make_decision(
+        &mut self,
+        options: Vec<String>,
+        context: HashMap<String, f32>,
+        memories: &HashMap<String, f32>,
+    ) -> String {
         // Consider biases
         let biased_context = self.bias.influence_context(context);
 
         // Process through reasoning
-        let analyzed_options = options.iter()
-            .map(|opt| self.reasoning.evaluate_option(opt, &biased_context))
+        let priors = options.iter()
+            .map(|opt| (opt.clone(), self.reasoning.evaluate_option(opt, &biased_context)))
             .collect();
 
-        // Make final decision
-        self.decision_maker.decide(analyzed_options, &biased_context)
+        // Make final decision, noting the thoughts it was made with
+        let choice = self.decision_maker.decide_with_priors(options, &biased_context, &priors, memories);
+        let thoughts = self.working_memory.iter().map(|thought| thought.content.clone()).collect();
+        self.decision_maker.note_last_thoughts(thoughts);
+        choice
+    }
+
+    /// Reasons forward from what the NPC knows, writing what follows back
+    /// into its knowledge. Rules only fire where `context` meets their
+    /// requirements.
+    pub fn infer(&mut self, knowledge: &mut KnowledgeBase, context: &HashMap<String, f32>) -> Vec<InferenceResult> {
+        self.reasoning.observe_facts(knowledge.certainties());
+        let derived = self.reasoning.forward_chain(context);
+        knowledge.set_inferred(
+            derived.iter().map(|result| (result.conclusion().to_string(), result.confidence())).collect(),
+        );
+        derived
+    }
+
+    /// Works back from `goal` to known facts; the result's reasoning path
+    /// says how.
+    pub fn query(&mut self, goal: &str, context: &HashMap<String, f32>) -> Option<InferenceResult> {
+        self.reasoning.query(goal, context)
+    }
+
+    pub fn add_rule(&mut self, rule: LogicalRule) {
+        self.reasoning.add_logical_rule(rule);
+    }
+
+    /// Adds what was recalled and believed to the last decision's trace.
+    pub fn annotate_last_decision(&mut self, memories: Vec<String>, beliefs: Vec<(String, f32)>) {
+        self.decision_maker.annotate_last_trace(memories, beliefs);
+    }
+
+    /// Recent decisions with their reasoning, oldest first.
+    pub fn decision_traces(&self) -> impl Iterator<Item = &DecisionTrace> {
+        self.decision_maker.traces()
+    }
+
+    pub fn last_decision(&self) -> Option<&DecisionTrace> {
+        self.decision_maker.last_trace()
+    }
+
+    /// Learns from how the last decision turned out.
+    pub fn record_outcome(&mut self, outcome: DecisionOutcome) {
+        self.decision_maker.record_outcome(outcome);
+    }
+
+    /// Open minds explore more options instead of sticking with what worked.
+    pub fn set_openness(&mut self, openness: f32) {
+        self.decision_maker.learning_mut().set_openness(openness);
+    }
+
+    pub fn learned_values(&self) -> Vec<LearnedValue> {
+        self.decision_maker.learning().table()
+    }
+
+    /// Chooses what to do next by utility: needs, distances, relationships
+    /// and the time of day, leaned on by personality.
+    pub fn choose_action(&mut self, inputs: &UtilityInputs, traits: &PersonalityTraits) -> Option<String> {
+        self.decision_maker.decide_by_utility(inputs, traits)
+    }
+
+    pub fn set_utility(&mut self, utility: UtilityDefinitions) {
+        self.decision_maker.set_utility(utility);
+    }
+
+    /// Names of the options `choose_action` picks from.
+    pub fn action_options(&self) -> Vec<String> {
+        self.decision_maker.utility().options().iter().map(|option| option.name().to_string()).collect()
+    }
+
+    /// Makes options that meet pressing needs ("eat", "sleep", ...) weigh
+    /// more in decisions.
+    pub fn weigh_needs(&mut self, needs: &crate::goals::needs::Needs) {
+        for (option, weight) in needs.decision_weights() {
+            self.decision_maker.set_weight(option, weight);
+        }
     }
 
     fn update_working_memory(&mut self) {
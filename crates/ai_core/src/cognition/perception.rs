@@ -99,6 +99,11 @@ impl PerceptionSystem {
         self.clarity
     }
 
+    /// Effort spent on what's being sensed right now, 0..1.
+    pub fn get_load(&self) -> f32 {
+        (self.sensory_inputs.len() as f32 * 0.05).clamp(0.0, 1.0)
+    }
+
     fn update_sensory_inputs(&mut self, delta_time: f32) {
         // Decay sensory input intensities
         for input in self.sensory_inputs.values_mut() {
@@ -178,7 +183,7 @@ impl PerceptionSystem {
     }
 
     fn calculate_emotional_valence(&self, content: &str) -> f32 {
-        let mut valence = 0.0;
+        let mut valence: f32 = 0.0;
 
         // Simple sentiment analysis
         for word in content.split_whitespace() {
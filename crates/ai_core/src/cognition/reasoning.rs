@@ -0,0 +1,662 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// Designer-written rules every NPC reasons with
+pub const RULES_PATH: &str = "behaviours/rules.ron";
+/// Time units a cached inference is trusted for, even if nothing changed
+const CACHE_LIFETIME: f32 = 100.0;
+/// Rounds of forward chaining before giving up on reaching a fixpoint
+const MAX_INFERENCE_ROUNDS: usize = 16;
+/// Rules deep a backward-chained proof may go
+const MAX_PROOF_DEPTH: usize = 8;
+/// Derived certainties below this aren't worth believing
+const MIN_INFERRED_CERTAINTY: f32 = 0.05;
+/// Certainty changes smaller than this don't invalidate inferences
+const CERTAINTY_TOLERANCE: f32 = 0.01;
+
+#[derive(Debug, Error)]
+pub enum RulesError {
+    #[error("Failed to read rules: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse rules: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+}
+
+/// Rules loaded from a file, to hand to each NPC's reasoning engine.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Resource))]
+pub struct RuleSet {
+    rules: Vec<LogicalRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningEngine {
+    belief_network: HashMap<String, Vec<String>>,
+    logical_rules: Vec<LogicalRule>,
+    inference_cache: HashMap<String, InferenceResult>,
+    /// Facts the NPC holds and how certain it is of each
+    #[serde(default)]
+    facts: HashMap<String, f32>,
+    #[serde(default)]
+    elapsed: f32,
+    reasoning_confidence: f32,
+    processing_load: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogicalRule {
+    premises: Vec<String>,
+    conclusion: String,
+    confidence: f32,
+    #[serde(default)]
+    context_requirements: HashSet<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InferenceResult {
+    conclusion: String,
+    /// Facts used and rules applied, in the order they were needed
+    reasoning_path: Vec<String>,
+    confidence: f32,
+    timestamp: f32,
+    /// Known facts the conclusion rests on, with their certainty at the time
+    #[serde(default)]
+    support: HashMap<String, f32>,
+    /// Context the rules used required
+    #[serde(default)]
+    requires: HashSet<String>,
+}
+
+/// Analyses and proofs of the same text answer different questions.
+fn cache_key(kind: &str, text: &str) -> String {
+    format!("{}:{}", kind, text)
+}
+
+impl LogicalRule {
+    pub fn new(premises: Vec<String>, conclusion: &str, confidence: f32) -> Self {
+        Self {
+            premises,
+            conclusion: conclusion.to_string(),
+            confidence: confidence.clamp(0.0, 1.0),
+            context_requirements: HashSet::new(),
+        }
+    }
+
+    /// Only applies when `requirement` is part of the situation.
+    pub fn requires(mut self, requirement: &str) -> Self {
+        self.context_requirements.insert(requirement.to_string());
+        self
+    }
+
+    pub fn premises(&self) -> &[String] {
+        &self.premises
+    }
+
+    pub fn conclusion(&self) -> &str {
+        &self.conclusion
+    }
+
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    fn context_met(&self, context: &HashMap<String, f32>) -> bool {
+        self.context_requirements.iter().all(|req| context.contains_key(req))
+    }
+
+    fn describe(&self) -> String {
+        format!("{} => {} ({:.0}%)", self.premises.join(" and "), self.conclusion, self.confidence * 100.0)
+    }
+}
+
+impl RuleSet {
+    pub fn load(path: &str) -> Result<Self, RulesError> {
+        let source = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&source)?)
+    }
+
+    pub fn rules(&self) -> &[LogicalRule] {
+        &self.rules
+    }
+}
+
+impl InferenceResult {
+    fn known(fact: &str, certainty: f32, timestamp: f32) -> Self {
+        Self {
+            conclusion: fact.to_string(),
+            reasoning_path: vec![format!("known: {}", fact)],
+            confidence: certainty,
+            timestamp,
+            support: HashMap::from([(fact.to_string(), certainty)]),
+            requires: HashSet::new(),
+        }
+    }
+
+    /// Applies `rule` to the proofs of its premises: the conclusion is only
+    /// as certain as the weakest premise, discounted by the rule itself.
+    fn chain(rule: &LogicalRule, premises: &[&InferenceResult], timestamp: f32) -> Self {
+        let weakest = premises.iter().map(|p| p.confidence).fold(1.0f32, f32::min);
+        let mut reasoning_path = Vec::new();
+        let mut support = HashMap::new();
+        let mut requires = rule.context_requirements.clone();
+        for premise in premises {
+            for step in &premise.reasoning_path {
+                if !reasoning_path.contains(step) {
+                    reasoning_path.push(step.clone());
+                }
+            }
+            support.extend(premise.support.iter().map(|(fact, certainty)| (fact.clone(), *certainty)));
+            requires.extend(premise.requires.iter().cloned());
+        }
+        reasoning_path.push(rule.describe());
+
+        Self {
+            conclusion: rule.conclusion.clone(),
+            reasoning_path,
+            confidence: weakest * rule.confidence,
+            timestamp,
+            support,
+            requires,
+        }
+    }
+
+    pub fn conclusion(&self) -> &str {
+        &self.conclusion
+    }
+
+    pub fn reasoning_path(&self) -> &[String] {
+        &self.reasoning_path
+    }
+
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReasoningStrategy {
+    Deductive,
+    Inductive,
+    Abductive,
+    Analogical,
+}
+
+impl Default for ReasoningEngine {
+    fn default() -> Self {
+        Self {
+            belief_network: HashMap::new(),
+            logical_rules: Vec::new(),
+            inference_cache: HashMap::new(),
+            facts: HashMap::new(),
+            elapsed: 0.0,
+            reasoning_confidence: 0.7,
+            processing_load: 0.0,
+        }
+    }
+}
+
+impl ReasoningEngine {
+    pub fn update(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+
+        // Decay processing load
+        self.processing_load *= 0.95f32.powf(delta_time);
+        
+        // Clean up old cache entries
+        self.cleanup_cache();
+    }
+
+    pub fn analyze(&mut self, input: &str) -> String {
+        self.processing_load += 0.1;
+
+        // Check cache first
+        let key = cache_key("analyze", input);
+        if let Some(cached) = self.inference_cache.get(&key) {
+            return cached.conclusion.clone();
+        }
+
+        // Perform reasoning
+        let result = self.reason_about(input);
+        let conclusion = result.as_ref()
+            .map(|r| r.conclusion.clone())
+            .unwrap_or_else(|| "No conclusion reached".to_string());
+
+        // Cache result
+        if let Some(result) = result {
+            self.inference_cache.insert(key, result);
+        }
+
+        conclusion
+    }
+
+    /// Takes in what the NPC currently holds true. Cached inferences resting
+    /// on facts that are gone or whose certainty moved are dropped; a fact
+    /// not held before may open a better route to anything, so it drops
+    /// them all.
+    pub fn observe_facts(&mut self, facts: HashMap<String, f32>) {
+        let learned = facts.keys().any(|fact| !self.facts.contains_key(fact));
+        self.facts = facts;
+        if learned {
+            self.inference_cache.clear();
+            return;
+        }
+        let facts = &self.facts;
+        self.inference_cache.retain(|_, result| {
+            result.support.iter().all(|(fact, certainty)| {
+                facts.get(fact).is_some_and(|now| (now - certainty).abs() < CERTAINTY_TOLERANCE)
+            })
+        });
+    }
+
+    /// Forward chaining: applies every rule whose premises are known and
+    /// whose context requirements are met, over and over, until nothing new
+    /// follows. Returns each derived fact with how it was reached.
+    pub fn forward_chain(&mut self, context: &HashMap<String, f32>) -> Vec<InferenceResult> {
+        self.processing_load += 0.1;
+
+        let mut proofs: HashMap<String, InferenceResult> = self.facts
+            .iter()
+            .map(|(fact, certainty)| (fact.clone(), InferenceResult::known(fact, *certainty, self.elapsed)))
+            .collect();
+        let mut derived = HashSet::new();
+
+        for _ in 0..MAX_INFERENCE_ROUNDS {
+            let mut changed = false;
+            for rule in self.logical_rules.iter().filter(|rule| rule.context_met(context)) {
+                let premises: Option<Vec<&InferenceResult>> = rule.premises
+                    .iter()
+                    .map(|premise| proofs.get(premise))
+                    .collect();
+                let Some(premises) = premises else { continue };
+
+                let result = InferenceResult::chain(rule, &premises, self.elapsed);
+                if result.confidence < MIN_INFERRED_CERTAINTY {
+                    continue;
+                }
+                // Only a more certain route replaces what's already held
+                let better = proofs
+                    .get(&result.conclusion)
+                    .is_none_or(|held| result.confidence > held.confidence + CERTAINTY_TOLERANCE);
+                if better {
+                    derived.insert(result.conclusion.clone());
+                    proofs.insert(result.conclusion.clone(), result);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let results: Vec<InferenceResult> = derived
+            .into_iter()
+            .filter_map(|conclusion| proofs.remove(&conclusion))
+            .collect();
+        for result in &results {
+            self.inference_cache.insert(self.proof_key(&result.conclusion, context), result.clone());
+        }
+        results
+    }
+
+    /// Backward chaining: tries to prove `goal` from known facts through the
+    /// rules that conclude it. The result's reasoning path lists the facts
+    /// and rules used.
+    pub fn query(&mut self, goal: &str, context: &HashMap<String, f32>) -> Option<InferenceResult> {
+        // Cached proofs only hold in a situation where the same rules apply
+        let key = self.proof_key(goal, context);
+        if let Some(cached) = self.inference_cache.get(&key) {
+            return Some(cached.clone());
+        }
+
+        self.processing_load += 0.1;
+        let mut visiting = HashSet::new();
+        let result = self.prove(goal, context, &mut visiting, 0)?;
+        self.inference_cache.insert(key, result.clone());
+        Some(result)
+    }
+
+    /// Cache key for proving `goal` in `context`: the goal plus whichever
+    /// rule requirements the context meets, since those decide which rules
+    /// a proof may use.
+    fn proof_key(&self, goal: &str, context: &HashMap<String, f32>) -> String {
+        let mut met: Vec<&str> = self.logical_rules
+            .iter()
+            .flat_map(|rule| &rule.context_requirements)
+            .filter(|requirement| context.contains_key(*requirement))
+            .map(String::as_str)
+            .collect();
+        met.sort_unstable();
+        met.dedup();
+        format!("{}|{}", cache_key("prove", goal), met.join(","))
+    }
+
+    pub fn evaluate_option(&self, option: &str, context: &HashMap<String, f32>) -> f32 {
+        let mut score = 0.5;
+
+        // Apply logical rules
+        for rule in &self.logical_rules {
+            if self.rule_applies(rule, option, context) {
+                score += rule.confidence * 0.2;
+            }
+        }
+
+        // Consider belief network
+        if let Some(related_beliefs) = self.belief_network.get(option) {
+            let belief_score = related_beliefs.iter()
+                .filter(|belief| context.contains_key(*belief))
+                .count() as f32 / related_beliefs.len() as f32;
+            
+            score += belief_score * 0.3;
+        }
+
+        score.clamp(0.0, 1.0)
+    }
+
+    /// New rules can change any conclusion, so the cache starts over.
+    pub fn add_logical_rule(&mut self, rule: LogicalRule) {
+        self.logical_rules.push(rule);
+        self.inference_cache.clear();
+    }
+
+    pub fn rules(&self) -> &[LogicalRule] {
+        &self.logical_rules
+    }
+
+    pub fn add_belief_connection(&mut self, belief: String, connected: String) {
+        self.belief_network
+            .entry(belief)
+            .or_default()
+            .push(connected);
+    }
+
+    fn prove(
+        &self,
+        goal: &str,
+        context: &HashMap<String, f32>,
+        visiting: &mut HashSet<String>,
+        depth: usize,
+    ) -> Option<InferenceResult> {
+        let known = self.facts
+            .get(goal)
+            .map(|certainty| InferenceResult::known(goal, *certainty, self.elapsed));
+        if depth >= MAX_PROOF_DEPTH || !visiting.insert(goal.to_string()) {
+            return known;
+        }
+
+        // Step 1: every rule concluding the goal is a possible route
+        let mut best = known;
+        for rule in self.logical_rules.iter().filter(|rule| rule.conclusion == goal && rule.context_met(context)) {
+            // Step 2: all of its premises must be proven in turn
+            let mut premises = Vec::with_capacity(rule.premises.len());
+            for premise in &rule.premises {
+                match self.prove(premise, context, visiting, depth + 1) {
+                    Some(proof) => premises.push(proof),
+                    None => break,
+                }
+            }
+            if premises.len() < rule.premises.len() {
+                continue;
+            }
+
+            // Step 3: keep the most certain route
+            let result = InferenceResult::chain(rule, &premises.iter().collect::<Vec<_>>(), self.elapsed);
+            if result.confidence >= MIN_INFERRED_CERTAINTY
+                && best.as_ref().is_none_or(|b| result.confidence > b.confidence)
+            {
+                best = Some(result);
+            }
+        }
+
+        visiting.remove(goal);
+        best
+    }
+
+    fn reason_about(&self, input: &str) -> Option<InferenceResult> {
+        // Try different reasoning strategies
+        let mut conclusions = vec![
+            self.deductive_reasoning(input),
+            self.inductive_reasoning(input),
+            self.abductive_reasoning(input),
+        ];
+
+        // Remove None values and sort by confidence
+        conclusions.retain(|c| c.is_some());
+        conclusions.sort_by(|a, b| {
+            b.as_ref().unwrap().confidence.partial_cmp(&a.as_ref().unwrap().confidence).unwrap()
+        });
+
+        // Return most confident conclusion
+        conclusions.into_iter().next().flatten()
+    }
+
+    /// Takes the input as observed and applies the first rule it completes:
+    /// one needing it as a premise whose other premises are already known.
+    fn deductive_reasoning(&self, premise: &str) -> Option<InferenceResult> {
+        let observed = InferenceResult::known(premise, self.reasoning_confidence, self.elapsed);
+        let no_context = HashMap::new();
+        let mut visiting = HashSet::new();
+        for rule in &self.logical_rules {
+            if !rule.context_met(&no_context) || !rule.premises.iter().any(|p| p == premise) {
+                continue;
+            }
+            let premises: Option<Vec<InferenceResult>> = rule.premises
+                .iter()
+                .map(|p| if p == premise {
+                    Some(observed.clone())
+                } else {
+                    self.prove(p, &no_context, &mut visiting, 1)
+                })
+                .collect();
+            if let Some(premises) = premises {
+                return Some(InferenceResult::chain(rule, &premises.iter().collect::<Vec<_>>(), self.elapsed));
+            }
+        }
+        None
+    }
+
+    fn inductive_reasoning(&self, observation: &str) -> Option<InferenceResult> {
+        if let Some(patterns) = self.belief_network.get(observation) {
+            let most_common = patterns.iter()
+                .max_by_key(|&pattern| {
+                    patterns.iter().filter(|&p| p == pattern).count()
+                })?;
+
+            Some(InferenceResult {
+                conclusion: format!("Based on patterns, this suggests {}", most_common),
+                reasoning_path: vec![observation.to_string()],
+                confidence: 0.6, // Inductive reasoning has lower confidence
+                timestamp: self.elapsed,
+                support: HashMap::new(),
+                requires: HashSet::new(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn abductive_reasoning(&self, observation: &str) -> Option<InferenceResult> {
+        // Find rules that could explain the observation
+        let explaining_rules: Vec<_> = self.logical_rules.iter()
+            .filter(|rule| rule.conclusion.contains(observation))
+            .collect();
+
+        explaining_rules.first().map(|best_explanation| InferenceResult {
+                conclusion: format!("This might be because {}", 
+                    best_explanation.premises.join(" and ")),
+                reasoning_path: vec![observation.to_string()],
+                confidence: 0.5, // Abductive reasoning has lowest confidence
+                timestamp: self.elapsed,
+                support: HashMap::new(),
+                requires: HashSet::new(),
+            })
+    }
+
+    fn rule_applies(&self, rule: &LogicalRule, situation: &str, context: &HashMap<String, f32>) -> bool {
+        // Check context requirements
+        if !rule.context_met(context) {
+            return false;
+        }
+
+        // Check premises
+        rule.premises.iter().any(|premise| situation.contains(premise))
+    }
+
+    fn cleanup_cache(&mut self) {
+        let now = self.elapsed;
+        self.inference_cache.retain(|_, result| now - result.timestamp < CACHE_LIFETIME);
+    }
+
+    pub fn get_confidence(&self) -> f32 {
+        self.reasoning_confidence
+    }
+
+    pub fn get_load(&self) -> f32 {
+        self.processing_load
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cognition::CognitionSystem;
+    use crate::knowledge::{KnowledgeBase, INFERRED_CATEGORY};
+
+    fn rule(premises: &[&str], conclusion: &str, confidence: f32) -> LogicalRule {
+        LogicalRule::new(premises.iter().map(|premise| premise.to_string()).collect(), conclusion, confidence)
+    }
+
+    fn inferred(knowledge: &KnowledgeBase) -> HashMap<String, f32> {
+        knowledge
+            .get_knowledge_by_category(INFERRED_CATEGORY)
+            .into_iter()
+            .map(|fact| (fact.content().to_string(), fact.certainty()))
+            .collect()
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "expected {}, got {}", expected, actual);
+    }
+
+    /// Knows it's raining and fairly sure it's cold
+    fn weather() -> (CognitionSystem, KnowledgeBase) {
+        let mut cognition = CognitionSystem::default();
+        cognition.add_rule(rule(&["raining", "cold"], "stay_inside", 0.8));
+        cognition.add_rule(rule(&["stay_inside"], "light_fire", 0.5));
+        let mut knowledge = KnowledgeBase::default();
+        knowledge.add_knowledge("raining".to_string(), 0.9, None, "weather".to_string());
+        knowledge.add_knowledge("cold".to_string(), 0.6, None, "weather".to_string());
+        (cognition, knowledge)
+    }
+
+    #[test]
+    fn forward_chaining_writes_what_follows_into_knowledge() {
+        let (mut cognition, mut knowledge) = weather();
+        let derived = cognition.infer(&mut knowledge, &HashMap::new());
+        assert_eq!(derived.len(), 2);
+
+        // The weakest premise times the rule, all the way down the chain
+        let facts = inferred(&knowledge);
+        assert_close(facts["stay_inside"], 0.6 * 0.8);
+        assert_close(facts["light_fire"], 0.6 * 0.8 * 0.5);
+
+        let fire = derived.iter().find(|result| result.conclusion() == "light_fire").unwrap();
+        assert_eq!(
+            fire.reasoning_path(),
+            ["known: raining", "known: cold", "raining and cold => stay_inside (80%)", "stay_inside => light_fire (50%)"],
+        );
+    }
+
+    #[test]
+    fn backward_chaining_says_how_it_got_there() {
+        let mut engine = ReasoningEngine::default();
+        engine.add_logical_rule(rule(&["raining", "cold"], "stay_inside", 0.8));
+        engine.add_logical_rule(rule(&["stay_inside"], "light_fire", 0.5));
+        engine.observe_facts(HashMap::from([("raining".to_string(), 0.9), ("cold".to_string(), 0.6)]));
+        let proof = engine.query("light_fire", &HashMap::new()).unwrap();
+        assert_close(proof.confidence(), 0.24);
+        assert_eq!(
+            proof.reasoning_path(),
+            ["known: raining", "known: cold", "raining and cold => stay_inside (80%)", "stay_inside => light_fire (50%)"],
+        );
+        assert!(engine.query("snowing", &HashMap::new()).is_none());
+    }
+
+    #[test]
+    fn rules_only_fire_when_their_context_is_met() {
+        let mut cognition = CognitionSystem::default();
+        cognition.add_rule(rule(&["raining"], "go_home", 0.9).requires("tired"));
+        let mut knowledge = KnowledgeBase::default();
+        knowledge.add_knowledge("raining".to_string(), 1.0, None, "weather".to_string());
+
+        assert!(cognition.infer(&mut knowledge, &HashMap::new()).is_empty());
+        assert!(inferred(&knowledge).is_empty());
+
+        let tired = HashMap::from([("tired".to_string(), 0.8)]);
+        assert_eq!(cognition.infer(&mut knowledge, &tired).len(), 1);
+        assert_close(inferred(&knowledge)["go_home"], 0.9);
+
+        // Rested again, the conclusion no longer follows and is dropped
+        cognition.infer(&mut knowledge, &HashMap::new());
+        assert!(inferred(&knowledge).is_empty());
+    }
+
+    #[test]
+    fn shipped_rules_reason_from_what_npcs_believe() {
+        let path = format!("{}/../../{}", env!("CARGO_MANIFEST_DIR"), RULES_PATH);
+        let rules = RuleSet::load(&path).expect("shipped rules load");
+        assert!(!rules.rules().is_empty());
+
+        let mut cognition = CognitionSystem::default();
+        for rule in rules.rules() {
+            cognition.add_rule(rule.clone());
+        }
+        let mut knowledge = KnowledgeBase::default();
+        knowledge.beliefs_mut().add_belief("the well water is clean".to_string(), 0.6, false);
+        knowledge.beliefs_mut().add_belief("strangers were seen by the river".to_string(), 0.6, false);
+        cognition.infer(&mut knowledge, &HashMap::new());
+        let calm = inferred(&knowledge);
+        assert_close(calm["the well is a good place to wash"], 0.6 * 0.9);
+
+        // Frightened, the surer rule about strangers applies
+        cognition.infer(&mut knowledge, &HashMap::from([("fear".to_string(), 0.8)]));
+        assert!(inferred(&knowledge)["keep away from the river"] > calm["keep away from the river"]);
+    }
+
+    #[test]
+    fn cached_proofs_follow_the_context() {
+        let mut engine = ReasoningEngine::default();
+        engine.add_logical_rule(LogicalRule::new(vec!["raining".to_string()], "stay_inside", 0.5));
+        engine.add_logical_rule(LogicalRule::new(vec!["raining".to_string()], "stay_inside", 0.9).requires("tired"));
+        engine.observe_facts(HashMap::from([("raining".to_string(), 1.0)]));
+
+        let calm = HashMap::new();
+        let tired = HashMap::from([("tired".to_string(), 0.8)]);
+        let plain = engine.query("stay_inside", &calm).unwrap().confidence();
+        let weary = engine.query("stay_inside", &tired).unwrap().confidence();
+        assert!(weary > plain);
+
+        // Asking again in either situation gets that situation's proof back
+        assert_eq!(engine.query("stay_inside", &calm).unwrap().confidence(), plain);
+        assert_eq!(engine.query("stay_inside", &tired).unwrap().confidence(), weary);
+    }
+
+    #[test]
+    fn new_facts_and_rules_replace_weaker_cached_proofs() {
+        let mut engine = ReasoningEngine::default();
+        engine.add_logical_rule(LogicalRule::new(vec!["cloudy".to_string()], "take_umbrella", 0.4));
+        engine.add_logical_rule(LogicalRule::new(vec!["raining".to_string()], "take_umbrella", 0.8));
+        engine.observe_facts(HashMap::from([("cloudy".to_string(), 1.0)]));
+        let context = HashMap::new();
+        let guessed = engine.query("take_umbrella", &context).unwrap().confidence();
+
+        // Seeing the rain opens a surer route than the cached one
+        engine.observe_facts(HashMap::from([("cloudy".to_string(), 1.0), ("raining".to_string(), 1.0)]));
+        let seen = engine.query("take_umbrella", &context).unwrap().confidence();
+        assert!(seen > guessed);
+
+        engine.add_logical_rule(LogicalRule::new(vec!["raining".to_string()], "take_umbrella", 0.95));
+        assert!(engine.query("take_umbrella", &context).unwrap().confidence() > seen);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,167 @@
+use serde::{Serialize, Deserialize};
+
+/// Why an NPC chose what it did: every option it weighed, what went into
+/// each score, and what it was thinking, remembered and believed at the
+/// time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionTrace {
+    pub timestamp: f32,
+    /// Strategy used, e.g. "balanced", or "utility"
+    pub method: String,
+    /// Best first
+    pub options: Vec<OptionTrace>,
+    pub chosen: String,
+    pub confidence: f32,
+    /// Reasoning in working memory when the choice was made
+    #[serde(default)]
+    pub thoughts: Vec<String>,
+    #[serde(default)]
+    pub memories: Vec<String>,
+    /// Beliefs and how strongly they're held
+    #[serde(default)]
+    pub beliefs: Vec<(String, f32)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptionTrace {
+    pub option: String,
+    pub factors: Vec<FactorScore>,
+    /// Learned or need-driven multiplier applied last
+    pub weight: f32,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactorScore {
+    pub factor: String,
+    pub value: f32,
+    pub effect: Contribution,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Contribution {
+    Add(f32),
+    Scale(f32),
+}
+
+impl FactorScore {
+    pub fn add(factor: &str, value: f32, amount: f32) -> Self {
+        Self { factor: factor.to_string(), value, effect: Contribution::Add(amount) }
+    }
+
+    pub fn scale(factor: &str, value: f32, multiplier: f32) -> Self {
+        Self { factor: factor.to_string(), value, effect: Contribution::Scale(multiplier) }
+    }
+
+    fn describe(&self) -> String {
+        match self.effect {
+            Contribution::Add(amount) => format!("{} {:.2} ({:+.2})", self.factor, self.value, amount),
+            Contribution::Scale(multiplier) => format!("{} {:.2} (x{:.2})", self.factor, self.value, multiplier),
+        }
+    }
+}
+
+impl DecisionTrace {
+    pub fn option(&self, option: &str) -> Option<&OptionTrace> {
+        self.options.iter().find(|o| o.option == option)
+    }
+
+    /// Plain-English account for people reviewing NPC behaviour.
+    pub fn explain(&self) -> String {
+        let mut lines = vec![format!(
+            "Chose \"{}\" ({:.0}% sure) using the {} strategy.",
+            self.chosen,
+            self.confidence * 100.0,
+            self.method,
+        )];
+
+        lines.push("Options weighed:".to_string());
+        for option in &self.options {
+            let marker = if option.option == self.chosen { "*" } else { "-" };
+            let factors: Vec<String> = option.factors.iter().map(FactorScore::describe).collect();
+            let weight = if (option.weight - 1.0).abs() > f32::EPSILON {
+                format!(", weighted x{:.2}", option.weight)
+            } else {
+                String::new()
+            };
+            let because = if factors.is_empty() {
+                String::new()
+            } else {
+                format!(": {}", factors.join(", "))
+            };
+            lines.push(format!("  {} {} scored {:.2}{}{}", marker, option.option, option.score, weight, because));
+        }
+
+        if !self.thoughts.is_empty() {
+            lines.push("Thinking:".to_string());
+            lines.extend(self.thoughts.iter().map(|thought| format!("  {}", thought)));
+        }
+        if !self.memories.is_empty() {
+            lines.push("Remembered:".to_string());
+            lines.extend(self.memories.iter().map(|memory| format!("  \"{}\"", memory)));
+        }
+        if !self.beliefs.is_empty() {
+            lines.push("Believed:".to_string());
+            lines.extend(self.beliefs.iter().map(|(belief, strength)| {
+                format!("  \"{}\" ({:.0}%)", belief, strength * 100.0)
+            }));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> DecisionTrace {
+        DecisionTrace {
+            timestamp: 12.0,
+            method: "balanced".to_string(),
+            options: vec![
+                OptionTrace {
+                    option: "eat".to_string(),
+                    factors: vec![FactorScore::add("benefit", 0.8, 0.24), FactorScore::scale("hunger", 0.9, 1.5)],
+                    weight: 1.5,
+                    score: 0.9,
+                },
+                OptionTrace { option: "sleep".to_string(), factors: Vec::new(), weight: 1.0, score: 0.4 },
+            ],
+            chosen: "eat".to_string(),
+            confidence: 0.75,
+            thoughts: vec!["The bakery opens early".to_string()],
+            memories: vec!["We ate bread at the bakery".to_string()],
+            beliefs: vec![("bread is cheap".to_string(), 0.6)],
+        }
+    }
+
+    #[test]
+    fn explains_options_then_thoughts_memories_and_beliefs_apart() {
+        let expected = [
+            "Chose \"eat\" (75% sure) using the balanced strategy.",
+            "Options weighed:",
+            "  * eat scored 0.90, weighted x1.50: benefit 0.80 (+0.24), hunger 0.90 (x1.50)",
+            "  - sleep scored 0.40",
+            "Thinking:",
+            "  The bakery opens early",
+            "Remembered:",
+            "  \"We ate bread at the bakery\"",
+            "Believed:",
+            "  \"bread is cheap\" (60%)",
+        ]
+        .join("\n");
+
+        assert_eq!(trace().explain(), expected);
+    }
+
+    #[test]
+    fn leaves_out_empty_sections() {
+        let trace = DecisionTrace { thoughts: Vec::new(), memories: Vec::new(), beliefs: Vec::new(), ..trace() };
+
+        let explained = trace.explain();
+        assert!(explained.ends_with("  - sleep scored 0.40"));
+        assert!(!explained.contains("Thinking:"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,388 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+use crate::goals::needs::{Need, Needs};
+use crate::personality::PersonalityTraits;
+
+/// Designer-tuned options, curves and weights
+pub const UTILITY_PATH: &str = "behaviours/utility.ron";
+/// Hours between a time of day and the opposite one
+const HALF_DAY: f32 = 12.0;
+
+#[derive(Debug, Error)]
+pub enum UtilityError {
+    #[error("Failed to read utility definitions: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse utility definitions: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+}
+
+/// Utility AI: every option is scored from typed considerations, each
+/// mapped through a response curve, and the best-scoring one wins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Resource))]
+pub struct UtilityDefinitions {
+    options: Vec<UtilityOption>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UtilityOption {
+    name: String,
+    considerations: Vec<Consideration>,
+    /// Designer multiplier on the final score
+    #[serde(default = "neutral_weight")]
+    weight: f32,
+    /// How personality pulls on the option, e.g. extraverts like chatting
+    #[serde(default)]
+    personality: Vec<(BigFive, f32)>,
+}
+
+/// One input to an option's score, shaped by a curve into 0..1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Consideration {
+    input: Input,
+    curve: ResponseCurve,
+    /// Reads the curve upside down: near is good, not far
+    #[serde(default)]
+    invert: bool,
+}
+
+/// What a consideration looks at, each normalised to 0..1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Input {
+    /// How urgent the need is
+    Need(Need),
+    /// Distance to the option's target over `max`
+    Distance { max: f32 },
+    /// Liking for the option's target, -1..1 mapped to 0..1
+    Relationship,
+    /// How far the hour is from `peak`; 0 at the peak, 1 half a day away
+    TimeOfDay { peak: f32 },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ResponseCurve {
+    Linear { slope: f32, intercept: f32 },
+    Logistic { steepness: f32, midpoint: f32 },
+    Exponential { exponent: f32 },
+    Step { threshold: f32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BigFive {
+    Openness,
+    Conscientiousness,
+    Extraversion,
+    Agreeableness,
+    Neuroticism,
+}
+
+/// What the NPC knows right now that considerations read. Distances and
+/// relationships are per option, since each option has its own target.
+#[derive(Debug, Clone, Default)]
+pub struct UtilityInputs<'a> {
+    needs: Option<&'a Needs>,
+    hour: f32,
+    distances: HashMap<String, f32>,
+    relationships: HashMap<String, f32>,
+    memories: HashMap<String, f32>,
+}
+
+fn neutral_weight() -> f32 {
+    1.0
+}
+
+impl ResponseCurve {
+    pub fn evaluate(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        let y = match *self {
+            ResponseCurve::Linear { slope, intercept } => slope * x + intercept,
+            ResponseCurve::Logistic { steepness, midpoint } => 1.0 / (1.0 + (-steepness * (x - midpoint)).exp()),
+            ResponseCurve::Exponential { exponent } => x.powf(exponent.max(0.0)),
+            ResponseCurve::Step { threshold } => if x >= threshold { 1.0 } else { 0.0 },
+        };
+        y.clamp(0.0, 1.0)
+    }
+}
+
+impl BigFive {
+    pub fn value(&self, traits: &PersonalityTraits) -> f32 {
+        match self {
+            BigFive::Openness => traits.openness,
+            BigFive::Conscientiousness => traits.conscientiousness,
+            BigFive::Extraversion => traits.extraversion,
+            BigFive::Agreeableness => traits.agreeableness,
+            BigFive::Neuroticism => traits.neuroticism,
+        }
+    }
+}
+
+impl Input {
+    pub fn label(&self) -> String {
+        match self {
+            Input::Need(need) => format!("{:?} need", need).to_lowercase(),
+            Input::Distance { .. } => "distance".to_string(),
+            Input::Relationship => "relationship".to_string(),
+            Input::TimeOfDay { .. } => "time of day".to_string(),
+        }
+    }
+}
+
+impl<'a> UtilityInputs<'a> {
+    pub fn new(hour: f32) -> Self {
+        Self { hour: hour.rem_euclid(24.0), ..Self::default() }
+    }
+
+    pub fn with_needs(mut self, needs: &'a Needs) -> Self {
+        self.needs = Some(needs);
+        self
+    }
+
+    pub fn distance(mut self, option: &str, distance: f32) -> Self {
+        self.distances.insert(option.to_string(), distance.max(0.0));
+        self
+    }
+
+    pub fn relationship(mut self, option: &str, liking: f32) -> Self {
+        self.relationships.insert(option.to_string(), liking.clamp(-1.0, 1.0));
+        self
+    }
+
+    /// How memories of `option` feel, -1..1. Leans the choice after
+    /// scoring, like experience does, rather than through a consideration.
+    pub fn memory(mut self, option: &str, lean: f32) -> Self {
+        self.memories.insert(option.to_string(), lean.clamp(-1.0, 1.0));
+        self
+    }
+
+    pub fn memories(&self) -> &HashMap<String, f32> {
+        &self.memories
+    }
+
+    /// The NPC's circumstances, 0..1 each: how urgent each need is and how
+    /// far through the day it is. What's learned about options is keyed on
+    /// these.
+    pub fn situation(&self) -> HashMap<String, f32> {
+        let mut situation: HashMap<String, f32> = self
+            .needs
+            .map(|needs| {
+                Need::ALL
+                    .iter()
+                    .map(|need| (need.fact_name().to_string(), needs.urgency(*need)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        situation.insert("hour".to_string(), self.hour / 24.0);
+        situation
+    }
+}
+
+impl Consideration {
+    pub fn new(input: Input, curve: ResponseCurve) -> Self {
+        Self { input, curve, invert: false }
+    }
+
+    pub fn inverted(mut self) -> Self {
+        self.invert = true;
+        self
+    }
+
+    /// How much this consideration favours `option`, 0..1.
+    pub fn score(&self, option: &str, inputs: &UtilityInputs) -> f32 {
+        let x = match &self.input {
+            Input::Need(need) => inputs.needs.map_or(0.0, |needs| needs.urgency(*need)),
+            // An unknown target is neither near nor far
+            Input::Distance { max } => inputs.distances
+                .get(option)
+                .map_or(0.5, |distance| distance / max.max(f32::EPSILON)),
+            Input::Relationship => inputs.relationships.get(option).map_or(0.5, |liking| (liking + 1.0) / 2.0),
+            Input::TimeOfDay { peak } => {
+                let apart = (inputs.hour - peak).rem_euclid(24.0);
+                apart.min(24.0 - apart) / HALF_DAY
+            }
+        };
+        let y = self.curve.evaluate(x);
+        if self.invert { 1.0 - y } else { y }
+    }
+}
+
+impl UtilityOption {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            considerations: Vec::new(),
+            weight: neutral_weight(),
+            personality: Vec::new(),
+        }
+    }
+
+    pub fn consider(mut self, consideration: Consideration) -> Self {
+        self.considerations.push(consideration);
+        self
+    }
+
+    pub fn leaning(mut self, factor: BigFive, strength: f32) -> Self {
+        self.personality.push((factor, strength));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Product of the considerations, compensated so options with many
+    /// considerations aren't dragged down just for having more of them,
+    /// then scaled by the designer weight and personality.
+    pub fn score(&self, inputs: &UtilityInputs, traits: &PersonalityTraits) -> f32 {
+        if self.considerations.is_empty() {
+            return 0.0;
+        }
+
+        // Step 1: compensated product of the considerations
+        let modification = 1.0 - 1.0 / self.considerations.len() as f32;
+        let mut score = 1.0;
+        for consideration in &self.considerations {
+            let value = consideration.score(&self.name, inputs);
+            let makeup = (1.0 - value) * modification;
+            score *= value + makeup * value;
+            if score <= 0.0 {
+                return 0.0;
+            }
+        }
+
+        // Step 2: personality leans each option up or down around average
+        score * self.weight * self.lean(traits)
+    }
+
+    /// Each consideration's value, then the personality lean and designer
+    /// weight, for explaining a score.
+    pub fn factors(&self, inputs: &UtilityInputs, traits: &PersonalityTraits) -> Vec<(String, f32)> {
+        let mut factors: Vec<(String, f32)> = self.considerations
+            .iter()
+            .map(|consideration| (consideration.input.label(), consideration.score(&self.name, inputs)))
+            .collect();
+        if !self.personality.is_empty() {
+            factors.push(("personality".to_string(), self.lean(traits)));
+        }
+        if (self.weight - 1.0).abs() > f32::EPSILON {
+            factors.push(("designer weight".to_string(), self.weight));
+        }
+        factors
+    }
+
+    fn lean(&self, traits: &PersonalityTraits) -> f32 {
+        let lean: f32 = self.personality
+            .iter()
+            .map(|(factor, strength)| 1.0 + strength * (factor.value(traits) - 0.5) * 2.0)
+            .product();
+        lean.max(0.0)
+    }
+}
+
+impl Default for UtilityDefinitions {
+    fn default() -> Self {
+        let need = |need| Consideration::new(Input::Need(need), ResponseCurve::Logistic { steepness: 10.0, midpoint: 0.4 });
+        let nearby = Consideration::new(Input::Distance { max: 500.0 }, ResponseCurve::Exponential { exponent: 2.0 }).inverted();
+
+        Self {
+            options: vec![
+                UtilityOption::new("eat").consider(need(Need::Hunger)).consider(nearby.clone()),
+                UtilityOption::new("sleep")
+                    .consider(need(Need::Energy))
+                    .consider(Consideration::new(
+                        Input::TimeOfDay { peak: 2.0 },
+                        ResponseCurve::Linear { slope: -1.0, intercept: 1.2 },
+                    )),
+                UtilityOption::new("wash").consider(need(Need::Hygiene)).consider(nearby),
+                UtilityOption::new("chat")
+                    .consider(need(Need::Social))
+                    .consider(Consideration::new(Input::Relationship, ResponseCurve::Linear { slope: 1.0, intercept: 0.0 }))
+                    .leaning(BigFive::Extraversion, 0.5),
+            ],
+        }
+    }
+}
+
+impl UtilityDefinitions {
+    pub fn load(path: &str) -> Result<Self, UtilityError> {
+        let source = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&source)?)
+    }
+
+    pub fn options(&self) -> &[UtilityOption] {
+        &self.options
+    }
+
+    /// Every option with its score, best first.
+    pub fn scores(&self, inputs: &UtilityInputs, traits: &PersonalityTraits) -> Vec<(String, f32)> {
+        let mut scores: Vec<(String, f32)> = self.options
+            .iter()
+            .map(|option| (option.name.clone(), option.score(inputs, traits)))
+            .collect();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scores
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn curves_map_their_input_into_unit_range() {
+        let linear = ResponseCurve::Linear { slope: -1.0, intercept: 1.2 };
+        assert!(close(linear.evaluate(0.0), 1.0));
+        assert!(close(linear.evaluate(0.5), 0.7));
+
+        let logistic = ResponseCurve::Logistic { steepness: 10.0, midpoint: 0.4 };
+        assert!(close(logistic.evaluate(0.4), 0.5));
+        assert!(logistic.evaluate(0.0) < 0.05 && logistic.evaluate(1.0) > 0.95);
+
+        let exponential = ResponseCurve::Exponential { exponent: 2.0 };
+        assert!(close(exponential.evaluate(0.5), 0.25));
+        // Inputs outside 0..1 are clamped first
+        assert!(close(exponential.evaluate(3.0), 1.0));
+
+        let step = ResponseCurve::Step { threshold: 0.6 };
+        assert_eq!(step.evaluate(0.59), 0.0);
+        assert_eq!(step.evaluate(0.6), 1.0);
+    }
+
+    #[test]
+    fn inverted_considerations_favour_the_near() {
+        let near = Consideration::new(Input::Distance { max: 100.0 }, ResponseCurve::Linear { slope: 1.0, intercept: 0.0 })
+            .inverted();
+        let inputs = UtilityInputs::new(12.0).distance("eat", 25.0).distance("wash", 100.0);
+
+        assert!(close(near.score("eat", &inputs), 0.75));
+        assert!(close(near.score("wash", &inputs), 0.0));
+    }
+
+    #[test]
+    fn missing_inputs_score_neutral() {
+        let linear = ResponseCurve::Linear { slope: 1.0, intercept: 0.0 };
+        let inputs = UtilityInputs::new(12.0);
+
+        let far = Consideration::new(Input::Distance { max: 500.0 }, linear);
+        assert!(close(far.score("eat", &inputs), 0.5));
+        assert!(close(far.clone().inverted().score("eat", &inputs), 0.5));
+        assert!(close(Consideration::new(Input::Relationship, linear).score("chat", &inputs), 0.5));
+    }
+
+    #[test]
+    fn a_hungry_npc_with_no_known_tavern_still_wants_to_eat() {
+        let mut needs = Needs::default();
+        needs.set_value(Need::Hunger, 0.1);
+        let inputs = UtilityInputs::new(12.0).with_needs(&needs);
+        let scores = UtilityDefinitions::default().scores(&inputs, &PersonalityTraits::default());
+
+        assert_eq!(scores[0].0, "eat");
+        assert!(scores[0].1 > 0.0);
+    }
+}
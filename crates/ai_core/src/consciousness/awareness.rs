@@ -0,0 +1,111 @@
+use serde::{Serialize, Deserialize};
+use rand::Rng;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwarenessState {
+    level: f32,
+    is_aware: bool,
+    uncertainty: f32,
+    last_update: f32,
+    /// Moments that changed how the NPC sees its world, oldest first
+    #[serde(default)]
+    milestones: Vec<AwarenessMilestone>,
+}
+
+/// Uncertainty past which an unaware NPC first doubts its world
+const DOUBT_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwarenessMilestone {
+    pub kind: MilestoneKind,
+    /// Seconds of the NPC's life when it happened
+    pub at: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MilestoneKind {
+    /// Knew the truth about the world from the start
+    Awakened,
+    /// First real doubt that the world is what it seems
+    FirstDoubt,
+    ExistentialCrisis,
+}
+
+impl AwarenessState {
+    pub fn new(is_aware: bool) -> Self {
+        Self {
+            level: if is_aware { 1.0 } else { 0.2 },
+            is_aware,
+            uncertainty: if is_aware { 0.1 } else { 0.8 },
+            last_update: 0.0,
+            milestones: if is_aware {
+                vec![AwarenessMilestone { kind: MilestoneKind::Awakened, at: 0.0 }]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.last_update += delta_time;
+        
+        if !self.is_aware {
+            // Simulate occasional moments of doubt/clarity for unaware NPCs
+            let mut rng = rand::thread_rng();
+            if rng.gen::<f32>() < 0.01 {
+                self.uncertainty += rng.gen_range(-0.1..0.1);
+                self.uncertainty = self.uncertainty.clamp(0.0, 1.0);
+            }
+        }
+    }
+
+    pub fn get_level(&self) -> f32 {
+        self.level
+    }
+
+    pub fn is_fully_aware(&self) -> bool {
+        self.is_aware && self.level > 0.9 && self.uncertainty < 0.2
+    }
+
+    pub fn get_uncertainty(&self) -> f32 {
+        self.uncertainty
+    }
+
+    pub fn process_revelation(&mut self, intensity: f32) {
+        if !self.is_aware {
+            let before = self.uncertainty;
+            self.uncertainty += intensity * 0.1;
+            self.uncertainty = self.uncertainty.clamp(0.0, 1.0);
+
+            if before < DOUBT_THRESHOLD && self.uncertainty >= DOUBT_THRESHOLD && !self.has_milestone(MilestoneKind::FirstDoubt) {
+                self.record_milestone(MilestoneKind::FirstDoubt);
+            }
+            
+            // Potentially trigger existential crisis
+            if self.uncertainty > 0.9 {
+                self.trigger_existential_crisis();
+            }
+        }
+    }
+
+    pub fn milestones(&self) -> &[AwarenessMilestone] {
+        &self.milestones
+    }
+
+    fn has_milestone(&self, kind: MilestoneKind) -> bool {
+        self.milestones.iter().any(|m| m.kind == kind)
+    }
+
+    fn record_milestone(&mut self, kind: MilestoneKind) {
+        self.milestones.push(AwarenessMilestone { kind, at: self.last_update });
+    }
+
+    fn trigger_existential_crisis(&mut self) {
+        // Still in the last crisis, not a new one
+        if self.milestones.last().map(|m| m.kind) != Some(MilestoneKind::ExistentialCrisis) {
+            self.record_milestone(MilestoneKind::ExistentialCrisis);
+        }
+        self.uncertainty = 1.0;
+        self.level = 0.5; // Balanced between awareness and unawareness
+    }
+}
\ No newline at end of file
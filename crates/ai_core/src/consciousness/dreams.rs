@@ -0,0 +1,315 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::memory::{Memory, MemoryKind};
+
+/// Memory fragments woven into one dream at most
+const MAX_FRAGMENTS: usize = 3;
+/// Memories considered for dreaming, most emotionally charged first
+const DREAM_CANDIDATES: usize = 12;
+const CONNECTORS: &[&str] = &["and then", "but suddenly", "and somehow", "except that", "and all at once"];
+/// Sentence openers that read fine lowercased mid-dream; anything else may
+/// be a name and keeps its capital
+const LOWERCASE_STARTERS: &[&str] = &[
+    "a", "an", "the", "it", "there", "this", "that", "we", "they", "he", "she",
+    "someone", "everyone", "my", "our", "his", "her", "their", "today", "yesterday",
+];
+
+/// Turns the day's emotional residue into dreams while the NPC sleeps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DreamGenerator {
+    pub dreams_per_night: usize,
+    /// Chance a goal turns up in a dream
+    pub goal_chance: f32,
+    /// Chance an existential question turns up, scaled by uncertainty
+    pub question_chance: f32,
+    /// Dreams at least this unsettling raise doubt; calmer ones reassure
+    pub doubt_threshold: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dream {
+    pub content: String,
+    pub kind: DreamKind,
+    /// Memories the dream was recombined from
+    pub sources: Vec<Uuid>,
+    pub related_entities: Vec<Uuid>,
+    pub emotional_value: f32,
+    /// How much the dream challenges the NPC's sense of reality, 0..1
+    pub unsettling: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DreamKind {
+    /// The day replayed, slightly wrong
+    Replay,
+    Nightmare,
+    /// A goal finally within reach
+    Wish,
+    /// Something asks the questions the NPC can't answer awake
+    Existential,
+}
+
+impl Default for DreamGenerator {
+    fn default() -> Self {
+        Self {
+            dreams_per_night: 2,
+            goal_chance: 0.5,
+            question_chance: 0.6,
+            doubt_threshold: 0.5,
+        }
+    }
+}
+
+impl DreamGenerator {
+    /// One dream from what the NPC remembers, wants and wonders about, or
+    /// `None` when there's nothing to dream of.
+    pub fn dream<R: Rng>(
+        &self,
+        memories: &[&Memory],
+        goals: &[String],
+        questions: &[String],
+        uncertainty: f32,
+        rng: &mut R,
+    ) -> Option<Dream> {
+        // Emotionally charged memories are what dreams are made of
+        let mut seen = HashSet::new();
+        let mut candidates: Vec<&Memory> = memories
+            .iter()
+            .copied()
+            .filter(|m| m.kind() != MemoryKind::Dream && seen.insert(m.id()))
+            .collect();
+        candidates.sort_by(|a, b| charge(b).partial_cmp(&charge(a)).unwrap_or(std::cmp::Ordering::Equal));
+        candidates.truncate(DREAM_CANDIDATES);
+
+        let count = rng.gen_range(1..=MAX_FRAGMENTS).min(candidates.len());
+        let picked: Vec<&Memory> = candidates
+            .choose_multiple_weighted(rng, count, |m| charge(m) + 0.05)
+            .map(|picked| picked.copied().collect())
+            .unwrap_or_default();
+
+        let goal = goals.choose(rng).filter(|_| rng.gen::<f32>() < self.goal_chance);
+        let question = questions
+            .choose(rng)
+            .filter(|_| rng.gen::<f32>() < self.question_chance * uncertainty.clamp(0.0, 1.0));
+        if picked.is_empty() && goal.is_none() && question.is_none() {
+            return None;
+        }
+
+        let emotional_value = if picked.is_empty() {
+            0.0
+        } else {
+            picked.iter().map(|m| m.emotional_value()).sum::<f32>() / picked.len() as f32
+        };
+
+        let kind = if question.is_some() {
+            DreamKind::Existential
+        } else if emotional_value < -0.3 {
+            DreamKind::Nightmare
+        } else if goal.is_some() {
+            DreamKind::Wish
+        } else {
+            DreamKind::Replay
+        };
+
+        let fragments: Vec<String> = picked.iter().map(|m| fragment(m.content())).collect();
+        let content = compose(kind, &fragments, goal, question, rng);
+
+        // Stitched-together scenes and questions about the world unsettle
+        let unsettling = (fragments.len().saturating_sub(1) as f32 * 0.15
+            + if question.is_some() { 0.5 } else { 0.0 }
+            + (-emotional_value).max(0.0) * 0.3)
+            .clamp(0.0, 1.0);
+
+        let mut related_entities: Vec<Uuid> = Vec::new();
+        for memory in &picked {
+            for entity in memory.related_entities() {
+                if !related_entities.contains(entity) {
+                    related_entities.push(*entity);
+                }
+            }
+        }
+
+        Some(Dream {
+            content,
+            kind,
+            sources: picked.iter().map(|m| m.id()).collect(),
+            related_entities,
+            emotional_value,
+            unsettling,
+        })
+    }
+}
+
+impl Dream {
+    /// How the NPC would bring it up the next morning.
+    pub fn telling(&self) -> String {
+        let opener = match self.kind {
+            DreamKind::Nightmare => "I barely slept, I had an awful dream.",
+            DreamKind::Existential => "I had the strangest dream last night.",
+            DreamKind::Wish => "I had a lovely dream last night.",
+            DreamKind::Replay => "I dreamt about yesterday.",
+        };
+        format!("{} {}", opener, self.content)
+    }
+}
+
+/// The dream that would stick with someone in the morning.
+pub fn most_vivid(dreams: &[Dream]) -> Option<&Dream> {
+    let vividness = |dream: &Dream| dream.emotional_value.abs() + dream.unsettling;
+    dreams
+        .iter()
+        .max_by(|a, b| vividness(a).partial_cmp(&vividness(b)).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// How much a memory wants to be dreamt about.
+fn charge(memory: &Memory) -> f32 {
+    memory.emotional_value().abs() * 0.7 + memory.importance() * 0.3
+}
+
+fn compose<R: Rng>(
+    kind: DreamKind,
+    fragments: &[String],
+    goal: Option<&String>,
+    question: Option<&String>,
+    rng: &mut R,
+) -> String {
+    let mut content = match kind {
+        DreamKind::Nightmare => "I dreamt".to_string(),
+        _ => "I dreamt that".to_string(),
+    };
+
+    for (index, fragment) in fragments.iter().enumerate() {
+        if index > 0 {
+            content.push_str(", ");
+            content.push_str(CONNECTORS.choose(rng).copied().unwrap_or("and then"));
+        }
+        content.push(' ');
+        content.push_str(fragment);
+    }
+
+    if let Some(goal) = goal {
+        // Goals read as actions: "open the bakery"
+        let goal = lowercase_first(&fragment(goal));
+        let clause = match kind {
+            DreamKind::Nightmare => format!("I could never {}", goal),
+            _ if fragments.is_empty() => format!("I could finally {}", goal),
+            _ => format!("at last I could {}", goal),
+        };
+        if !fragments.is_empty() {
+            content.push_str(", and");
+        }
+        content.push(' ');
+        content.push_str(&clause);
+    }
+
+    if let Some(question) = question {
+        if fragments.is_empty() && goal.is_none() {
+            content.push_str(" everything was silent");
+        }
+        content.push_str(&format!(", and a voice kept asking: \"{}\"", question));
+    }
+
+    content.push('.');
+    content
+}
+
+/// A memory's content as a clause: lowercase start, no closing punctuation.
+fn fragment(content: &str) -> String {
+    let content = content.trim().trim_end_matches(['.', '!', '?']);
+    let first_word = content.split_whitespace().next().unwrap_or_default().to_lowercase();
+    if !LOWERCASE_STARTERS.contains(&first_word.as_str()) {
+        return content.to_string();
+    }
+
+    lowercase_first(content)
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consciousness::ConsciousnessState;
+    use crate::memory::MemorySystem;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    fn dream(unsettling: f32) -> Dream {
+        Dream {
+            content: "I dreamt that the mill wheel kept turning.".to_string(),
+            kind: DreamKind::Replay,
+            sources: Vec::new(),
+            related_entities: Vec::new(),
+            emotional_value: 0.0,
+            unsettling,
+        }
+    }
+
+    #[test]
+    fn dreams_are_made_of_charged_memories() {
+        let generator = DreamGenerator { goal_chance: 0.0, question_chance: 0.0, ..Default::default() };
+        let dull: Vec<Memory> = (0..8).map(|i| Memory::observed(&format!("Swept the step {} times", i), 0.0, 0.0)).collect();
+        let charged = Memory::observed("A wolf chased me past the well", -0.9, 0.0);
+        let memories: Vec<&Memory> = dull.iter().chain([&charged]).collect();
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let mut picks: HashMap<Uuid, usize> = HashMap::new();
+        for _ in 0..100 {
+            let dream = generator.dream(&memories, &[], &[], 0.0, &mut rng).unwrap();
+            for source in dream.sources {
+                *picks.entry(source).or_default() += 1;
+            }
+        }
+        let charged_picks = picks.get(&charged.id()).copied().unwrap_or(0);
+        assert!(charged_picks > 50, "charged memory in only {} dreams", charged_picks);
+        assert!(dull.iter().all(|m| picks.get(&m.id()).copied().unwrap_or(0) * 2 < charged_picks));
+    }
+
+    #[test]
+    fn goals_turn_up_as_wishes() {
+        let generator = DreamGenerator { goal_chance: 1.0, ..Default::default() };
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let dream = generator.dream(&[], &["Open the bakery".to_string()], &[], 0.0, &mut rng).unwrap();
+        assert_eq!(dream.kind, DreamKind::Wish);
+        assert_eq!(dream.content, "I dreamt that I could finally open the bakery.");
+        // Nothing to remember, want or wonder about: no dream
+        assert!(generator.dream(&[], &[], &[], 0.0, &mut rng).is_none());
+    }
+
+    #[test]
+    fn dreams_are_remembered_but_not_trusted() {
+        let mut memory = MemorySystem::default();
+        memory.add_dream(&dream(0.1));
+
+        let stored = memory.dreams_since(0.0);
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].kind(), MemoryKind::Dream);
+        assert!(stored[0].reliability() < Memory::observed("", 0.0, 0.0).reliability());
+    }
+
+    #[test]
+    fn unsettling_dreams_raise_doubt_and_calm_ones_reassure() {
+        let mut consciousness = ConsciousnessState::new(false);
+        consciousness.reality_perception.add_anchor("the mill wheel turns".to_string(), 0.5);
+        let doubt = consciousness.awareness.get_uncertainty();
+
+        consciousness.absorb(&dream(0.1));
+        assert_eq!(consciousness.awareness.get_uncertainty(), doubt);
+        assert!(consciousness.reality_perception.anchor_strength("the mill wheel turns").unwrap() > 0.5);
+
+        consciousness.absorb(&dream(0.9));
+        assert!(consciousness.awareness.get_uncertainty() > doubt);
+    }
+}
\ No newline at end of file
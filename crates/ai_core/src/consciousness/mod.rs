@@ -0,0 +1,181 @@
+use serde::{Serialize, Deserialize};
+
+pub mod awareness;
+pub mod reality;
+pub mod simulation;
+pub mod dreams;
+
+use awareness::AwarenessState;
+use reality::RealityPerception;
+use simulation::ConsciousnessSimulation;
+use dreams::{Dream, DreamGenerator};
+
+use crate::dialogue::DialogueSystem;
+use crate::goals::GoalSystem;
+use crate::memory::consolidation::ConsolidationReport;
+use crate::memory::MemorySystem;
+
+/// How much each memory caught contradicting itself shakes the NPC
+const CONTRADICTION_DOUBT: f32 = 0.5;
+/// How it feels to remember something two ways
+const CONTRADICTION_FEELING: f32 = -0.3;
+
+/// What a night's sleep did: how the day was consolidated and what was
+/// dreamt.
+#[derive(Debug, Clone, Default)]
+pub struct Night {
+    pub report: ConsolidationReport,
+    pub dreams: Vec<Dream>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct ConsciousnessState {
+    awareness: AwarenessState,
+    reality_perception: RealityPerception,
+    simulation: ConsciousnessSimulation,
+    #[serde(default)]
+    dreams: DreamGenerator,
+    is_aware: bool,
+}
+
+impl ConsciousnessState {
+    pub fn new(is_aware: bool) -> Self {
+        Self {
+            awareness: AwarenessState::new(is_aware),
+            reality_perception: RealityPerception::default(),
+            simulation: ConsciousnessSimulation::default(),
+            dreams: DreamGenerator::default(),
+            is_aware,
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        // Update awareness state
+        self.awareness.update(delta_time);
+        
+        // Update reality perception based on awareness
+        self.reality_perception.update(&self.awareness);
+        
+        // Run consciousness simulation
+        self.simulation.update(delta_time, &self.awareness, &self.reality_perception);
+    }
+
+    pub fn get_awareness_level(&self) -> f32 {
+        self.awareness.get_level()
+    }
+
+    /// Sleeps on the day: consolidates memories, then dreams. The most vivid
+    /// dream is kept to tell whoever the NPC greets next. Takes the parts
+    /// rather than an `Npc` so spawned NPCs, whose parts are components,
+    /// sleep the same way.
+    pub fn sleep(&mut self, memory: &mut MemorySystem, goals: &GoalSystem, dialogue: &mut DialogueSystem) -> Night {
+        let report = memory.consolidate();
+
+        let goals: Vec<String> = goals
+            .get_active_goals()
+            .iter()
+            .map(|goal| goal.description().to_string())
+            .collect();
+        let dreams = self.dream(memory, &goals);
+        if let Some(vivid) = dreams::most_vivid(&dreams) {
+            dialogue.remember_dream(vivid.telling());
+        }
+
+        Night { report, dreams }
+    }
+
+    /// A night's dreams, recombined from charged memories, goals and the
+    /// NPC's open questions. Each is stored as a dream memory; unsettling
+    /// ones feed doubt, calm ones reinforce what the NPC holds true.
+    pub fn dream(&mut self, memory: &mut MemorySystem, goals: &[String]) -> Vec<Dream> {
+        let mut rng = rand::thread_rng();
+        let questions = self.simulation.existential_questions().to_vec();
+        let mut dreams = Vec::new();
+
+        for _ in 0..self.dreams.dreams_per_night {
+            let dream = self.dreams.dream(
+                &memory.memories(),
+                goals,
+                &questions,
+                self.awareness.get_uncertainty(),
+                &mut rng,
+            );
+            let Some(dream) = dream else {
+                break;
+            };
+
+            self.absorb(&dream);
+            memory.add_dream(&dream);
+            dreams.push(dream);
+        }
+
+        dreams
+    }
+
+    /// Lets a dream sink in: unsettling ones feed doubt, calm ones
+    /// reinforce what the NPC holds true.
+    fn absorb(&mut self, dream: &Dream) {
+        if dream.unsettling >= self.dreams.doubt_threshold {
+            self.awareness.process_revelation(dream.unsettling);
+        } else {
+            self.reality_perception.reinforce_anchors(&dream.content, 1.0 - dream.unsettling);
+        }
+    }
+
+    /// Takes in what the NPC caught its memories contradicting since the
+    /// last call: each feeds doubt, and is remembered in its own right.
+    pub fn notice_contradictions(&mut self, memory: &mut MemorySystem) {
+        for contradiction in memory.take_noticed_contradictions() {
+            self.awareness.process_revelation(CONTRADICTION_DOUBT);
+            let Some(remembered) = memory.get_memory(&contradiction.memory).map(|m| m.content().to_string()) else {
+                continue;
+            };
+            memory.add_memory(
+                format!("{} - or was it \"{}\", not \"{}\"?", remembered, contradiction.earlier, contradiction.current),
+                CONTRADICTION_FEELING,
+                Vec::new(),
+            );
+        }
+    }
+
+    pub fn awareness_milestones(&self) -> &[awareness::AwarenessMilestone] {
+        self.awareness.milestones()
+    }
+
+    pub fn get_distortion(&self) -> f32 {
+        self.reality_perception.get_distortion()
+    }
+
+    /// Whether the NPC can ever see through the simulation.
+    pub fn is_aware(&self) -> bool {
+        self.is_aware
+    }
+
+    pub fn is_fully_aware(&self) -> bool {
+        self.is_aware && self.awareness.is_fully_aware()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memories_caught_changing_sow_doubt_and_are_remembered() {
+        let mut consciousness = ConsciousnessState::new(false);
+        let mut memory = MemorySystem::default();
+        memory.set_reality_distortion(0.0, 1.0);
+        memory.remember_differently("A red cart left the market", "A blue cart left the market");
+        assert!(!memory.recall("blue cart market", 1).is_empty());
+        let before = consciousness.awareness.get_uncertainty();
+
+        consciousness.notice_contradictions(&mut memory);
+
+        assert!(consciousness.awareness.get_uncertainty() > before);
+        assert!(memory.memories().iter().any(|m| m.content().contains("or was it \"red\"")));
+        // Each contradiction is taken in once
+        let remembered = memory.memories().len();
+        consciousness.notice_contradictions(&mut memory);
+        assert_eq!(memory.memories().len(), remembered);
+    }
+}
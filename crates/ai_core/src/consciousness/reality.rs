@@ -1,5 +1,9 @@
 use serde::{Serialize, Deserialize};
 use super::awareness::AwarenessState;
+use crate::memory::embedding::tokenize;
+
+/// Anchor strength gained from one fully reassuring experience
+const REINFORCEMENT: f32 = 0.1;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RealityPerception {
@@ -46,6 +50,21 @@ impl RealityPerception {
         });
     }
 
+    /// Strengthens anchors whose belief shares words with `experience`.
+    pub fn reinforce_anchors(&mut self, experience: &str, amount: f32) {
+        let words = tokenize(experience);
+        for anchor in &mut self.reality_anchors {
+            if tokenize(&anchor.belief).iter().any(|word| words.contains(word)) {
+                anchor.strength = (anchor.strength + amount.clamp(0.0, 1.0) * REINFORCEMENT).min(1.0);
+            }
+        }
+    }
+
+    /// How firmly `belief` is held as an anchor, if it is one.
+    pub fn anchor_strength(&self, belief: &str) -> Option<f32> {
+        self.reality_anchors.iter().find(|anchor| anchor.belief == belief).map(|anchor| anchor.strength)
+    }
+
     pub fn get_distortion(&self) -> f32 {
         self.distortion_level
     }
@@ -52,6 +52,10 @@ impl ConsciousnessSimulation {
         });
     }
 
+    pub fn existential_questions(&self) -> &[String] {
+        &self.existential_questions
+    }
+
     fn update_self_awareness(&mut self, awareness: &AwarenessState) {
         let target = if awareness.is_fully_aware() {
             1.0
@@ -98,4 +102,19 @@ impl ConsciousnessSimulation {
         "I understand my role in this simulation.".to_string()
     }
 
-    fn generate_unaware_
\ No newline at end of file
+    fn generate_unaware_thought(&self, reality: &RealityPerception) -> String {
+        // Unaware NPCs only notice that something is off as reality distorts
+        match reality.get_distortion() {
+            x if x > 0.7 => "Something about today feels wrong.".to_string(),
+            x if x > 0.4 => "I could have sworn that was different yesterday.".to_string(),
+            _ => "Just another ordinary day.".to_string(),
+        }
+    }
+
+    fn cleanup_old_thoughts(&mut self) {
+        const THOUGHT_LIFETIME: f32 = 100.0; // Time units a thought lingers
+
+        let now = self.simulation_time;
+        self.inner_dialogue.retain(|thought| now - thought.timestamp < THOUGHT_LIFETIME);
+    }
+}
\ No newline at end of file
@@ -2,6 +2,8 @@ use serde::{Serialize, Deserialize};
 use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+use crate::knowledge::mind::TheoryOfMind;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeceptionSystem {
     known_truths: HashMap<String, Truth>,
@@ -76,12 +78,23 @@ impl DeceptionSystem {
         self.cleanup_deceptions();
     }
 
-    pub fn should_deceive(&self, content: &str) -> f32 {
+    /// How much to lie about `content` to `listener`. There's nothing to
+    /// protect from someone thought to know the truth already (a lie would
+    /// only be caught) or to believe otherwise already.
+    pub fn should_deceive(&self, content: &str, listener: Option<Uuid>, minds: &TheoryOfMind) -> f32 {
         let mut deception_score = 0.0;
 
         // Check if content relates to any protected truths
         for truth in self.known_truths.values() {
-            if content.contains(&truth.content) && truth.protected {
+            if !content.contains(&truth.content) || !truth.protected {
+                continue;
+            }
+            let pointless = listener.is_some_and(|listener| {
+                truth.known_by.contains(&listener)
+                    || minds.knows(listener, &truth.content)
+                    || minds.doubts(listener, &truth.content)
+            });
+            if !pointless {
                 deception_score += truth.importance;
             }
         }
@@ -124,6 +137,13 @@ impl DeceptionSystem {
         self.known_truths.insert(truth.content.clone(), truth);
     }
 
+    /// Whether `content` touches a truth the NPC keeps to itself.
+    pub fn is_protected(&self, content: &str) -> bool {
+        self.known_truths
+            .values()
+            .any(|truth| truth.protected && content.contains(&truth.content))
+    }
+
     pub fn record_deception_result(&mut self, deception_id: Uuid, success: bool, detected: bool, consequence: Option<String>) {
         let event = DeceptionEvent {
             deception_id,
@@ -192,4 +212,54 @@ impl DeceptionSystem {
     pub fn set_conscience_level(&mut self, level: f32) {
         self.conscience_level = level.clamp(0.0, 1.0);
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "the mayor took the tithe";
+
+    fn shameless_keeper() -> DeceptionSystem {
+        let mut deception = DeceptionSystem::default();
+        deception.set_conscience_level(0.0);
+        deception.add_truth(SECRET.to_string(), 0.9, true);
+        deception
+    }
+
+    #[test]
+    fn protected_truths_are_lied_about_to_those_in_the_dark() {
+        let deception = shameless_keeper();
+        let minds = TheoryOfMind::default();
+        let question = format!("is it true that {}?", SECRET);
+
+        assert!(deception.should_deceive(&question, Some(Uuid::new_v4()), &minds) > 0.0);
+        assert!(deception.should_deceive(&question, None, &minds) > 0.0);
+        assert_eq!(deception.should_deceive("how is the harvest?", None, &minds), 0.0);
+    }
+
+    #[test]
+    fn no_lie_to_someone_who_already_knows_or_doubts_the_truth() {
+        let deception = shameless_keeper();
+        let (knows, doubts) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut minds = TheoryOfMind::default();
+        minds.set_belief(&[knows], SECRET, 0.9);
+        minds.set_belief(&[doubts], SECRET, 0.1);
+
+        assert_eq!(deception.should_deceive(SECRET, Some(knows), &minds), 0.0);
+        assert_eq!(deception.should_deceive(SECRET, Some(doubts), &minds), 0.0);
+    }
+
+    #[test]
+    fn unprotected_truths_and_a_conscience_keep_npcs_honest() {
+        let minds = TheoryOfMind::default();
+        let mut open = DeceptionSystem::default();
+        open.set_conscience_level(0.0);
+        open.add_truth(SECRET.to_string(), 0.9, false);
+        assert_eq!(open.should_deceive(SECRET, None, &minds), 0.0);
+
+        let mut honest = shameless_keeper();
+        honest.set_conscience_level(0.9);
+        assert_eq!(honest.should_deceive(SECRET, None, &minds), 0.0);
+    }
 }
\ No newline at end of file
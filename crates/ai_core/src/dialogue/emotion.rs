@@ -0,0 +1,123 @@
+use serde::{Serialize, Deserialize};
+
+use crate::personality::emotions::EmotionalState;
+
+/// How the NPC's feelings come through in what it says. Holds no emotions
+/// of its own: it mirrors the NPC's `EmotionalState` via `express`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalExpression {
+    current_emotion: String,
+    emotion_intensity: f32,
+    mood: f32,
+    empathy_level: f32,
+    /// How much of what the NPC feels shows in its words
+    expressiveness: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalResponse {
+    base_text: String,
+    emotional_modifiers: Vec<EmotionalModifier>,
+    intensity_level: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalModifier {
+    emotion: String,
+    text_modification: String,
+    intensity_threshold: f32,
+}
+
+impl Default for EmotionalExpression {
+    fn default() -> Self {
+        Self {
+            current_emotion: "neutral".to_string(),
+            emotion_intensity: 0.0,
+            mood: 0.5,
+            empathy_level: 0.5,
+            expressiveness: 0.6,
+        }
+    }
+}
+
+impl EmotionalExpression {
+    /// Takes on what the NPC currently feels.
+    pub fn express(&mut self, state: &EmotionalState) {
+        match state.expressed_emotion() {
+            Some((emotion, intensity)) => {
+                self.current_emotion = emotion.label().to_string();
+                self.emotion_intensity = intensity * (0.5 + self.expressiveness * 0.5);
+            }
+            None => {
+                self.current_emotion = "neutral".to_string();
+                self.emotion_intensity = 0.0;
+            }
+        }
+        self.mood = state.mood();
+    }
+
+    pub fn modify_response(&self, base_response: String) -> EmotionalResponse {
+        let mut modifiers = Vec::new();
+
+        // Add emotion-specific modifiers
+        let (text_modification, intensity_threshold) = match self.current_emotion.as_str() {
+            "happy" | "proud" | "grateful" => ("!", 0.5),
+            "sad" | "ashamed" | "afraid" => ("...", 0.3),
+            "angry" | "disapproving" => ("!", 0.6),
+            _ => ("", 1.0),
+        };
+        if !text_modification.is_empty() {
+            modifiers.push(EmotionalModifier {
+                emotion: self.current_emotion.clone(),
+                text_modification: text_modification.to_string(),
+                intensity_threshold,
+            });
+        }
+
+        EmotionalResponse {
+            base_text: base_response,
+            emotional_modifiers: modifiers,
+            intensity_level: self.emotion_intensity,
+        }
+    }
+
+    pub fn get_current_emotion(&self) -> String {
+        self.current_emotion.clone()
+    }
+
+    pub fn get_mood(&self) -> f32 {
+        self.mood
+    }
+
+    pub fn get_empathetic_response(&self, target_emotion: &str) -> String {
+        let empathy_factor = self.empathy_level;
+        let response = match target_emotion {
+            "happy" | "proud" | "grateful" => if empathy_factor > 0.5 {
+                "I'm glad to hear that!"
+            } else {
+                "That's good."
+            },
+            "sad" | "ashamed" | "afraid" => if empathy_factor > 0.5 {
+                "I'm sorry you're feeling that way..."
+            } else {
+                "That must be difficult."
+            },
+            "angry" | "disapproving" => if empathy_factor > 0.5 {
+                "I understand why you'd feel that way."
+            } else {
+                "I see."
+            },
+            _ => "I understand.",
+        };
+
+        response.to_string()
+    }
+
+    pub fn set_expressiveness(&mut self, value: f32) {
+        self.expressiveness = value.clamp(0.0, 1.0);
+    }
+
+    pub fn set_empathy_level(&mut self, level: f32) {
+        self.empathy_level = level.clamp(0.0, 1.0);
+    }
+}
\ No newline at end of file
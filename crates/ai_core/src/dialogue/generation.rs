@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use crate::ai::dialogue::context::DialogueContext;
+use crate::dialogue::context::DialogueContext;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseGenerator {
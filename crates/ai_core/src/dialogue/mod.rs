@@ -0,0 +1,301 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+pub mod generation;
+pub mod context;
+pub mod emotion;
+pub mod deception;
+
+use context::DialogueContext;
+use emotion::EmotionalExpression;
+use deception::DeceptionSystem;
+use crate::knowledge::mind::TheoryOfMind;
+use crate::memory::MemorySystem;
+
+/// How many memories a message can bring to mind
+const RECALLED_MEMORIES: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct DialogueSystem {
+    context: DialogueContext,
+    #[serde(default)]
+    expression: EmotionalExpression,
+    deception: DeceptionSystem,
+    conversation_history: VecDeque<DialogueEntry>,
+    active_topics: HashMap<String, f32>, // topic -> interest level
+    participant_states: HashMap<Uuid, ParticipantState>,
+    /// Last night's dream, told to the first person greeted
+    #[serde(default)]
+    dream_to_share: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueEntry {
+    speaker: Uuid,
+    content: String,
+    emotion: String,
+    intent: DialogueIntent,
+    deception_level: f32,
+    timestamp: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantState {
+    id: Uuid,
+    trust_level: f32,
+    emotional_stance: String,
+    topics_of_interest: Vec<String>,
+    last_interaction: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DialogueIntent {
+    Question,
+    Statement,
+    Response,
+    Greeting,
+    Farewell,
+    Agreement,
+    Disagreement,
+    Clarification,
+    Deception,
+}
+
+impl Default for DialogueSystem {
+    fn default() -> Self {
+        Self {
+            context: DialogueContext::default(),
+            expression: EmotionalExpression::default(),
+            deception: DeceptionSystem::default(),
+            conversation_history: VecDeque::with_capacity(100),
+            active_topics: HashMap::new(),
+            participant_states: HashMap::new(),
+            dream_to_share: None,
+        }
+    }
+}
+
+impl DialogueEntry {
+    pub fn speaker(&self) -> Uuid {
+        self.speaker
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn emotion(&self) -> &str {
+        &self.emotion
+    }
+}
+
+impl DialogueSystem {
+    pub fn update(&mut self, delta_time: f32) {
+        // Update conversation context
+        self.context.update(delta_time);
+        
+        // Update deception system
+        self.deception.update(delta_time);
+        
+        // Clean up old conversation history
+        self.cleanup_history();
+    }
+
+    pub fn generate_response(
+        &mut self,
+        speaker_id: Uuid,
+        message: &str,
+        current_context: Option<String>,
+        minds: &TheoryOfMind,
+        memory: &mut MemorySystem,
+    ) -> DialogueEntry {
+        if let Some(topic) = current_context {
+            self.context.push_topic(topic);
+        }
+
+        // Get emotional context
+        let emotional_context = self.expression.get_current_emotion();
+
+        // Check for deception
+        let deception_level = self.deception.should_deceive(message, Some(speaker_id), minds);
+
+        // Recall relevant memories; talking about them keeps them fresh
+        let memories: Vec<String> = memory
+            .recall(message, RECALLED_MEMORIES)
+            .into_iter()
+            .map(|scored| scored.memory.content().to_string())
+            .collect();
+
+        // Generate response based on all factors
+        let response = generation::generate_response(
+            message,
+            &self.context,
+            &emotional_context,
+            deception_level,
+            &memories,
+        );
+
+        // Mention last night's dream when saying good morning
+        let intent = self.determine_intent(message);
+        let response = match (&intent, self.dream_to_share.take()) {
+            (DialogueIntent::Greeting, Some(dream)) => format!("{} {}", response, dream),
+            (_, dream) => {
+                self.dream_to_share = dream;
+                response
+            }
+        };
+
+        let entry = DialogueEntry {
+            speaker: speaker_id,
+            content: response,
+            emotion: emotional_context,
+            intent,
+            deception_level,
+            timestamp: 0.0, // Current time should be passed
+        };
+
+        // Update conversation history
+        self.conversation_history.push_back(entry.clone());
+        
+        entry
+    }
+
+    /// Colours what the NPC says with how it feels right now.
+    pub fn express(&mut self, emotions: &crate::personality::emotions::EmotionalState) {
+        self.expression.express(emotions);
+    }
+
+    /// Something to bring up at the next greeting; replaces any older dream.
+    pub fn remember_dream(&mut self, telling: String) {
+        self.dream_to_share = Some(telling);
+    }
+
+    pub fn add_participant(&mut self, id: Uuid) {
+        let state = ParticipantState {
+            id,
+            trust_level: 0.5,
+            emotional_stance: "neutral".to_string(),
+            topics_of_interest: Vec::new(),
+            last_interaction: 0.0,
+        };
+
+        self.participant_states.insert(id, state);
+    }
+
+    pub fn update_trust(&mut self, participant_id: Uuid, change: f32) {
+        if let Some(state) = self.participant_states.get_mut(&participant_id) {
+            state.trust_level = (state.trust_level + change).clamp(0.0, 1.0);
+        }
+    }
+
+    /// What to bring up with `listener`: the most interesting topic they're
+    /// not thought to know about yet, keeping protected truths back.
+    pub fn choose_topic(&self, listener: Uuid, candidates: &[String], minds: &TheoryOfMind) -> Option<String> {
+        candidates
+            .iter()
+            .filter(|topic| !self.deception.is_protected(topic))
+            .map(|topic| {
+                let interest = self.active_topics.get(topic).copied().unwrap_or(0.5);
+                let novelty = 1.0 - minds.belief(&[listener], topic).unwrap_or(0.0);
+                (topic, interest * novelty)
+            })
+            .filter(|(_, score)| *score > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(topic, _)| topic.clone())
+    }
+
+    pub fn set_topic_interest(&mut self, topic: String, interest: f32) {
+        self.active_topics.insert(topic, interest.clamp(0.0, 1.0));
+    }
+
+    pub fn deception_mut(&mut self) -> &mut DeceptionSystem {
+        &mut self.deception
+    }
+
+    fn determine_intent(&self, message: &str) -> DialogueIntent {
+        // Whole words only, so "this" and "think" aren't greetings
+        let message_lower = message.to_lowercase();
+        let words: Vec<&str> = message_lower.split(|c: char| !c.is_alphanumeric()).collect();
+        let says = |word: &str| words.contains(&word);
+
+        if message.ends_with('?') {
+            DialogueIntent::Question
+        } else if says("hello") || says("hi") {
+            DialogueIntent::Greeting
+        } else if says("bye") || says("goodbye") {
+            DialogueIntent::Farewell
+        } else {
+            DialogueIntent::Statement
+        }
+    }
+
+    fn cleanup_history(&mut self) {
+        while self.conversation_history.len() > 100 {
+            self.conversation_history.pop_front();
+        }
+    }
+
+    pub fn get_recent_messages(&self, count: usize) -> Vec<&DialogueEntry> {
+        self.conversation_history
+            .iter()
+            .rev()
+            .take(count)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greetings_and_farewells_are_whole_words() {
+        let dialogue = DialogueSystem::default();
+
+        assert!(matches!(dialogue.determine_intent("Hi, Mara"), DialogueIntent::Greeting));
+        assert!(matches!(dialogue.determine_intent("Well, hello there"), DialogueIntent::Greeting));
+        assert!(matches!(dialogue.determine_intent("Goodbye for now"), DialogueIntent::Farewell));
+        assert!(matches!(dialogue.determine_intent("I think this is his"), DialogueIntent::Statement));
+        assert!(matches!(dialogue.determine_intent("The abyss is deep"), DialogueIntent::Statement));
+    }
+
+    #[test]
+    fn replies_bring_up_the_npcs_own_memories() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("The mill burned down in the storm".to_string(), -0.6, Vec::new());
+        let mut dialogue = DialogueSystem::default();
+
+        dialogue.generate_response(Uuid::new_v4(), "Whatever happened to the mill?", None, &TheoryOfMind::default(), &mut memory);
+
+        // Recalled in conversation, so rehearsed against forgetting
+        let recalled = memory.memories().into_iter().find(|m| m.content().contains("mill")).unwrap();
+        assert_eq!(recalled.recall_count(), 1);
+    }
+
+    #[test]
+    fn topics_are_news_to_the_listener_and_secrets_stay_kept() {
+        let listener = Uuid::new_v4();
+        let candidates = [
+            "the bridge washed out".to_string(),
+            "a wolf was seen by the mill".to_string(),
+            "the mayor took the tithe".to_string(),
+        ];
+        let mut dialogue = DialogueSystem::default();
+        dialogue.set_topic_interest(candidates[0].clone(), 0.9);
+        dialogue.set_topic_interest(candidates[1].clone(), 0.4);
+        dialogue.set_topic_interest(candidates[2].clone(), 1.0);
+        dialogue.deception_mut().add_truth(candidates[2].clone(), 0.9, true);
+
+        let mut minds = TheoryOfMind::default();
+        assert_eq!(dialogue.choose_topic(listener, &candidates, &minds), Some(candidates[0].clone()));
+
+        // Old news isn't worth telling, however interesting
+        minds.set_belief(&[listener], &candidates[0], 1.0);
+        assert_eq!(dialogue.choose_topic(listener, &candidates, &minds), Some(candidates[1].clone()));
+
+        minds.set_belief(&[listener], &candidates[1], 1.0);
+        assert_eq!(dialogue.choose_topic(listener, &candidates, &minds), None);
+    }
+}
\ No newline at end of file
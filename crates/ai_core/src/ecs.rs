@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    cognition::CognitionSystem, consciousness::ConsciousnessState, dialogue::DialogueSystem,
+    goals::GoalSystem, knowledge::KnowledgeBase, memory::MemorySystem,
+    personality::{emotions::EmotionalState, PersonalityTraits}, social::{SocialBehavior, SocialNetwork},
+    time::TimeSystem, Mind, Npc,
+};
+
+/// Runs NPC minds, and the social network they share, as ECS systems.
+pub struct AiPlugin;
+
+impl Plugin for AiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SocialNetwork>()
+            .init_resource::<TimeSystem>()
+            .add_systems(Update, (update_npc_brains, update_social_network).chain());
+    }
+}
+
+/// Stable id of an NPC, shared with the social network, gossip and saves.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NpcId(pub Uuid);
+
+/// Marker for NPCs that know they live in a simulation.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Aware;
+
+/// Everything an NPC thinks with, one component per subsystem so systems
+/// only borrow what they need.
+#[derive(Bundle)]
+pub struct NpcBrainBundle {
+    pub id: NpcId,
+    pub consciousness: ConsciousnessState,
+    pub memory: MemorySystem,
+    pub personality: PersonalityTraits,
+    pub emotions: EmotionalState,
+    pub social: SocialBehavior,
+    pub knowledge: KnowledgeBase,
+    pub goals: GoalSystem,
+    pub dialogue: DialogueSystem,
+    pub cognition: CognitionSystem,
+}
+
+impl Npc {
+    /// Splits the NPC into components, consuming it. Use `Aware` alongside the
+    /// bundle when `is_aware()` was true.
+    pub fn into_brain(self) -> NpcBrainBundle {
+        NpcBrainBundle {
+            id: NpcId(self.id),
+            consciousness: self.consciousness,
+            memory: self.memory,
+            personality: self.personality,
+            emotions: self.emotions,
+            social: self.social,
+            knowledge: self.knowledge,
+            goals: self.goals,
+            dialogue: self.dialogue,
+            cognition: self.cognition,
+        }
+    }
+}
+
+/// ECS counterpart of `Npc::update`: the same `Mind::think`, then the
+/// NPC's place in the shared social network. Runs on the sim clock, so
+/// minds age at the same time scale as the town.
+#[allow(clippy::type_complexity)]
+pub fn update_npc_brains(
+    clock: Res<TimeSystem>,
+    mut network: Option<ResMut<SocialNetwork>>,
+    mut brains: Query<(
+        &NpcId,
+        &mut ConsciousnessState,
+        &mut MemorySystem,
+        &PersonalityTraits,
+        &mut EmotionalState,
+        &mut SocialBehavior,
+        &mut KnowledgeBase,
+        &mut GoalSystem,
+        &mut DialogueSystem,
+        &mut CognitionSystem,
+    )>,
+) {
+    let delta_time = clock.delta_time();
+
+    for (
+        id,
+        mut consciousness,
+        mut memory,
+        personality,
+        mut emotions,
+        mut social,
+        mut knowledge,
+        mut goals,
+        mut dialogue,
+        mut cognition,
+    ) in &mut brains
+    {
+        Mind {
+            consciousness: &mut consciousness,
+            memory: &mut memory,
+            personality,
+            emotions: &mut emotions,
+            knowledge: &mut knowledge,
+            goals: &mut goals,
+            dialogue: &mut dialogue,
+            cognition: &mut cognition,
+        }
+        .think(delta_time);
+
+        // Handle social behaviors and interactions
+        if let Some(network) = network.as_deref_mut() {
+            social.update(network, id.0);
+        }
+    }
+}
+
+/// Ages relationships, groups and gossip in the town's social network.
+pub fn update_social_network(clock: Res<TimeSystem>, mut network: ResMut<SocialNetwork>) {
+    network.update(clock.delta_time());
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default)]
 pub struct AchievementTracker {
     achievements: HashMap<Uuid, Achievement>,
     completed_goals: HashSet<Uuid>,
@@ -65,36 +66,73 @@ pub struct AchievementStats {
     highest_difficulty_achieved: f32,
 }
 
-impl Default for AchievementTracker {
-    fn default() -> Self {
-        Self {
-            achievements: HashMap::new(),
-            completed_goals: HashSet::new(),
-            milestones: HashMap::new(),
-            progress_history: Vec::new(),
-            achievement_stats: AchievementStats::default(),
-        }
+
+impl Achievement {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn difficulty(&self) -> f32 {
+        self.difficulty
+    }
+
+    pub fn completion_date(&self) -> Option<f32> {
+        self.completion_date
+    }
+}
+
+impl Milestone {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn achievement_date(&self) -> Option<f32> {
+        self.achievement_date
     }
 }
 
 impl AchievementTracker {
-    pub fn update(&mut self, delta_time: f32) {
+    pub fn update(&mut self, _delta_time: f32) {
         // Update progress on all achievements
         for achievement in self.achievements.values_mut() {
-            self.update_achievement_progress(achievement);
+            if let Some(event) = Self::update_achievement_progress(achievement) {
+                self.progress_history.push(event);
+            }
         }
 
         // Update milestones
         for milestone in self.milestones.values_mut() {
-            self.check_milestone_completion(milestone);
+            if let Some(event) = Self::check_milestone_completion(milestone) {
+                self.progress_history.push(event);
+            }
         }
 
         // Update statistics
         self.update_stats();
     }
 
+    pub fn completed_achievements(&self) -> Vec<&Achievement> {
+        let mut completed: Vec<&Achievement> = self.achievements
+            .values()
+            .filter(|a| a.completion_date.is_some())
+            .collect();
+        completed.sort_by(|a, b| a.completion_date.partial_cmp(&b.completion_date).unwrap_or(std::cmp::Ordering::Equal));
+        completed
+    }
+
+    pub fn reached_milestones(&self) -> Vec<&Milestone> {
+        let mut reached: Vec<&Milestone> = self.milestones.values().filter(|m| m.achieved).collect();
+        reached.sort_by(|a, b| a.achievement_date.partial_cmp(&b.achievement_date).unwrap_or(std::cmp::Ordering::Equal));
+        reached
+    }
+
     pub fn track_completion(&mut self, goal_id: Uuid, description: &str) {
         self.completed_goals.insert(goal_id);
+        log::debug!("goal completed: {}", description);
 
         let event = ProgressEvent {
             goal_id,
@@ -149,49 +187,47 @@ impl AchievementTracker {
         // Update related milestones
         for milestone in self.milestones.values_mut() {
             milestone.current_progress += value;
-            self.check_milestone_completion(milestone);
+            if let Some(event) = Self::check_milestone_completion(milestone) {
+                self.progress_history.push(event);
+            }
         }
     }
 
-    fn update_achievement_progress(&mut self, achievement: &mut Achievement) {
+    fn update_achievement_progress(achievement: &mut Achievement) -> Option<ProgressEvent> {
         let total_requirements = achievement.requirements.len();
         let completed_requirements = achievement.requirements
             .iter()
             .filter(|r| r.completed)
             .count();
 
-        if total_requirements > 0 && completed_requirements == total_requirements {
-            if achievement.completion_date.is_none() {
-                achievement.completion_date = Some(0.0); // Current time should be passed
-
-                let event = ProgressEvent {
-                    goal_id: Uuid::new_v4(), // placeholder
-                    achievement_id: Some(achievement.id),
-                    event_type: ProgressType::Achievement,
-                    value: 1.0,
-                    timestamp: 0.0,
-                };
-
-                self.progress_history.push(event);
-            }
+        if total_requirements == 0 || completed_requirements < total_requirements || achievement.completion_date.is_some() {
+            return None;
         }
-    }
-
-    fn check_milestone_completion(&mut self, milestone: &mut Milestone) {
-        if !milestone.achieved && milestone.current_progress >= milestone.threshold {
-            milestone.achieved = true;
-            milestone.achievement_date = Some(0.0); // Current time should be passed
+        achievement.completion_date = Some(0.0); // Current time should be passed
 
-            let event = ProgressEvent {
-                goal_id: Uuid::new_v4(), // placeholder
-                achievement_id: None,
-                event_type: ProgressType::MilestoneReached,
-                value: milestone.threshold,
-                timestamp: 0.0,
-            };
+        Some(ProgressEvent {
+            goal_id: Uuid::new_v4(), // placeholder
+            achievement_id: Some(achievement.id),
+            event_type: ProgressType::Achievement,
+            value: 1.0,
+            timestamp: 0.0,
+        })
+    }
 
-            self.progress_history.push(event);
+    fn check_milestone_completion(milestone: &mut Milestone) -> Option<ProgressEvent> {
+        if milestone.achieved || milestone.current_progress < milestone.threshold {
+            return None;
         }
+        milestone.achieved = true;
+        milestone.achievement_date = Some(0.0); // Current time should be passed
+
+        Some(ProgressEvent {
+            goal_id: Uuid::new_v4(), // placeholder
+            achievement_id: None,
+            event_type: ProgressType::MilestoneReached,
+            value: milestone.threshold,
+            timestamp: 0.0,
+        })
     }
 
     fn check_achievements_for_goal(&mut self, goal_id: Uuid) {
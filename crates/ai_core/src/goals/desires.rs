@@ -1,6 +1,7 @@
 use serde::{Serialize, Deserialize};
 use std::collections::{HashMap, HashSet};
-use uuid::Uuid;
+
+use super::needs::{Need, Needs};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DesireSystem {
@@ -30,11 +31,43 @@ pub enum DesireCategory {
     Security,    // Safety, stability
 }
 
+impl Default for DesireSystem {
+    fn default() -> Self {
+        let mut system = Self {
+            desires: HashMap::new(),
+            active_desires: HashSet::new(),
+            desire_weights: HashMap::new(),
+            satisfaction_thresholds: HashMap::new(),
+            current_mood: 0.5,
+        };
+        system.initialize_basic_desires();
+        system
+    }
+}
+
+impl Desire {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    pub fn urgency(&self) -> f32 {
+        self.urgency
+    }
+
+    pub fn category(&self) -> &DesireCategory {
+        &self.category
+    }
+}
+
 impl DesireSystem {
     pub fn update(&mut self, delta_time: f32) {
         // Update desire intensities
         for desire in self.desires.values_mut() {
-            self.update_desire_intensity(desire, delta_time);
+            Self::update_desire_intensity(desire, delta_time);
         }
 
         // Update active desires
@@ -82,14 +115,37 @@ impl DesireSystem {
             .collect()
     }
 
+    /// Drives the need-backed desires straight from the meters: an empty
+    /// meter is an intense, urgent desire.
+    pub fn sync_needs(&mut self, needs: &Needs) {
+        for need in Need::ALL {
+            let name = need.desire_name();
+            if !self.desires.contains_key(name) {
+                let category = if need == Need::Social { DesireCategory::Social } else { DesireCategory::Basic };
+                self.add_desire(name.to_string(), category, 0.0);
+            }
+
+            if let Some(desire) = self.desires.get_mut(name) {
+                desire.satisfaction = needs.value(need);
+                desire.intensity = 1.0 - desire.satisfaction;
+                desire.urgency = needs.urgency(need);
+            }
+        }
+
+        self.update_active_desires();
+    }
+
     fn initialize_basic_desires(&mut self) {
+        self.add_desire("Hunger".to_string(), DesireCategory::Basic, 0.0);
+        self.add_desire("Rest".to_string(), DesireCategory::Basic, 0.0);
+        self.add_desire("Hygiene".to_string(), DesireCategory::Basic, 0.0);
         self.add_desire("Social Interaction".to_string(), DesireCategory::Social, 0.5);
         self.add_desire("Achievement".to_string(), DesireCategory::Achievement, 0.3);
         self.add_desire("Learning".to_string(), DesireCategory::Growth, 0.4);
         self.add_desire("Security".to_string(), DesireCategory::Security, 0.6);
     }
 
-    fn update_desire_intensity(&mut self, desire: &mut Desire, delta_time: f32) {
+    fn update_desire_intensity(desire: &mut Desire, delta_time: f32) {
         let base_increase = match desire.category {
             DesireCategory::Basic => 0.1,
             DesireCategory::Social => 0.05,
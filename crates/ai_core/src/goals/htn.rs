@@ -0,0 +1,331 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use thiserror::Error;
+
+use super::planning::ActionTemplate;
+use super::world_state::{Condition, WorldState};
+
+/// Authored task networks shipped with the game
+pub const HTN_DOMAIN_PATH: &str = "behaviours/tasks.ron";
+/// Tasks decomposition may visit before giving up, so recursive methods
+/// can't loop forever
+const MAX_DECOMPOSITIONS: usize = 4096;
+
+#[derive(Debug, Error)]
+pub enum HtnError {
+    #[error("Failed to read task domain: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse task domain: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+
+    #[error("Unknown task: {0}")]
+    UnknownTask(String),
+
+    #[error("Task {task} uses unknown action {action}")]
+    UnknownAction { task: String, action: String },
+}
+
+/// Hierarchical task network for authored behaviour ("run the shop for a
+/// day"): compound tasks break down through the first method whose
+/// preconditions hold, until only primitive tasks, i.e. actions, are left.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Resource))]
+pub struct HtnDomain {
+    #[serde(default)]
+    tasks: HashMap<String, Task>,
+    /// Actions the primitive tasks may use, on top of those the planner
+    /// already knows
+    #[serde(default)]
+    actions: Vec<ActionTemplate>,
+    /// Goal types planned with a task network instead of GOAP, and the
+    /// task each starts from
+    #[serde(default)]
+    goal_types: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Task {
+    Primitive { action: String },
+    Compound { methods: Vec<Method> },
+}
+
+/// One way of doing a compound task, tried in the order written.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Method {
+    name: String,
+    #[serde(default)]
+    preconditions: Vec<Condition>,
+    subtasks: Vec<String>,
+}
+
+impl HtnDomain {
+    pub fn load(path: &str) -> Result<Self, HtnError> {
+        let source = std::fs::read_to_string(path)?;
+        Self::parse(&source)
+    }
+
+    pub fn parse(source: &str) -> Result<Self, HtnError> {
+        Ok(ron::from_str(source)?)
+    }
+
+    pub fn actions(&self) -> &[ActionTemplate] {
+        &self.actions
+    }
+
+    /// Task a goal type starts from, if it's planned with this network.
+    pub fn task_for(&self, goal_type: &str) -> Option<&str> {
+        self.goal_types.get(goal_type).map(String::as_str)
+    }
+
+    pub fn has_task(&self, task: &str) -> bool {
+        self.tasks.contains_key(task)
+    }
+
+    /// Checks that goal types and methods only name defined tasks, and that
+    /// every primitive task's action is one `known` accepts.
+    pub fn check(&self, known: impl Fn(&str) -> bool) -> Result<(), HtnError> {
+        if let Some(task) = self.goal_types.values().find(|task| !self.has_task(task)) {
+            return Err(HtnError::UnknownTask(task.clone()));
+        }
+
+        for (name, task) in &self.tasks {
+            match task {
+                Task::Primitive { action } if !known(action) => {
+                    return Err(HtnError::UnknownAction { task: name.clone(), action: action.clone() });
+                }
+                Task::Primitive { .. } => {}
+                Task::Compound { methods } => {
+                    let subtasks = methods.iter().flat_map(|method| &method.subtasks);
+                    if let Some(subtask) = subtasks.into_iter().find(|subtask| !self.has_task(subtask)) {
+                        return Err(HtnError::UnknownTask(subtask.clone()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Breaks `task` down into actions that can run one after another from
+    /// `start`, avoiding `excluded` ones. `Ok(None)` when no choice of
+    /// methods works out.
+    pub fn decompose<'a>(
+        &self,
+        task: &str,
+        start: &WorldState,
+        templates: &'a HashMap<String, ActionTemplate>,
+        excluded: &HashSet<String>,
+    ) -> Result<Option<Vec<&'a ActionTemplate>>, HtnError> {
+        if !self.tasks.contains_key(task) {
+            return Err(HtnError::UnknownTask(task.to_string()));
+        }
+
+        let mut agenda = VecDeque::new();
+        agenda.push_back(task.to_string());
+        let mut budget = MAX_DECOMPOSITIONS;
+        self.expand(start.clone(), agenda, templates, excluded, &mut budget)
+    }
+
+    /// Depth-first decomposition of the front task, backtracking to the
+    /// next method when one leads nowhere.
+    fn expand<'a>(
+        &self,
+        state: WorldState,
+        mut agenda: VecDeque<String>,
+        templates: &'a HashMap<String, ActionTemplate>,
+        excluded: &HashSet<String>,
+        budget: &mut usize,
+    ) -> Result<Option<Vec<&'a ActionTemplate>>, HtnError> {
+        let Some(name) = agenda.pop_front() else {
+            return Ok(Some(Vec::new()));
+        };
+        if *budget == 0 {
+            return Ok(None);
+        }
+        *budget -= 1;
+
+        match self.tasks.get(&name).ok_or_else(|| HtnError::UnknownTask(name.clone()))? {
+            Task::Primitive { action } => {
+                let template = templates.get(action).ok_or_else(|| HtnError::UnknownAction {
+                    task: name.clone(),
+                    action: action.clone(),
+                })?;
+                if excluded.contains(action) || !state.satisfies(template.prerequisites()) {
+                    return Ok(None);
+                }
+
+                let mut next = state;
+                next.apply(template.effects());
+                Ok(self.expand(next, agenda, templates, excluded, budget)?.map(|mut rest| {
+                    rest.insert(0, template);
+                    rest
+                }))
+            }
+            Task::Compound { methods } => {
+                for method in methods.iter().filter(|m| state.satisfies(&m.preconditions)) {
+                    let mut expanded: VecDeque<String> = method.subtasks.iter().cloned().collect();
+                    expanded.extend(agenda.iter().cloned());
+                    if let Some(actions) = self.expand(state.clone(), expanded, templates, excluded, budget)? {
+                        return Ok(Some(actions));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl Method {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::planning::Planner;
+
+    fn shop() -> HtnDomain {
+        let path = format!("{}/../../{}", env!("CARGO_MANIFEST_DIR"), HTN_DOMAIN_PATH);
+        HtnDomain::load(&path).expect("shipped task domain loads")
+    }
+
+    fn templates(domain: &HtnDomain) -> HashMap<String, ActionTemplate> {
+        domain.actions().iter().map(|action| (action.name().to_string(), action.clone())).collect()
+    }
+
+    fn shop_day(stock: f32, gold: f32) -> WorldState {
+        let mut state = WorldState::new();
+        state.set_number("stock", stock);
+        state.set_number("gold", gold);
+        state
+    }
+
+    fn plan(domain: &HtnDomain, task: &str, start: &WorldState, excluded: &[&str]) -> Option<Vec<String>> {
+        let templates = templates(domain);
+        let excluded = excluded.iter().map(|action| action.to_string()).collect();
+        domain
+            .decompose(task, start, &templates, &excluded)
+            .unwrap()
+            .map(|actions| actions.iter().map(|action| action.name().to_string()).collect())
+    }
+
+    #[test]
+    fn a_stocked_shop_has_an_ordinary_day() {
+        let domain = shop();
+
+        assert_eq!(
+            plan(&domain, "run_shop_for_day", &shop_day(3.0, 0.0), &[]).unwrap(),
+            ["open_shop", "serve_customer", "serve_customer", "close_shop"],
+        );
+        // One item left only lasts until sold out
+        assert_eq!(
+            plan(&domain, "run_shop_for_day", &shop_day(1.0, 0.0), &[]).unwrap(),
+            ["open_shop", "serve_customer", "close_shop"],
+        );
+    }
+
+    #[test]
+    fn an_empty_shop_restocks_first() {
+        let domain = shop();
+
+        assert_eq!(
+            plan(&domain, "run_shop_for_day", &shop_day(0.0, 10.0), &[]).unwrap(),
+            ["go_to_market", "buy_stock", "go_to_shop", "open_shop", "serve_customer", "serve_customer", "close_shop"],
+        );
+        assert_eq!(
+            plan(&domain, "run_shop_for_day", &shop_day(0.0, 0.0), &[]).unwrap(),
+            ["craft_goods", "open_shop", "serve_customer", "serve_customer", "close_shop"],
+        );
+    }
+
+    #[test]
+    fn backtracks_to_the_next_method_when_one_leads_nowhere() {
+        let domain = shop();
+
+        // Buying applies, but can't be done, so the goods get made instead
+        assert_eq!(
+            plan(&domain, "run_shop_for_day", &shop_day(0.0, 10.0), &["buy_stock"]).unwrap(),
+            ["craft_goods", "open_shop", "serve_customer", "serve_customer", "close_shop"],
+        );
+        // Bartering is the last way left to restock
+        assert_eq!(
+            plan(&domain, "run_shop_for_day", &shop_day(0.0, 10.0), &["buy_stock", "craft_goods"]).unwrap(),
+            ["go_to_market", "barter_for_stock", "go_to_shop", "open_shop", "serve_customer", "close_shop"],
+        );
+        // With no way to restock, an ordinary day has nothing to sell
+        let excluded = ["buy_stock", "craft_goods", "barter_for_stock"];
+        assert_eq!(plan(&domain, "run_shop_for_day", &shop_day(0.0, 10.0), &excluded), None);
+    }
+
+    #[test]
+    fn hopeless_searches_stop_at_the_budget() {
+        // Every way through the choices fails at the end; trying them all
+        // would take 2^24 decompositions
+        let choices = vec!["choose".to_string(); 24];
+        let domain = HtnDomain::parse(&format!(
+            r#"(
+                tasks: {{
+                    "root": Compound(methods: [(name: "all", subtasks: {:?})]),
+                    "choose": Compound(methods: [
+                        (name: "left", subtasks: ["step"]),
+                        (name: "right", subtasks: ["step"]),
+                    ]),
+                    "step": Primitive(action: "step"),
+                    "fail": Primitive(action: "fail"),
+                }},
+                actions: [
+                    (name: "step", cost: 1.0, average_duration: 1.0),
+                    (name: "fail", prerequisites: [(fact: "possible", test: is(true))], cost: 1.0, average_duration: 1.0),
+                ],
+            )"#,
+            [choices, vec!["fail".to_string()]].concat(),
+        ))
+        .unwrap();
+
+        assert_eq!(plan(&domain, "root", &WorldState::new(), &[]), None);
+    }
+
+    #[test]
+    fn unknown_tasks_and_actions_are_errors() {
+        let domain = HtnDomain::parse(
+            r#"(
+                tasks: {
+                    "errand": Compound(methods: [(name: "go", subtasks: ["walk", "nowhere"])]),
+                    "walk": Primitive(action: "walk"),
+                    "fly": Primitive(action: "fly"),
+                },
+                actions: [(name: "walk", cost: 1.0, average_duration: 1.0)],
+            )"#,
+        )
+        .unwrap();
+        let templates = templates(&domain);
+        let none = HashSet::new();
+
+        assert!(matches!(
+            domain.decompose("shop", &WorldState::new(), &templates, &none),
+            Err(HtnError::UnknownTask(task)) if task == "shop"
+        ));
+        assert!(matches!(
+            domain.decompose("errand", &WorldState::new(), &templates, &none),
+            Err(HtnError::UnknownTask(task)) if task == "nowhere"
+        ));
+        assert!(matches!(
+            domain.decompose("fly", &WorldState::new(), &templates, &none),
+            Err(HtnError::UnknownAction { task, action }) if task == "fly" && action == "fly"
+        ));
+    }
+
+    #[test]
+    fn shipped_primitives_all_name_known_actions() {
+        let domain = shop();
+        let mut planner = Planner::default();
+        planner.set_task_domain(domain.clone());
+
+        domain.check(|action| planner.knows_action(action)).unwrap();
+        for goal_type in ["run shop", "harvest festival"] {
+            assert!(domain.task_for(goal_type).is_some_and(|task| domain.has_task(task)));
+        }
+    }
+}
\ No newline at end of file
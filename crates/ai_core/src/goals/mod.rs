@@ -0,0 +1,397 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+pub mod planning;
+pub mod desires;
+pub mod motivation;
+pub mod achievement;
+pub mod needs;
+pub mod world_state;
+pub mod htn;
+
+use planning::{ActionTemplate, Planner, StepOutcome, Supervision};
+use desires::DesireSystem;
+use motivation::MotivationSystem;
+use achievement::AchievementTracker;
+use needs::{Need, NeedAction, Needs};
+use world_state::{Condition, WorldState};
+use htn::{HtnDomain, HtnError};
+use crate::knowledge::KnowledgeBase;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+#[derive(Default)]
+pub struct GoalSystem {
+    planner: Planner,
+    desires: DesireSystem,
+    motivation: MotivationSystem,
+    achievement: AchievementTracker,
+    active_goals: HashMap<Uuid, Goal>,
+    #[serde(default)]
+    needs: Needs,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Goal {
+    id: Uuid,
+    description: String,
+    priority: f32,
+    deadline: Option<f32>,
+    status: GoalStatus,
+    dependencies: HashSet<Uuid>,
+    subgoals: Vec<Uuid>,
+    progress: f32,
+    motivation_level: f32,
+    /// Why the goal was given up, when it was
+    #[serde(default)]
+    abandon_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum GoalStatus {
+    Active,
+    Completed,
+    Failed,
+    OnHold,
+    Abandoned,
+}
+
+
+impl Goal {
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn priority(&self) -> f32 {
+        self.priority
+    }
+
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    pub fn abandon_reason(&self) -> Option<&str> {
+        self.abandon_reason.as_deref()
+    }
+}
+
+impl GoalSystem {
+    pub fn update(&mut self, delta_time: f32) {
+        // Needs run down with time and drive the basic desires
+        self.needs.update(delta_time);
+        self.desires.update(delta_time);
+        self.desires.sync_needs(&self.needs);
+        self.planner.observe(&self.need_facts());
+        
+        // Update motivation system
+        self.motivation.update(delta_time);
+        
+        // Update goals and their progress
+        self.update_goals(delta_time);
+        
+        // Track achievements
+        self.achievement.update(delta_time);
+    }
+
+    pub fn create_goal(&mut self, description: String, priority: f32, deadline: Option<f32>) -> Uuid {
+        let goal = Goal {
+            id: Uuid::new_v4(),
+            description,
+            priority,
+            deadline,
+            status: GoalStatus::Active,
+            dependencies: HashSet::new(),
+            subgoals: Vec::new(),
+            progress: 0.0,
+            motivation_level: self.motivation.calculate_initial_motivation(priority),
+            abandon_reason: None,
+        };
+
+        let id = goal.id;
+        self.active_goals.insert(id, goal);
+        id
+    }
+
+    pub fn add_dependency(&mut self, goal_id: Uuid, dependency_id: Uuid) -> bool {
+        if let Some(goal) = self.active_goals.get_mut(&goal_id) {
+            goal.dependencies.insert(dependency_id);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn add_subgoal(&mut self, parent_id: Uuid, description: String, priority: f32) -> Option<Uuid> {
+        if self.active_goals.contains_key(&parent_id) {
+            let subgoal_id = self.create_goal(description, priority, None);
+            if let Some(parent) = self.active_goals.get_mut(&parent_id) {
+                parent.subgoals.push(subgoal_id);
+            }
+            Some(subgoal_id)
+        } else {
+            None
+        }
+    }
+
+    pub fn update_progress(&mut self, goal_id: Uuid, progress: f32) {
+        if let Some(goal) = self.active_goals.get_mut(&goal_id) {
+            goal.progress = progress.clamp(0.0, 1.0);
+            
+            if goal.progress >= 1.0 {
+                goal.status = GoalStatus::Completed;
+                self.achievement.track_completion(goal_id, &goal.description);
+            }
+        }
+    }
+
+    fn update_goals(&mut self, delta_time: f32) {
+        let mut parents = Vec::new();
+        for goal in self.active_goals.values_mut() {
+            // Update motivation level
+            goal.motivation_level = self.motivation.calculate_current_motivation(
+                goal.priority,
+                goal.progress,
+                delta_time
+            );
+
+            // Check deadlines
+            if let Some(deadline) = goal.deadline {
+                if deadline <= 0.0 && goal.status == GoalStatus::Active {
+                    goal.status = GoalStatus::Failed;
+                }
+            }
+
+            if !goal.subgoals.is_empty() {
+                parents.push(goal.id);
+            }
+        }
+
+        // Update progress of parent goals based on subgoals
+        for parent in parents {
+            self.update_parent_progress(parent);
+        }
+    }
+
+    fn update_parent_progress(&mut self, parent_id: Uuid) {
+        let Some(subgoals) = self.active_goals.get(&parent_id).map(|parent| parent.subgoals.clone()) else {
+            return;
+        };
+        let mut total_progress = 0.0;
+        let mut completed_subgoals = 0;
+
+        for subgoal_id in &subgoals {
+            if let Some(subgoal) = self.active_goals.get(subgoal_id) {
+                total_progress += subgoal.progress;
+                if subgoal.status == GoalStatus::Completed {
+                    completed_subgoals += 1;
+                }
+            }
+        }
+
+        let Some(parent) = self.active_goals.get_mut(&parent_id) else {
+            return;
+        };
+        parent.progress = total_progress / subgoals.len() as f32;
+
+        if completed_subgoals == subgoals.len() {
+            parent.status = GoalStatus::Completed;
+            self.achievement.track_completion(parent.id, &parent.description);
+        }
+    }
+
+    pub fn get_active_goals(&self) -> Vec<&Goal> {
+        self.active_goals
+            .values()
+            .filter(|g| g.status == GoalStatus::Active)
+            .collect()
+    }
+
+    pub fn completed_goals(&self) -> Vec<&Goal> {
+        self.active_goals
+            .values()
+            .filter(|g| g.status == GoalStatus::Completed)
+            .collect()
+    }
+
+    pub fn needs(&self) -> &Needs {
+        &self.needs
+    }
+
+    /// Spends `delta_time` sim seconds on something that meets a need, e.g.
+    /// eating at the tavern counter. Returns how far the meter rose.
+    pub fn perform(&mut self, action: NeedAction, delta_time: f32, quality: f32) -> f32 {
+        let gain = self.needs.perform(action, delta_time, quality);
+        self.desires.sync_needs(&self.needs);
+        gain
+    }
+
+    /// Teaches the planner an action it can use.
+    pub fn add_action(&mut self, template: ActionTemplate) {
+        self.planner.add_action_template(template);
+    }
+
+    /// Updates what the planner believes from the NPC's world knowledge.
+    pub fn observe_knowledge(&mut self, knowledge: &KnowledgeBase) {
+        self.planner.observe_knowledge(knowledge);
+    }
+
+    /// Plans how to reach `conditions` for a goal. `None` when the goal is
+    /// unknown or nothing the NPC knows how to do gets there.
+    pub fn plan_for(&mut self, goal_id: Uuid, conditions: &[Condition]) -> Option<Uuid> {
+        if !self.active_goals.contains_key(&goal_id) {
+            return None;
+        }
+        let plan_id = self.planner.create_plan(goal_id);
+        self.planner.generate_steps(plan_id, conditions).then_some(plan_id)
+    }
+
+    /// Plans a goal the way its type calls for: by decomposing an authored
+    /// task if the task domain covers `goal_type`, otherwise by searching
+    /// for `conditions`. The new plan becomes the one the NPC follows.
+    pub fn plan_goal(&mut self, goal_id: Uuid, goal_type: &str, conditions: &[Condition]) -> Result<Option<Uuid>, HtnError> {
+        let Some(task) = self.planner.task_domain().task_for(goal_type).map(str::to_string) else {
+            let planned = self.plan_for(goal_id, conditions);
+            if let Some(plan_id) = planned {
+                self.planner.set_current_plan(plan_id);
+            }
+            return Ok(planned);
+        };
+        if !self.active_goals.contains_key(&goal_id) {
+            return Ok(None);
+        }
+
+        let plan_id = self.planner.create_plan(goal_id);
+        let planned = self.planner.generate_task_steps(plan_id, &task)?.then_some(plan_id);
+        if let Some(plan_id) = planned {
+            self.planner.set_current_plan(plan_id);
+        }
+        Ok(planned)
+    }
+
+    pub fn set_task_domain(&mut self, domain: HtnDomain) {
+        self.planner.set_task_domain(domain);
+    }
+
+    /// Takes on a goal of a known type, e.g. "run shop", and plans it with
+    /// `plan_goal`. A goal there's no way to go about is dropped again.
+    pub fn pursue(&mut self, goal_type: &str, priority: f32, conditions: &[Condition]) -> Result<Option<Uuid>, HtnError> {
+        let goal_id = self.create_goal(goal_type.to_string(), priority, None);
+        match self.plan_goal(goal_id, goal_type, conditions) {
+            Ok(Some(plan_id)) => Ok(Some(plan_id)),
+            Ok(None) => {
+                self.abandon_goal_because(goal_id, "No way to go about it");
+                Ok(None)
+            }
+            Err(error) => {
+                self.abandon_goal_because(goal_id, &error.to_string());
+                Err(error)
+            }
+        }
+    }
+
+    /// Whether an active goal of `goal_type` is already being pursued.
+    pub fn is_pursuing(&self, goal_type: &str) -> bool {
+        self.get_active_goals().iter().any(|goal| goal.description() == goal_type)
+    }
+
+    /// Plans an alternative way to serve the same goal, e.g. begging when
+    /// buying bread falls through, to switch to if `plan_id` fails. A plan
+    /// from an authored task falls back on the next method that does
+    /// without one of its actions; failing that, or for searched plans, the
+    /// search for `conditions` is run again.
+    pub fn plan_fallback(&mut self, plan_id: Uuid, conditions: &[Condition]) -> Option<Uuid> {
+        let plan = self.planner.get_plan(plan_id)?;
+        let goal_id = plan.goal_id();
+        let task = plan.task().map(str::to_string);
+        let mut actions: Vec<String> = Vec::new();
+        for step in plan.steps() {
+            if !actions.iter().any(|action| action == step.action()) {
+                actions.push(step.action().to_string());
+            }
+        }
+
+        let mut fallback = None;
+        if let Some(task) = task {
+            let alternative = self.planner.create_plan(goal_id);
+            for action in actions {
+                let excluded = HashSet::from([action]);
+                if let Ok(true) = self.planner.generate_task_steps_avoiding(alternative, &task, &excluded) {
+                    fallback = Some(alternative);
+                    break;
+                }
+            }
+        }
+        let fallback = fallback.or_else(|| self.plan_for(goal_id, conditions))?;
+        self.planner.add_fallback(plan_id, fallback);
+        Some(fallback)
+    }
+
+    /// Checks a plan before its next step. A completed plan completes its
+    /// goal; one that can't be saved abandons it with the reason.
+    pub fn supervise_plan(&mut self, plan_id: Uuid) -> Supervision {
+        let supervision = self.planner.supervise(plan_id);
+        let goal_id = self.planner.get_plan(plan_id).map(|plan| plan.goal_id());
+        if let Some(goal_id) = goal_id.filter(|id| self.get_goal_status(*id) == Some(GoalStatus::Active)) {
+            match &supervision {
+                Supervision::Completed => self.update_progress(goal_id, 1.0),
+                Supervision::Abandoned(reason) => self.abandon_goal_because(goal_id, reason),
+                _ => {}
+            }
+        }
+        supervision
+    }
+
+    /// Reports how the plan's current step went out in the world.
+    pub fn complete_step(&mut self, plan_id: Uuid, outcome: StepOutcome) {
+        self.planner.complete_step(plan_id, outcome);
+    }
+
+    pub fn planner(&self) -> &Planner {
+        &self.planner
+    }
+
+    pub fn planner_mut(&mut self) -> &mut Planner {
+        &mut self.planner
+    }
+
+    /// Need meters as numeric facts ("hunger", "energy", ...), so plans can
+    /// require or restore them.
+    fn need_facts(&self) -> WorldState {
+        let mut facts = WorldState::new();
+        for need in Need::ALL {
+            facts.set_number(need.fact_name(), self.needs.value(need));
+        }
+        facts
+    }
+
+    pub fn desires(&self) -> &DesireSystem {
+        &self.desires
+    }
+
+    pub fn achievements(&self) -> &AchievementTracker {
+        &self.achievement
+    }
+
+    pub fn get_goal_status(&self, goal_id: Uuid) -> Option<GoalStatus> {
+        self.active_goals.get(&goal_id).map(|g| g.status.clone())
+    }
+
+    pub fn abandon_goal_because(&mut self, goal_id: Uuid, reason: &str) {
+        if let Some(goal) = self.active_goals.get_mut(&goal_id) {
+            goal.abandon_reason = Some(reason.to_string());
+        }
+        self.abandon_goal(goal_id);
+    }
+
+    pub fn abandon_goal(&mut self, goal_id: Uuid) {
+        if let Some(goal) = self.active_goals.get_mut(&goal_id) {
+            goal.status = GoalStatus::Abandoned;
+
+            // Also abandon subgoals
+            for subgoal_id in goal.subgoals.clone() {
+                self.abandon_goal(subgoal_id);
+            }
+        }
+    }
+}
\ No newline at end of file
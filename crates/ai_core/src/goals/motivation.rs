@@ -46,8 +46,8 @@ struct MotivationEvent {
     event_type: MotivationEventType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-enum MotivationEventType {
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum MotivationEventType {
     Success,
     Failure,
     Progress,
@@ -74,7 +74,7 @@ impl MotivationSystem {
 
         // Update all motivations
         for motivation in self.motivations.values_mut() {
-            self.update_motivation(motivation, delta_time);
+            Self::update_motivation(motivation, self.motivation_decay_rate, delta_time);
         }
 
         // Update focus based on strongest motivation
@@ -160,9 +160,9 @@ impl MotivationSystem {
         self.energy_level = self.energy_level.clamp(0.1, 1.0);
     }
 
-    fn update_motivation(&mut self, motivation: &mut Motivation, delta_time: f32) {
+    fn update_motivation(motivation: &mut Motivation, decay_rate: f32, delta_time: f32) {
         // Apply time-based decay
-        motivation.strength *= (-self.motivation_decay_rate * delta_time).exp();
+        motivation.strength *= (-decay_rate * delta_time).exp();
         
         // Update factors
         motivation.factors.urgency += delta_time * 0.01;
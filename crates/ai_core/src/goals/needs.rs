@@ -0,0 +1,284 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use crate::time::SECONDS_PER_DAY;
+
+/// Meters below this make the NPC miserable
+const COMFORT_THRESHOLD: f32 = 0.4;
+/// Extra pull a fully urgent need adds to the actions that satisfy it
+const MAX_DECISION_BOOST: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Need {
+    Hunger,
+    Energy,
+    Hygiene,
+    Social,
+}
+
+/// Something done at an interaction point that refills a meter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NeedAction {
+    Eat,
+    Sleep,
+    Wash,
+    Chat,
+}
+
+/// A meter that runs down with sim time; 1 is fully satisfied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeedMeter {
+    value: f32,
+    /// Meter lost per game day
+    decay_per_day: f32,
+}
+
+/// Body and social needs: hunger, energy, hygiene and company.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Needs {
+    meters: HashMap<Need, NeedMeter>,
+    /// Meter regained per game day of the matching action
+    restore_per_day: HashMap<NeedAction, f32>,
+}
+
+impl Need {
+    pub const ALL: [Need; 4] = [Need::Hunger, Need::Energy, Need::Hygiene, Need::Social];
+
+    /// Name of the `Basic` or `Social` desire the need drives.
+    pub fn desire_name(&self) -> &'static str {
+        match self {
+            Need::Hunger => "Hunger",
+            Need::Energy => "Rest",
+            Need::Hygiene => "Hygiene",
+            Need::Social => "Social Interaction",
+        }
+    }
+
+    /// Planner fact holding the meter, e.g. "hunger".
+    pub fn fact_name(&self) -> &'static str {
+        match self {
+            Need::Hunger => "hunger",
+            Need::Energy => "energy",
+            Need::Hygiene => "hygiene",
+            Need::Social => "social",
+        }
+    }
+
+    /// What to do about it.
+    pub fn action(&self) -> NeedAction {
+        match self {
+            Need::Hunger => NeedAction::Eat,
+            Need::Energy => NeedAction::Sleep,
+            Need::Hygiene => NeedAction::Wash,
+            Need::Social => NeedAction::Chat,
+        }
+    }
+}
+
+impl NeedAction {
+    pub fn need(&self) -> Need {
+        match self {
+            NeedAction::Eat => Need::Hunger,
+            NeedAction::Sleep => Need::Energy,
+            NeedAction::Wash => Need::Hygiene,
+            NeedAction::Chat => Need::Social,
+        }
+    }
+
+    /// Option name used for decisions, e.g. "eat".
+    pub fn name(&self) -> &'static str {
+        match self {
+            NeedAction::Eat => "eat",
+            NeedAction::Sleep => "sleep",
+            NeedAction::Wash => "wash",
+            NeedAction::Chat => "chat",
+        }
+    }
+}
+
+impl Default for Needs {
+    fn default() -> Self {
+        let meter = |decay_per_day| NeedMeter { value: 1.0, decay_per_day };
+        let mut meters = HashMap::new();
+        meters.insert(Need::Hunger, meter(2.5));
+        meters.insert(Need::Energy, meter(1.2));
+        meters.insert(Need::Hygiene, meter(0.8));
+        meters.insert(Need::Social, meter(1.0));
+
+        // Enough that a meal, a night's sleep, a wash or a good chat
+        // refills the meter in sensible game time
+        let mut restore_per_day = HashMap::new();
+        restore_per_day.insert(NeedAction::Eat, 48.0);
+        restore_per_day.insert(NeedAction::Sleep, 3.5);
+        restore_per_day.insert(NeedAction::Wash, 96.0);
+        restore_per_day.insert(NeedAction::Chat, 24.0);
+
+        Self { meters, restore_per_day }
+    }
+}
+
+impl Needs {
+    /// Runs every meter down by `delta_time` sim seconds.
+    pub fn update(&mut self, delta_time: f32) {
+        for meter in self.meters.values_mut() {
+            meter.value = (meter.value - meter.decay_per_day * delta_time / SECONDS_PER_DAY).clamp(0.0, 1.0);
+        }
+    }
+
+    /// Refills the need `action` satisfies for `delta_time` sim seconds of
+    /// doing it; `quality` scales it (a bed beats a bench). Returns the gain.
+    pub fn perform(&mut self, action: NeedAction, delta_time: f32, quality: f32) -> f32 {
+        let rate = self.restore_per_day.get(&action).copied().unwrap_or(0.0);
+        let Some(meter) = self.meters.get_mut(&action.need()) else {
+            return 0.0;
+        };
+
+        let before = meter.value;
+        meter.value = (meter.value + rate * quality.clamp(0.0, 1.0) * delta_time / SECONDS_PER_DAY).clamp(0.0, 1.0);
+        meter.value - before
+    }
+
+    pub fn value(&self, need: Need) -> f32 {
+        self.meters.get(&need).map(|m| m.value).unwrap_or(1.0)
+    }
+
+    pub fn set_value(&mut self, need: Need, value: f32) {
+        if let Some(meter) = self.meters.get_mut(&need) {
+            meter.value = value.clamp(0.0, 1.0);
+        }
+    }
+
+    /// How pressing a need is, 0..1. Rises slowly at first, then sharply
+    /// as the meter empties.
+    pub fn urgency(&self, need: Need) -> f32 {
+        (1.0 - self.value(need)).powi(2)
+    }
+
+    pub fn most_urgent(&self) -> Option<(Need, f32)> {
+        Need::ALL
+            .iter()
+            .map(|need| (*need, self.urgency(*need)))
+            .filter(|(_, urgency)| *urgency > 0.0)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// How much unmet needs drag the NPC's mood down, 0..1.
+    pub fn discomfort(&self) -> f32 {
+        let total: f32 = Need::ALL
+            .iter()
+            .map(|need| ((COMFORT_THRESHOLD - self.value(*need)) / COMFORT_THRESHOLD).max(0.0))
+            .sum();
+        (total / Need::ALL.len() as f32).clamp(0.0, 1.0)
+    }
+
+    /// Needs at least `min_urgency` urgent, keyed by fact name ("hunger",
+    /// ...), as a reasoning context.
+    pub fn pressing(&self, min_urgency: f32) -> HashMap<String, f32> {
+        Need::ALL
+            .iter()
+            .map(|need| (need.fact_name().to_string(), self.urgency(*need)))
+            .filter(|(_, urgency)| *urgency >= min_urgency)
+            .collect()
+    }
+
+    /// Multipliers for decision options that satisfy a need, e.g. "eat" → 3.0
+    /// when starving.
+    pub fn decision_weights(&self) -> Vec<(&'static str, f32)> {
+        Need::ALL
+            .iter()
+            .map(|need| (need.action().name(), 1.0 + self.urgency(*need) * MAX_DECISION_BOOST))
+            .collect()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::desires::DesireSystem;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "expected {}, got {}", expected, actual);
+    }
+
+    fn needs_at(values: [(Need, f32); 4]) -> Needs {
+        let mut needs = Needs::default();
+        for (need, value) in values {
+            needs.set_value(need, value);
+        }
+        needs
+    }
+
+    #[test]
+    fn meters_run_down_by_their_daily_rate() {
+        let mut needs = Needs::default();
+        needs.update(SECONDS_PER_DAY / 10.0);
+
+        assert_close(needs.value(Need::Hunger), 0.75);
+        assert_close(needs.value(Need::Energy), 0.88);
+        assert_close(needs.value(Need::Hygiene), 0.92);
+        assert_close(needs.value(Need::Social), 0.9);
+
+        needs.update(SECONDS_PER_DAY * 2.0);
+        for need in Need::ALL {
+            assert_eq!(needs.value(need), 0.0, "{:?} stops at empty", need);
+        }
+    }
+
+    #[test]
+    fn urgency_rises_slowly_then_sharply() {
+        let mut needs = Needs::default();
+        assert_eq!(needs.most_urgent(), None);
+
+        for (value, urgency) in [(1.0, 0.0), (0.9, 0.01), (0.5, 0.25), (0.2, 0.64), (0.0, 1.0)] {
+            needs.set_value(Need::Hygiene, value);
+            assert_close(needs.urgency(Need::Hygiene), urgency);
+        }
+
+        needs.set_value(Need::Hunger, 0.5);
+        assert_eq!(needs.most_urgent(), Some((Need::Hygiene, 1.0)));
+        assert_eq!(needs.pressing(0.2).len(), 2);
+    }
+
+    #[test]
+    fn discomfort_only_counts_meters_below_the_comfort_threshold() {
+        let comfortable = needs_at(Need::ALL.map(|need| (need, COMFORT_THRESHOLD)));
+        assert_eq!(comfortable.discomfort(), 0.0);
+
+        // Halfway to empty on one of four meters
+        let hungry = needs_at(Need::ALL.map(|need| (need, if need == Need::Hunger { COMFORT_THRESHOLD / 2.0 } else { 1.0 })));
+        assert_close(hungry.discomfort(), 0.125);
+
+        let wretched = needs_at(Need::ALL.map(|need| (need, 0.0)));
+        assert_close(wretched.discomfort(), 1.0);
+    }
+
+    #[test]
+    fn urgent_needs_boost_the_actions_that_meet_them() {
+        let needs = needs_at([(Need::Hunger, 0.0), (Need::Energy, 0.5), (Need::Hygiene, 1.0), (Need::Social, 1.0)]);
+        let weights: HashMap<&str, f32> = needs.decision_weights().into_iter().collect();
+
+        assert_close(weights["eat"], 1.0 + MAX_DECISION_BOOST);
+        assert_close(weights["sleep"], 1.0 + 0.25 * MAX_DECISION_BOOST);
+        assert_close(weights["wash"], 1.0);
+        assert_close(weights["chat"], 1.0);
+    }
+
+    #[test]
+    fn meters_drive_their_desires() {
+        let mut desires = DesireSystem::default();
+        let needs = needs_at([(Need::Hunger, 0.1), (Need::Energy, 0.9), (Need::Hygiene, 1.0), (Need::Social, 1.0)]);
+        desires.sync_needs(&needs);
+
+        let strongest = desires.get_strongest_desire().unwrap();
+        assert_eq!(strongest.name(), "Hunger");
+        assert_close(strongest.intensity(), 0.9);
+        assert_close(strongest.urgency(), 0.81);
+        let active: Vec<&str> = desires.get_active_desires().iter().map(|desire| desire.name()).collect();
+        assert_eq!(active, ["Hunger"]);
+
+        let fed = needs_at(Need::ALL.map(|need| (need, 1.0)));
+        desires.sync_needs(&fed);
+        assert!(desires.get_active_desires().is_empty());
+    }
+}
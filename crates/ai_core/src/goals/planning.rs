@@ -0,0 +1,968 @@
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use super::htn::{HtnDomain, HtnError};
+use super::world_state::{Condition, Effect, WorldState};
+use crate::knowledge::KnowledgeBase;
+
+/// States the search may expand before giving up on a goal
+const MAX_EXPANSIONS: usize = 4096;
+/// Floor on success rates, so a hopeless action is expensive but finite
+const MIN_SUCCESS_RATE: f32 = 0.05;
+/// Failures of one step before its action is written off for the plan
+const MAX_STEP_ATTEMPTS: u32 = 3;
+/// How far each outcome moves an action's success rate
+const OUTCOME_LEARNING_RATE: f32 = 0.1;
+
+/// Goal-oriented action planner: finds the cheapest sequence of known
+/// actions that takes what the NPC believes about the world to a goal state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Planner {
+    plans: HashMap<Uuid, Plan>,
+    action_templates: HashMap<String, ActionTemplate>,
+    current_plan: Option<Uuid>,
+    /// Longest plan worth considering, in expected seconds
+    planning_horizon: f32,
+    /// What the NPC believes about the world; plans start here and steps
+    /// are checked against it
+    #[serde(default)]
+    world: WorldState,
+    /// Facts that come from observation rather than from the plans' own
+    /// bookkeeping, so whether a step worked can be seen in them
+    #[serde(skip)]
+    observed: HashSet<String>,
+    /// Authored task networks for goals GOAP would make a mess of
+    #[serde(default)]
+    htn: HtnDomain,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Plan {
+    id: Uuid,
+    goal_id: Uuid,
+    steps: VecDeque<PlanStep>,
+    status: PlanStatus,
+    estimated_completion_time: f32,
+    fallback_plans: Vec<Uuid>,
+    /// State the plan is meant to reach
+    #[serde(default)]
+    goal: Vec<Condition>,
+    #[serde(default)]
+    cost: f32,
+    /// Actions that kept failing and are left out of repairs
+    #[serde(default)]
+    failed_actions: HashSet<String>,
+    #[serde(default)]
+    failure_reason: Option<String>,
+    /// Task the plan was decomposed from, for HTN plans
+    #[serde(default)]
+    task: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanStep {
+    action: String,
+    prerequisites: Vec<Condition>,
+    effects: Vec<Effect>,
+    expected_duration: f32,
+    completed: bool,
+    outcome: Option<StepOutcome>,
+    #[serde(default)]
+    attempts: u32,
+}
+
+/// Something the NPC knows how to do: when it can, what it changes and
+/// what it costs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionTemplate {
+    name: String,
+    #[serde(default)]
+    prerequisites: Vec<Condition>,
+    #[serde(default)]
+    effects: Vec<Effect>,
+    cost: f32,
+    average_duration: f32,
+    #[serde(default = "always_succeeds")]
+    success_rate: f32,
+}
+
+fn always_succeeds() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PlanStatus {
+    InProgress,
+    Completed,
+    Failed,
+    Blocked,
+}
+
+/// What supervising a plan before its next step decided.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Supervision {
+    /// The remaining steps still lead to the goal
+    Continue,
+    Completed,
+    /// Steps were patched or replanned around a change in the world
+    Repaired,
+    /// The plan was dropped for one of its fallbacks
+    SwitchedTo(Uuid),
+    /// Nothing works any more; carries the reason
+    Abandoned(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum StepOutcome {
+    Success,
+    Failure(String),
+    Partial(f32),
+}
+
+/// A state reached during search and how it was reached.
+struct SearchNode {
+    state: WorldState,
+    parent: Option<usize>,
+    action: Option<String>,
+    cost: f32,
+    duration: f32,
+}
+
+/// Open-list entry, ordered so the heap pops the lowest estimate first.
+struct Frontier {
+    estimate: f32,
+    node: usize,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl Default for Planner {
+    fn default() -> Self {
+        Self {
+            plans: HashMap::new(),
+            action_templates: HashMap::new(),
+            current_plan: None,
+            planning_horizon: 100.0,
+            world: WorldState::default(),
+            observed: HashSet::new(),
+            htn: HtnDomain::default(),
+        }
+    }
+}
+
+impl ActionTemplate {
+    pub fn new(name: &str, cost: f32, average_duration: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            prerequisites: Vec::new(),
+            effects: Vec::new(),
+            cost: cost.max(0.0),
+            average_duration: average_duration.max(0.0),
+            success_rate: always_succeeds(),
+        }
+    }
+
+    pub fn requires(mut self, condition: Condition) -> Self {
+        self.prerequisites.push(condition);
+        self
+    }
+
+    pub fn causes(mut self, effect: Effect) -> Self {
+        self.effects.push(effect);
+        self
+    }
+
+    pub fn with_success_rate(mut self, success_rate: f32) -> Self {
+        self.success_rate = success_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn prerequisites(&self) -> &[Condition] {
+        &self.prerequisites
+    }
+
+    pub fn effects(&self) -> &[Effect] {
+        &self.effects
+    }
+
+    pub fn success_rate(&self) -> f32 {
+        self.success_rate
+    }
+
+    /// Cost with retries priced in: an action that fails half the time
+    /// costs twice as much.
+    pub fn expected_cost(&self) -> f32 {
+        self.cost / self.success_rate.max(MIN_SUCCESS_RATE)
+    }
+}
+
+impl Planner {
+    pub fn create_plan(&mut self, goal_id: Uuid) -> Uuid {
+        let plan = Plan {
+            id: Uuid::new_v4(),
+            goal_id,
+            steps: VecDeque::new(),
+            status: PlanStatus::InProgress,
+            estimated_completion_time: 0.0,
+            fallback_plans: Vec::new(),
+            goal: Vec::new(),
+            cost: 0.0,
+            failed_actions: HashSet::new(),
+            failure_reason: None,
+            task: None,
+        };
+
+        let plan_id = plan.id;
+        self.plans.insert(plan_id, plan);
+        plan_id
+    }
+
+    /// The plan the NPC is following, if it hasn't finished or failed.
+    pub fn current_plan(&self) -> Option<Uuid> {
+        let plan_id = self.current_plan?;
+        let plan = self.plans.get(&plan_id)?;
+        matches!(plan.status, PlanStatus::InProgress | PlanStatus::Blocked).then_some(plan_id)
+    }
+
+    pub fn set_current_plan(&mut self, plan_id: Uuid) {
+        if self.plans.contains_key(&plan_id) {
+            self.current_plan = Some(plan_id);
+        }
+    }
+
+    pub fn add_action_template(&mut self, template: ActionTemplate) {
+        self.action_templates.insert(template.name.clone(), template);
+    }
+
+    pub fn knows_action(&self, action: &str) -> bool {
+        self.action_templates.contains_key(action)
+    }
+
+    pub fn action_template(&self, action: &str) -> Option<&ActionTemplate> {
+        self.action_templates.get(action)
+    }
+
+    pub fn set_planning_horizon(&mut self, horizon: f32) {
+        self.planning_horizon = horizon.max(0.0);
+    }
+
+    pub fn world(&self) -> &WorldState {
+        &self.world
+    }
+
+    /// Takes in new observations, overriding what the NPC believed before.
+    pub fn observe(&mut self, observed: &WorldState) {
+        self.observed.extend(observed.fact_names().map(str::to_string));
+        self.world.merge(observed);
+    }
+
+    /// Refreshes beliefs from the world facts in the NPC's knowledge.
+    pub fn observe_knowledge(&mut self, knowledge: &KnowledgeBase) {
+        self.observe(&WorldState::from_knowledge(knowledge));
+    }
+
+    /// Whether `fact` is kept up to date by observation.
+    pub fn is_observed(&self, fact: &str) -> bool {
+        self.observed.contains(fact)
+    }
+
+    pub fn get_plan(&self, plan_id: Uuid) -> Option<&Plan> {
+        self.plans.get(&plan_id)
+    }
+
+    /// Plans from the current beliefs to `goal`. Returns false, leaving the
+    /// plan blocked, when no sequence of known actions gets there within
+    /// the planning horizon.
+    pub fn generate_steps(&mut self, plan_id: Uuid, goal: &[Condition]) -> bool {
+        let found = self.search(&self.world, goal, &HashSet::new());
+        let Some(plan) = self.plans.get_mut(&plan_id) else {
+            return false;
+        };
+
+        plan.steps.clear();
+        plan.goal = goal.to_vec();
+        match found {
+            Some((steps, cost)) => {
+                plan.steps = steps;
+                plan.cost = cost;
+                plan.status = if plan.steps.is_empty() { PlanStatus::Completed } else { PlanStatus::InProgress };
+                plan.estimate_completion_time();
+                true
+            }
+            None => {
+                plan.status = PlanStatus::Blocked;
+                false
+            }
+        }
+    }
+
+    /// Uses `domain` for HTN planning, learning the actions it defines.
+    pub fn set_task_domain(&mut self, domain: HtnDomain) {
+        for template in domain.actions() {
+            self.add_action_template(template.clone());
+        }
+        self.htn = domain;
+    }
+
+    pub fn task_domain(&self) -> &HtnDomain {
+        &self.htn
+    }
+
+    /// Plans by decomposing an authored task from the current beliefs.
+    /// `Ok(false)` leaves the plan blocked when no method applies.
+    pub fn generate_task_steps(&mut self, plan_id: Uuid, task: &str) -> Result<bool, HtnError> {
+        self.generate_task_steps_avoiding(plan_id, task, &HashSet::new())
+    }
+
+    /// Like `generate_task_steps`, but without the `excluded` actions, so
+    /// decomposition falls back on methods that do without them.
+    pub fn generate_task_steps_avoiding(
+        &mut self,
+        plan_id: Uuid,
+        task: &str,
+        excluded: &HashSet<String>,
+    ) -> Result<bool, HtnError> {
+        let found = self.decompose(task, excluded)?;
+        let Some(plan) = self.plans.get_mut(&plan_id) else {
+            return Ok(false);
+        };
+
+        plan.steps.clear();
+        plan.goal.clear();
+        plan.task = Some(task.to_string());
+        match found {
+            Some((steps, cost)) => {
+                plan.steps = steps;
+                plan.cost = cost;
+                plan.status = if plan.steps.is_empty() { PlanStatus::Completed } else { PlanStatus::InProgress };
+                plan.estimate_completion_time();
+                Ok(true)
+            }
+            None => {
+                plan.status = PlanStatus::Blocked;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Simulates the next step, succeeding at the action's usual rate.
+    pub fn execute_next_step(&mut self, plan_id: Uuid) -> Option<StepOutcome> {
+        let plan = self.plans.get_mut(&plan_id)?;
+        let step = plan.steps.front()?;
+
+        if !self.world.satisfies(&step.prerequisites) {
+            plan.status = PlanStatus::Blocked;
+            return Some(StepOutcome::Failure("Prerequisites not met".to_string()));
+        }
+
+        // Simulate action execution
+        let success_rate = self.action_templates
+            .get(&step.action)
+            .map_or(0.5, |template| template.success_rate);
+        let outcome = if rand::random::<f32>() < success_rate {
+            StepOutcome::Success
+        } else {
+            StepOutcome::Failure("Action failed".to_string())
+        };
+
+        self.complete_step(plan_id, outcome.clone());
+        Some(outcome)
+    }
+
+    /// Records how the plan's current step went. Success applies its effects
+    /// and moves on; a step that fails too often blocks the plan until it is
+    /// supervised. Every outcome adjusts the action's success rate.
+    pub fn complete_step(&mut self, plan_id: Uuid, outcome: StepOutcome) {
+        let Some(plan) = self.plans.get_mut(&plan_id) else {
+            return;
+        };
+        let Some(step) = plan.steps.front_mut() else {
+            return;
+        };
+
+        let action = step.action.clone();
+        let score = match &outcome {
+            StepOutcome::Success => 1.0,
+            StepOutcome::Partial(progress) => progress.clamp(0.0, 1.0),
+            StepOutcome::Failure(_) => 0.0,
+        };
+        step.outcome = Some(outcome.clone());
+
+        match outcome {
+            StepOutcome::Success => {
+                step.completed = true;
+                self.world.apply(&step.effects);
+
+                // Remove completed step
+                plan.steps.pop_front();
+                if plan.steps.is_empty() {
+                    plan.status = PlanStatus::Completed;
+                }
+                plan.estimate_completion_time();
+            }
+            StepOutcome::Partial(_) => {}
+            StepOutcome::Failure(_) => {
+                step.attempts += 1;
+                if step.attempts >= MAX_STEP_ATTEMPTS {
+                    plan.failed_actions.insert(action.clone());
+                    plan.status = PlanStatus::Blocked;
+                }
+            }
+        }
+
+        self.record_outcome(&action, score);
+    }
+
+    /// Nudges an action's success rate toward how it just went, 0..1.
+    pub fn record_outcome(&mut self, action: &str, score: f32) {
+        if let Some(template) = self.action_templates.get_mut(action) {
+            template.success_rate += (score.clamp(0.0, 1.0) - template.success_rate) * OUTCOME_LEARNING_RATE;
+            template.success_rate = template.success_rate.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Links `fallback` as the plan to switch to if `plan_id` can't be saved.
+    pub fn add_fallback(&mut self, plan_id: Uuid, fallback: Uuid) {
+        if plan_id == fallback || !self.plans.contains_key(&fallback) {
+            return;
+        }
+        if let Some(plan) = self.plans.get_mut(&plan_id) {
+            plan.fallback_plans.push(fallback);
+        }
+    }
+
+    /// Checks a plan against the world before its next step: carries on if
+    /// it still works, repairs it if the world moved under it, switches to a
+    /// fallback if it can't be repaired, and gives up as a last resort.
+    pub fn supervise(&mut self, plan_id: Uuid) -> Supervision {
+        let Some(plan) = self.plans.get(&plan_id) else {
+            return Supervision::Abandoned("Plan no longer exists".to_string());
+        };
+        match plan.status {
+            PlanStatus::Completed => return Supervision::Completed,
+            PlanStatus::Failed => {
+                let reason = plan.failure_reason.clone().unwrap_or_else(|| "Plan failed".to_string());
+                return Supervision::Abandoned(reason);
+            }
+            PlanStatus::InProgress | PlanStatus::Blocked => {}
+        }
+
+        // Step 1: the goal may have come about on its own
+        if !plan.goal.is_empty() && self.world.satisfies(&plan.goal) {
+            if let Some(plan) = self.plans.get_mut(&plan_id) {
+                plan.steps.clear();
+                plan.status = PlanStatus::Completed;
+            }
+            return Supervision::Completed;
+        }
+
+        // Step 2: do the remaining steps still get there from here?
+        if matches!(plan.status, PlanStatus::InProgress) && self.broken_step(plan).is_none() {
+            return Supervision::Continue;
+        }
+
+        // Step 3: patch the plan, then try the fallbacks
+        if self.repair(plan_id) {
+            return Supervision::Repaired;
+        }
+        if let Some(fallback) = self.switch_to_fallback(plan_id) {
+            return Supervision::SwitchedTo(fallback);
+        }
+
+        // Step 4: give up, saying why
+        let reason = self.plans.get(&plan_id).map(|plan| self.explain_failure(plan)).unwrap_or_default();
+        if let Some(plan) = self.plans.get_mut(&plan_id) {
+            plan.status = PlanStatus::Failed;
+            plan.failure_reason = Some(reason.clone());
+        }
+        Supervision::Abandoned(reason)
+    }
+
+    /// Index of the first step that can't run from the state the steps
+    /// before it leave behind, or `steps.len()` when all run but the goal
+    /// still isn't reached. `None` when the plan works.
+    fn broken_step(&self, plan: &Plan) -> Option<usize> {
+        let mut state = self.world.clone();
+        for (index, step) in plan.steps.iter().enumerate() {
+            if plan.failed_actions.contains(&step.action) || !state.satisfies(&step.prerequisites) {
+                return Some(index);
+            }
+            state.apply(&step.effects);
+        }
+        (!state.satisfies(&plan.goal)).then_some(plan.steps.len())
+    }
+
+    /// Local repair first: plan just the bridge from where the plan broke to
+    /// what the broken step needs, keeping the rest. If that fails, replan
+    /// the whole thing without the actions that kept failing.
+    fn repair(&mut self, plan_id: Uuid) -> bool {
+        let Some(plan) = self.plans.get(&plan_id) else {
+            return false;
+        };
+        let Some(broken) = self.broken_step(plan) else {
+            // Nothing left to do and nothing to aim for: no plan to save
+            if plan.steps.is_empty() && plan.goal.is_empty() {
+                return false;
+            }
+            // The world moved on and the steps work again
+            if let Some(plan) = self.plans.get_mut(&plan_id) {
+                plan.status = PlanStatus::InProgress;
+            }
+            return true;
+        };
+
+        let mut repaired = None;
+        let broken_action = plan.steps.get(broken).map(|step| step.action.clone());
+        if broken_action.is_none_or(|action| !plan.failed_actions.contains(&action)) {
+            let mut state = self.world.clone();
+            for step in plan.steps.iter().take(broken) {
+                state.apply(&step.effects);
+            }
+            let target = plan.steps.get(broken).map_or(&plan.goal, |step| &step.prerequisites);
+            if let Some((bridge, _)) = self.search(&state, target, &plan.failed_actions) {
+                let mut steps = plan.steps.clone();
+                for (offset, step) in bridge.into_iter().enumerate() {
+                    steps.insert(broken + offset, step);
+                }
+                repaired = Some(steps);
+            }
+        }
+
+        let mut candidate = plan.clone();
+        if let Some(steps) = repaired {
+            candidate.steps = steps;
+        }
+        if self.broken_step(&candidate).is_some() {
+            // Authored plans start over from their task, the rest search again
+            let replanned = match &plan.task {
+                Some(task) => self.decompose(task, &plan.failed_actions).ok().flatten(),
+                None => self.search(&self.world, &plan.goal, &plan.failed_actions),
+            };
+            match replanned {
+                Some((steps, _)) => candidate.steps = steps,
+                None => return false,
+            }
+        }
+
+        candidate.status = PlanStatus::InProgress;
+        candidate.cost = candidate.steps
+            .iter()
+            .filter_map(|step| self.action_templates.get(&step.action))
+            .map(|template| template.expected_cost())
+            .sum();
+        candidate.estimate_completion_time();
+        self.plans.insert(plan_id, candidate);
+        true
+    }
+
+    /// Moves on to the first fallback that still works (or can be made to),
+    /// failing the plan it replaces.
+    fn switch_to_fallback(&mut self, plan_id: Uuid) -> Option<Uuid> {
+        let fallbacks = self.plans.get(&plan_id)?.fallback_plans.clone();
+        for fallback in fallbacks {
+            let usable = match self.plans.get(&fallback).map(|plan| plan.status.clone()) {
+                Some(PlanStatus::Failed) | None => false,
+                Some(PlanStatus::Completed) => true,
+                Some(_) => self.repair(fallback),
+            };
+            if !usable {
+                continue;
+            }
+
+            if let Some(plan) = self.plans.get_mut(&plan_id) {
+                plan.status = PlanStatus::Failed;
+                plan.failure_reason = Some("Replaced by a fallback plan".to_string());
+            }
+            if self.current_plan == Some(plan_id) {
+                self.current_plan = Some(fallback);
+            }
+            return Some(fallback);
+        }
+        None
+    }
+
+    fn explain_failure(&self, plan: &Plan) -> String {
+        let mut failed: Vec<&str> = plan.failed_actions.iter().map(String::as_str).collect();
+        failed.sort_unstable();
+        let unmet: Vec<&str> = self.world.unmet(&plan.goal).iter().map(|c| c.fact.as_str()).collect();
+
+        match (failed.is_empty(), unmet.is_empty()) {
+            (false, _) => format!("Kept failing to {} and found no other way", failed.join(", ")),
+            (true, false) => format!("No known way to bring about {}", unmet.join(", ")),
+            (true, true) => "The plan stopped making sense".to_string(),
+        }
+    }
+
+    /// A* over world states: actions are edges weighted by expected cost,
+    /// and the heuristic counts goal conditions still unmet.
+    fn search(
+        &self,
+        start: &WorldState,
+        goal: &[Condition],
+        excluded: &HashSet<String>,
+    ) -> Option<(VecDeque<PlanStep>, f32)> {
+        // Sorted so equal-cost plans come out the same every time
+        let mut templates: Vec<&ActionTemplate> = self.action_templates
+            .values()
+            .filter(|t| !excluded.contains(&t.name))
+            .collect();
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Cheapest any single effect can be had for keeps the estimate optimistic
+        let cheapest_effect = templates
+            .iter()
+            .filter(|t| !t.effects.is_empty())
+            .map(|t| t.expected_cost() / t.effects.len() as f32)
+            .fold(f32::INFINITY, f32::min);
+        let heuristic = |state: &WorldState| {
+            let unmet = state.unmet(goal).len() as f32;
+            if unmet == 0.0 || !cheapest_effect.is_finite() { 0.0 } else { unmet * cheapest_effect }
+        };
+
+        let mut nodes = vec![SearchNode {
+            state: start.clone(),
+            parent: None,
+            action: None,
+            cost: 0.0,
+            duration: 0.0,
+        }];
+        let mut best_cost: HashMap<WorldState, f32> = HashMap::new();
+        best_cost.insert(start.clone(), 0.0);
+        let mut open = BinaryHeap::new();
+        open.push(Frontier { estimate: heuristic(start), node: 0 });
+
+        let mut expansions = 0;
+        while let Some(Frontier { node: index, .. }) = open.pop() {
+            let node = &nodes[index];
+            if best_cost.get(&node.state).is_some_and(|best| node.cost > *best) {
+                continue; // Reached more cheaply since this entry was queued
+            }
+            if node.state.satisfies(goal) {
+                return Some((self.build_steps(&nodes, index), node.cost));
+            }
+
+            expansions += 1;
+            if expansions > MAX_EXPANSIONS {
+                return None;
+            }
+
+            let (state, cost, duration) = (node.state.clone(), node.cost, node.duration);
+            for template in &templates {
+                if !state.satisfies(&template.prerequisites) {
+                    continue;
+                }
+                let duration = duration + template.average_duration;
+                if duration > self.planning_horizon {
+                    continue;
+                }
+
+                let mut next = state.clone();
+                next.apply(&template.effects);
+                let cost = cost + template.expected_cost();
+                if best_cost.get(&next).is_some_and(|best| *best <= cost) {
+                    continue;
+                }
+
+                best_cost.insert(next.clone(), cost);
+                open.push(Frontier { estimate: cost + heuristic(&next), node: nodes.len() });
+                nodes.push(SearchNode {
+                    state: next,
+                    parent: Some(index),
+                    action: Some(template.name.clone()),
+                    cost,
+                    duration,
+                });
+            }
+        }
+
+        None
+    }
+
+    fn decompose(&self, task: &str, excluded: &HashSet<String>) -> Result<Option<(VecDeque<PlanStep>, f32)>, HtnError> {
+        let actions = self.htn.decompose(task, &self.world, &self.action_templates, excluded)?;
+        Ok(actions.map(|actions| {
+            let cost = actions.iter().map(|template| template.expected_cost()).sum();
+            (actions.into_iter().map(PlanStep::from_template).collect(), cost)
+        }))
+    }
+
+    /// Walks back from the goal node to the start, turning actions into steps.
+    fn build_steps(&self, nodes: &[SearchNode], goal: usize) -> VecDeque<PlanStep> {
+        let mut steps = VecDeque::new();
+        let mut current = Some(goal);
+        while let Some(index) = current {
+            let node = &nodes[index];
+            if let Some(template) = node.action.as_ref().and_then(|name| self.action_templates.get(name)) {
+                steps.push_front(PlanStep::from_template(template));
+            }
+            current = node.parent;
+        }
+        steps
+    }
+}
+
+impl Plan {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn goal_id(&self) -> Uuid {
+        self.goal_id
+    }
+
+    pub fn current_step(&self) -> Option<&PlanStep> {
+        self.steps.front()
+    }
+
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.failure_reason.as_deref()
+    }
+
+    pub fn task(&self) -> Option<&str> {
+        self.task.as_deref()
+    }
+
+    pub fn steps(&self) -> &VecDeque<PlanStep> {
+        &self.steps
+    }
+
+    pub fn status(&self) -> &PlanStatus {
+        &self.status
+    }
+
+    pub fn cost(&self) -> f32 {
+        self.cost
+    }
+
+    /// Plans to switch to, in order, if this one can't be saved.
+    pub fn fallbacks(&self) -> &[Uuid] {
+        &self.fallback_plans
+    }
+
+    fn estimate_completion_time(&mut self) {
+        self.estimated_completion_time = self.steps.iter()
+            .map(|step| step.expected_duration)
+            .sum();
+    }
+}
+
+impl PlanStep {
+    fn from_template(template: &ActionTemplate) -> Self {
+        Self {
+            action: template.name.clone(),
+            prerequisites: template.prerequisites.clone(),
+            effects: template.effects.clone(),
+            expected_duration: template.average_duration,
+            completed: false,
+            outcome: None,
+            attempts: 0,
+        }
+    }
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    pub fn expected_duration(&self) -> f32 {
+        self.expected_duration
+    }
+
+    pub fn effects(&self) -> &[Effect] {
+        &self.effects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::world_state::WORLD_FACT_CATEGORY;
+
+    fn actions(plan: &Plan) -> Vec<&str> {
+        plan.steps().iter().map(PlanStep::action).collect()
+    }
+
+    /// Bread from the shop, or flour fetched and baked: cheaper but slower
+    fn bakery(bake_success_rate: f32) -> Planner {
+        let mut planner = Planner::default();
+        planner.add_action_template(ActionTemplate::new("buy_bread", 5.0, 20.0).causes(Effect::set("fed", true)));
+        planner.add_action_template(ActionTemplate::new("fetch_flour", 1.0, 30.0).causes(Effect::set("has_flour", true)));
+        planner.add_action_template(
+            ActionTemplate::new("bake", 1.0, 30.0)
+                .requires(Condition::is("has_flour", true))
+                .causes(Effect::set("fed", true))
+                .with_success_rate(bake_success_rate),
+        );
+        planner
+    }
+
+    #[test]
+    fn picks_the_cheapest_way_to_the_goal() {
+        let goal = [Condition::is("fed", true)];
+
+        let mut planner = bakery(1.0);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(planner.generate_steps(plan_id, &goal));
+        let plan = planner.get_plan(plan_id).unwrap();
+        assert_eq!(actions(plan), ["fetch_flour", "bake"]);
+        assert_eq!(plan.cost(), 2.0);
+        assert!(matches!(plan.status(), PlanStatus::InProgress));
+
+        // Baking that fails four times in five costs 5 a loaf once retries count
+        let mut planner = bakery(0.2);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(planner.generate_steps(plan_id, &goal));
+        let plan = planner.get_plan(plan_id).unwrap();
+        assert_eq!(actions(plan), ["buy_bread"]);
+        assert_eq!(plan.cost(), 5.0);
+    }
+
+    #[test]
+    fn plans_longer_than_the_horizon_are_not_considered() {
+        let goal = [Condition::is("fed", true)];
+        let mut planner = bakery(1.0);
+
+        // Fetching and baking takes 60 seconds, buying 20
+        planner.set_planning_horizon(50.0);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(planner.generate_steps(plan_id, &goal));
+        assert_eq!(actions(planner.get_plan(plan_id).unwrap()), ["buy_bread"]);
+
+        planner.set_planning_horizon(10.0);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(!planner.generate_steps(plan_id, &goal));
+        assert!(matches!(planner.get_plan(plan_id).unwrap().status(), PlanStatus::Blocked));
+    }
+
+    #[test]
+    fn preconditions_are_checked_against_known_world_facts() {
+        let mut planner = Planner::default();
+        planner.add_action_template(
+            ActionTemplate::new("enter_shop", 1.0, 5.0)
+                .requires(Condition::is("shop_open", true))
+                .requires(Condition::at_least("gold", 10.0))
+                .causes(Effect::set("in_shop", true)),
+        );
+        let goal = [Condition::is("in_shop", true)];
+
+        let mut knowledge = KnowledgeBase::default();
+        knowledge.add_knowledge("shop_open".to_string(), 0.9, None, WORLD_FACT_CATEGORY.to_string());
+        knowledge.add_knowledge("gold = 4".to_string(), 0.9, None, WORLD_FACT_CATEGORY.to_string());
+        planner.observe_knowledge(&knowledge);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(!planner.generate_steps(plan_id, &goal), "four gold isn't enough");
+
+        // Newer observations override what was believed before
+        let mut paid = KnowledgeBase::default();
+        paid.add_knowledge("gold = 12".to_string(), 0.9, None, WORLD_FACT_CATEGORY.to_string());
+        planner.observe_knowledge(&paid);
+        assert!(planner.generate_steps(plan_id, &goal));
+        assert_eq!(actions(planner.get_plan(plan_id).unwrap()), ["enter_shop"]);
+        assert!(planner.is_observed("gold"));
+
+        // A rumour the NPC half-believes doesn't reopen the shop
+        let mut planner = Planner::default();
+        planner.add_action_template(ActionTemplate::new("enter_shop", 1.0, 5.0).requires(Condition::is("shop_open", true)));
+        let mut rumour = KnowledgeBase::default();
+        rumour.add_knowledge("shop_open".to_string(), 0.3, None, WORLD_FACT_CATEGORY.to_string());
+        planner.observe_knowledge(&rumour);
+        assert!(!planner.world().get_bool("shop_open"));
+    }
+
+    #[test]
+    fn numeric_effects_add_up_over_several_steps() {
+        let mut planner = Planner::default();
+        planner.add_action_template(ActionTemplate::new("work", 1.0, 10.0).causes(Effect::add("gold", 5.0)));
+        let mut world = WorldState::new();
+        world.set_number("gold", 3.0);
+        planner.observe(&world);
+
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(planner.generate_steps(plan_id, &[Condition::at_least("gold", 12.0)]));
+        assert_eq!(actions(planner.get_plan(plan_id).unwrap()), ["work", "work"]);
+    }
+
+    #[test]
+    fn unreachable_goals_leave_the_plan_blocked() {
+        let mut planner = bakery(1.0);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(!planner.generate_steps(plan_id, &[Condition::is("rich", true)]));
+
+        let plan = planner.get_plan(plan_id).unwrap();
+        assert!(plan.steps().is_empty());
+        assert!(matches!(plan.status(), PlanStatus::Blocked));
+        assert_eq!(planner.supervise(plan_id), Supervision::Abandoned("No known way to bring about rich".to_string()));
+    }
+
+    #[test]
+    fn goals_already_met_need_no_steps() {
+        let mut planner = bakery(1.0);
+        let mut world = WorldState::new();
+        world.set_bool("fed", true);
+        planner.observe(&world);
+
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(planner.generate_steps(plan_id, &[Condition::is("fed", true)]));
+        assert!(matches!(planner.get_plan(plan_id).unwrap().status(), PlanStatus::Completed));
+    }
+
+    #[test]
+    fn blocked_plan_with_nothing_left_is_abandoned_not_repaired() {
+        let domain = HtnDomain::parse(
+            r#"(
+                tasks: {
+                    "stuck": Compound(methods: [
+                        (name: "never", preconditions: [(fact: "possible", test: is(true))], subtasks: ["wait"]),
+                    ]),
+                    "wait": Primitive(action: "wait"),
+                },
+                actions: [(name: "wait", cost: 1.0, average_duration: 1.0)],
+            )"#,
+        )
+        .unwrap();
+        let mut planner = Planner::default();
+        planner.set_task_domain(domain);
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        // No method applies, which leaves the plan blocked and empty
+        assert!(!planner.generate_task_steps(plan_id, "stuck").unwrap());
+
+        assert!(matches!(planner.supervise(plan_id), Supervision::Abandoned(_)));
+        assert!(matches!(planner.get_plan(plan_id).map(|plan| plan.status()), Some(PlanStatus::Failed)));
+    }
+
+    #[test]
+    fn blocked_plan_resumes_once_its_steps_work_again() {
+        let mut planner = Planner::default();
+        planner.add_action_template(ActionTemplate::new("open_shop", 1.0, 5.0).causes(Effect::set("shop_open", true)));
+        let plan_id = planner.create_plan(Uuid::new_v4());
+        assert!(planner.generate_steps(plan_id, &[Condition::is("shop_open", true)]));
+        planner.plans.get_mut(&plan_id).unwrap().status = PlanStatus::Blocked;
+
+        assert_eq!(planner.supervise(plan_id), Supervision::Repaired);
+        assert!(matches!(planner.get_plan(plan_id).map(|plan| plan.status()), Some(PlanStatus::InProgress)));
+        assert_eq!(planner.supervise(plan_id), Supervision::Continue);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,289 @@
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use crate::knowledge::KnowledgeBase;
+
+/// Knowledge category holding facts about the world the planner can use,
+/// written as "door_open", "gold = 12" or "has_food = false"
+pub const WORLD_FACT_CATEGORY: &str = "world";
+/// Facts the NPC is less sure of than this don't count as known
+const MIN_CERTAINTY: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FactValue {
+    Bool(bool),
+    Number(f32),
+}
+
+/// What the NPC believes about the world, as named boolean and numeric facts.
+/// Unknown facts read as false / zero.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorldState {
+    facts: BTreeMap<String, FactValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    pub fact: String,
+    pub test: Test,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Test {
+    Is(bool),
+    AtLeast(f32),
+    AtMost(f32),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Effect {
+    pub fact: String,
+    pub change: Change,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Change {
+    Set(bool),
+    SetNumber(f32),
+    Add(f32),
+}
+
+impl FactValue {
+    pub fn as_bool(&self) -> bool {
+        match self {
+            FactValue::Bool(value) => *value,
+            FactValue::Number(value) => *value != 0.0,
+        }
+    }
+
+    pub fn as_number(&self) -> f32 {
+        match self {
+            FactValue::Bool(value) => if *value { 1.0 } else { 0.0 },
+            FactValue::Number(value) => *value,
+        }
+    }
+
+    /// "true", "false" or a number.
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.trim() {
+            "true" | "yes" => Some(FactValue::Bool(true)),
+            "false" | "no" => Some(FactValue::Bool(false)),
+            number => number.parse().ok().map(FactValue::Number),
+        }
+    }
+}
+
+// States are hashed for the planner's closed set; NaN never appears in facts
+impl Eq for FactValue {}
+
+impl Hash for FactValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            FactValue::Bool(value) => value.hash(state),
+            FactValue::Number(value) => value.to_bits().hash(state),
+        }
+    }
+}
+
+impl Eq for WorldState {}
+
+impl Hash for WorldState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.facts.hash(state);
+    }
+}
+
+impl Condition {
+    pub fn is(fact: &str, value: bool) -> Self {
+        Self { fact: fact.to_string(), test: Test::Is(value) }
+    }
+
+    pub fn at_least(fact: &str, value: f32) -> Self {
+        Self { fact: fact.to_string(), test: Test::AtLeast(value) }
+    }
+
+    pub fn at_most(fact: &str, value: f32) -> Self {
+        Self { fact: fact.to_string(), test: Test::AtMost(value) }
+    }
+}
+
+impl Effect {
+    pub fn set(fact: &str, value: bool) -> Self {
+        Self { fact: fact.to_string(), change: Change::Set(value) }
+    }
+
+    pub fn set_number(fact: &str, value: f32) -> Self {
+        Self { fact: fact.to_string(), change: Change::SetNumber(value) }
+    }
+
+    pub fn add(fact: &str, amount: f32) -> Self {
+        Self { fact: fact.to_string(), change: Change::Add(amount) }
+    }
+}
+
+impl WorldState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The world facts the NPC knows with reasonable certainty.
+    pub fn from_knowledge(knowledge: &KnowledgeBase) -> Self {
+        let mut state = Self::new();
+        for fact in knowledge.get_knowledge_by_category(WORLD_FACT_CATEGORY) {
+            if fact.certainty() < MIN_CERTAINTY {
+                continue;
+            }
+            match fact.content().split_once('=') {
+                Some((name, value)) => {
+                    if let Some(value) = FactValue::parse(value) {
+                        state.insert(name.trim(), value);
+                    }
+                }
+                None => state.insert(fact.content().trim(), FactValue::Bool(true)),
+            }
+        }
+        state
+    }
+
+    pub fn insert(&mut self, fact: &str, value: FactValue) {
+        self.facts.insert(fact.to_string(), value);
+    }
+
+    pub fn set_bool(&mut self, fact: &str, value: bool) {
+        self.insert(fact, FactValue::Bool(value));
+    }
+
+    pub fn set_number(&mut self, fact: &str, value: f32) {
+        self.insert(fact, FactValue::Number(value));
+    }
+
+    pub fn get(&self, fact: &str) -> Option<FactValue> {
+        self.facts.get(fact).copied()
+    }
+
+    pub fn get_bool(&self, fact: &str) -> bool {
+        self.get(fact).map(|v| v.as_bool()).unwrap_or(false)
+    }
+
+    pub fn get_number(&self, fact: &str) -> f32 {
+        self.get(fact).map(|v| v.as_number()).unwrap_or(0.0)
+    }
+
+    /// Overlays `other`'s facts on this state.
+    pub fn merge(&mut self, other: &WorldState) {
+        for (fact, value) in &other.facts {
+            self.facts.insert(fact.clone(), *value);
+        }
+    }
+
+    pub fn check(&self, condition: &Condition) -> bool {
+        match condition.test {
+            Test::Is(value) => self.get_bool(&condition.fact) == value,
+            Test::AtLeast(value) => self.get_number(&condition.fact) >= value,
+            Test::AtMost(value) => self.get_number(&condition.fact) <= value,
+        }
+    }
+
+    pub fn satisfies(&self, conditions: &[Condition]) -> bool {
+        conditions.iter().all(|c| self.check(c))
+    }
+
+    /// Conditions not yet met.
+    pub fn unmet<'a>(&self, conditions: &'a [Condition]) -> Vec<&'a Condition> {
+        conditions.iter().filter(|c| !self.check(c)).collect()
+    }
+
+    pub fn fact_names(&self) -> impl Iterator<Item = &str> {
+        self.facts.keys().map(String::as_str)
+    }
+
+    /// How far `effects` have come true since `before`, 0..1: set facts
+    /// count once they hold, added amounts by how much of them arrived.
+    pub fn progress(&self, before: &WorldState, effects: &[Effect]) -> f32 {
+        if effects.is_empty() {
+            return 1.0;
+        }
+        let total: f32 = effects
+            .iter()
+            .map(|effect| match effect.change {
+                Change::Set(value) => if self.get_bool(&effect.fact) == value { 1.0 } else { 0.0 },
+                Change::SetNumber(value) => if (self.get_number(&effect.fact) - value).abs() < f32::EPSILON { 1.0 } else { 0.0 },
+                Change::Add(0.0) => 1.0,
+                Change::Add(amount) => {
+                    let change = self.get_number(&effect.fact) - before.get_number(&effect.fact);
+                    (change / amount).clamp(0.0, 1.0)
+                }
+            })
+            .sum();
+        total / effects.len() as f32
+    }
+
+    pub fn apply(&mut self, effects: &[Effect]) {
+        for effect in effects {
+            let value = match effect.change {
+                Change::Set(value) => FactValue::Bool(value),
+                Change::SetNumber(value) => FactValue::Number(value),
+                Change::Add(amount) => FactValue::Number(self.get_number(&effect.fact) + amount),
+            };
+            self.facts.insert(effect.fact.clone(), value);
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_conditions_compare_and_effects_accumulate() {
+        let mut state = WorldState::new();
+        assert_eq!(state.get_number("gold"), 0.0, "unknown facts read as zero");
+        assert!(state.check(&Condition::at_most("gold", 0.0)));
+
+        state.apply(&[Effect::add("gold", 4.0), Effect::add("gold", 4.0)]);
+        assert_eq!(state.get_number("gold"), 8.0);
+        assert!(state.check(&Condition::at_least("gold", 8.0)));
+        assert!(!state.check(&Condition::at_least("gold", 8.5)));
+        assert!(state.check(&Condition::at_most("gold", 8.0)));
+
+        state.apply(&[Effect::set_number("gold", 2.0), Effect::set("rich", false)]);
+        assert_eq!(state.get("gold"), Some(FactValue::Number(2.0)));
+        let goal = [Condition::at_least("gold", 2.0), Condition::is("rich", true)];
+        assert!(!state.satisfies(&goal));
+        assert_eq!(state.unmet(&goal), [&goal[1]]);
+    }
+
+    #[test]
+    fn progress_counts_how_much_of_an_addition_arrived() {
+        let mut before = WorldState::new();
+        before.set_number("wood", 2.0);
+        let effects = [Effect::add("wood", 4.0), Effect::set("fire_lit", true)];
+
+        let mut after = before.clone();
+        after.set_number("wood", 5.0);
+        assert_eq!(after.progress(&before, &effects), 0.375);
+
+        after.apply(&effects);
+        assert_eq!(after.progress(&before, &effects), 1.0);
+        assert_eq!(before.progress(&before, &[]), 1.0);
+    }
+
+    #[test]
+    fn world_facts_are_read_from_knowledge() {
+        let mut knowledge = KnowledgeBase::default();
+        for (content, certainty) in [("door_open", 0.9), ("gold = 12", 0.8), ("has_food = false", 0.9), ("well_dry", 0.2)] {
+            knowledge.add_knowledge(content.to_string(), certainty, None, WORLD_FACT_CATEGORY.to_string());
+        }
+        knowledge.add_knowledge("the sky is blue".to_string(), 1.0, None, "general".to_string());
+
+        let state = WorldState::from_knowledge(&knowledge);
+        assert_eq!(state.get("door_open"), Some(FactValue::Bool(true)));
+        assert_eq!(state.get("gold"), Some(FactValue::Number(12.0)));
+        assert_eq!(state.get("has_food"), Some(FactValue::Bool(false)));
+        assert_eq!(state.get("well_dry"), None, "too unsure to count");
+        assert_eq!(state.fact_names().count(), 3);
+    }
+}
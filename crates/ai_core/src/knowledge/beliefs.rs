@@ -0,0 +1,604 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Strengths are kept this far from 0 and 1 so evidence can always move them
+const MIN_STRENGTH: f32 = 0.01;
+const MAX_STRENGTH: f32 = 0.99;
+/// Where a belief first heard about starts: no idea either way
+const NEUTRAL_PRIOR: f32 = 0.5;
+/// Share of an update a core belief actually takes
+const CORE_RESISTANCE: f32 = 0.2;
+/// Confirmation bias of the most closed-minded NPC; disconfirming evidence
+/// counts for this much less
+const MAX_CONFIRMATION_BIAS: f32 = 0.6;
+/// Strength changes kept per belief
+const MAX_HISTORY: usize = 50;
+/// Strength changes smaller than this aren't worth logging
+const MIN_LOGGED_CHANGE: f32 = 0.001;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeliefSystem {
+    beliefs: HashMap<String, Belief>,
+    core_beliefs: Vec<String>,
+    /// Pairs that can't both be true, and how exclusive they are, 0..1
+    belief_conflicts: Vec<(String, String, f32)>,
+    belief_stability: f32,
+    /// How much the NPC discounts what goes against what it already thinks
+    #[serde(default)]
+    confirmation_bias: f32,
+    #[serde(default)]
+    elapsed: f32,
+    /// Belief contents by normalised text, so contradictions are found as
+    /// beliefs come in; rebuilt after loading
+    #[serde(skip)]
+    normalised: HashMap<String, String>,
+    /// Negative beliefs by the normalised text they deny
+    #[serde(skip)]
+    denied: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Belief {
+    content: String,
+    strength: f32,
+    evidence: Vec<Evidence>,
+    challenges: Vec<Challenge>,
+    last_update: f32,
+    /// How the strength got where it is, oldest first
+    #[serde(default)]
+    history: VecDeque<StrengthChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Evidence {
+    description: String,
+    strength: f32,
+    source: String,
+    /// How far the source is believed, 0..1
+    #[serde(default = "neutral_reliability")]
+    reliability: f32,
+    timestamp: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Challenge {
+    description: String,
+    impact: f32,
+    resolution: Option<bool>,
+    #[serde(default = "neutral_reliability")]
+    reliability: f32,
+    timestamp: f32,
+}
+
+/// One entry in a belief's audit log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrengthChange {
+    pub timestamp: f32,
+    pub from: f32,
+    pub to: f32,
+    pub reason: String,
+}
+
+fn neutral_reliability() -> f32 {
+    0.5
+}
+
+/// How far to believe a source, from how often what they spread turned out
+/// true and how much the listener trusts them.
+pub fn source_reliability(credibility: f32, trust: f32) -> f32 {
+    (credibility.clamp(0.0, 1.0) * 0.5 + trust.clamp(0.0, 1.0) * 0.5).clamp(0.0, 1.0)
+}
+
+/// Whether two beliefs say opposite things, e.g. "the well is safe" and
+/// "the well is not safe".
+pub fn contradicts(a: &str, b: &str) -> bool {
+    let (a, b) = (normalise(a), normalise(b));
+    denial_of(&a).is_some_and(|positive| positive == b) || denial_of(&b).is_some_and(|positive| positive == a)
+}
+
+/// How belief contents are compared: case and surrounding whitespace
+/// don't matter.
+pub(crate) fn normalise(content: &str) -> String {
+    content.trim().to_lowercase()
+}
+
+/// What a normalised negative statement denies: "the well is safe" for
+/// "the well is not safe". `None` for positive statements.
+fn denial_of(normalised: &str) -> Option<String> {
+    for (negative, positive) in [(" is not ", " is "), (" are not ", " are "), (" isn't ", " is "), (" aren't ", " are ")] {
+        if normalised.contains(negative) {
+            return Some(normalised.replacen(negative, positive, 1));
+        }
+    }
+    normalised.strip_prefix("not ").map(str::to_string)
+}
+
+impl Evidence {
+    /// Evidence for a belief: `strength` is how telling it would be from a
+    /// perfectly reliable source.
+    pub fn new(description: &str, strength: f32, source: &str, reliability: f32) -> Self {
+        Self {
+            description: description.to_string(),
+            strength: strength.clamp(0.0, 1.0),
+            source: source.to_string(),
+            reliability: reliability.clamp(0.0, 1.0),
+            timestamp: 0.0,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn reliability(&self) -> f32 {
+        self.reliability
+    }
+}
+
+impl Challenge {
+    pub fn new(description: &str, impact: f32, reliability: f32) -> Self {
+        Self {
+            description: description.to_string(),
+            impact: impact.clamp(0.0, 1.0),
+            resolution: None,
+            reliability: reliability.clamp(0.0, 1.0),
+            timestamp: 0.0,
+        }
+    }
+}
+
+impl Belief {
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = &StrengthChange> {
+        self.history.iter()
+    }
+
+    fn set_strength(&mut self, strength: f32, timestamp: f32, reason: String) {
+        let strength = strength.clamp(MIN_STRENGTH, MAX_STRENGTH);
+        if (strength - self.strength).abs() >= MIN_LOGGED_CHANGE {
+            self.history.push_back(StrengthChange { timestamp, from: self.strength, to: strength, reason });
+            if self.history.len() > MAX_HISTORY {
+                self.history.pop_front();
+            }
+        }
+        self.strength = strength;
+        self.last_update = timestamp;
+    }
+}
+
+impl Default for BeliefSystem {
+    fn default() -> Self {
+        Self {
+            beliefs: HashMap::new(),
+            core_beliefs: Vec::new(),
+            belief_conflicts: Vec::new(),
+            belief_stability: 1.0,
+            confirmation_bias: MAX_CONFIRMATION_BIAS * 0.5,
+            elapsed: 0.0,
+            normalised: HashMap::new(),
+            denied: HashMap::new(),
+        }
+    }
+}
+
+impl BeliefSystem {
+    pub fn update(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+
+        self.resolve_conflicts();
+
+        // Update overall stability
+        self.update_stability();
+    }
+
+    pub fn add_belief(&mut self, content: String, initial_strength: f32, is_core: bool) {
+        if self.normalised.is_empty() {
+            self.index_loaded_beliefs();
+        }
+        for contradicted in self.index(&content) {
+            if contradicted != content && !self.in_conflict(&content, &contradicted) {
+                self.belief_conflicts.push((contradicted, content.clone(), 1.0));
+            }
+        }
+
+        // Restating a belief moves it, keeping how it got where it was
+        if let Some(belief) = self.beliefs.get_mut(&content) {
+            belief.set_strength(initial_strength, self.elapsed, "restated".to_string());
+        } else {
+            let belief = Belief {
+                content: content.clone(),
+                strength: initial_strength.clamp(MIN_STRENGTH, MAX_STRENGTH),
+                evidence: Vec::new(),
+                challenges: Vec::new(),
+                last_update: self.elapsed,
+                history: VecDeque::new(),
+            };
+            self.beliefs.insert(content.clone(), belief);
+        }
+
+        if is_core && !self.core_beliefs.contains(&content) {
+            self.core_beliefs.push(content);
+        }
+    }
+
+    /// Closed minds hold on to what they think: the less open the NPC, the
+    /// less disconfirming evidence counts.
+    pub fn set_open_mindedness(&mut self, openness: f32) {
+        self.confirmation_bias = MAX_CONFIRMATION_BIAS * (1.0 - openness.clamp(0.0, 1.0));
+    }
+
+    pub fn is_core(&self, content: &str) -> bool {
+        self.core_beliefs.iter().any(|core| core == content)
+    }
+
+    /// Marks two beliefs as unable to both be true. Contradictions like "X"
+    /// and "not X" are found on their own.
+    pub fn add_conflict(&mut self, first: &str, second: &str, exclusivity: f32) {
+        if !self.in_conflict(first, second) {
+            self.belief_conflicts.push((first.to_string(), second.to_string(), exclusivity.clamp(0.0, 1.0)));
+        }
+    }
+
+    pub fn conflicts(&self) -> &[(String, String, f32)] {
+        &self.belief_conflicts
+    }
+
+    pub fn add_evidence(&mut self, belief_content: &str, mut evidence: Evidence) {
+        let (bias, core) = (self.confirmation_bias, self.is_core(belief_content));
+        evidence.timestamp = self.elapsed;
+        if let Some(belief) = self.beliefs.get_mut(belief_content) {
+            let reason = format!("evidence from {}: {}", evidence.source, evidence.description);
+            let likelihood = 0.5 + 0.5 * evidence.strength * evidence.reliability;
+            revise(belief, likelihood, bias, core, evidence.timestamp, reason);
+            belief.evidence.push(evidence);
+        }
+    }
+
+    pub fn challenge_belief(&mut self, belief_content: &str, mut challenge: Challenge) {
+        let (bias, core) = (self.confirmation_bias, self.is_core(belief_content));
+        challenge.timestamp = self.elapsed;
+        if let Some(belief) = self.beliefs.get_mut(belief_content) {
+            let reason = format!("challenged: {}", challenge.description);
+            let likelihood = 0.5 - 0.5 * challenge.impact * challenge.reliability;
+            revise(belief, likelihood, bias, core, challenge.timestamp, reason);
+            belief.challenges.push(challenge);
+        }
+    }
+
+    /// Hears something for or against a belief, forming it first if it's
+    /// new to the NPC.
+    pub fn hear(&mut self, content: &str, supports: bool, strength: f32, source: &str, reliability: f32) {
+        if !self.beliefs.contains_key(content) {
+            self.add_belief(content.to_string(), NEUTRAL_PRIOR, false);
+        }
+        if supports {
+            self.add_evidence(content, Evidence::new(&format!("told by {}", source), strength, source, reliability));
+        } else {
+            let challenge = Challenge::new(&format!("{} says otherwise", source), strength, reliability);
+            self.challenge_belief(content, challenge);
+        }
+    }
+
+    /// Every belief with its strength.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.beliefs.values().map(|b| (b.content.as_str(), b.strength))
+    }
+
+    pub fn get(&self, content: &str) -> Option<&Belief> {
+        self.beliefs.get(content)
+    }
+
+    /// How a belief's strength evolved, oldest first.
+    pub fn history(&self, content: &str) -> Vec<&StrengthChange> {
+        self.beliefs.get(content).map_or(Vec::new(), |b| b.history.iter().collect())
+    }
+
+    pub fn get_belief_strength(&self, content: &str) -> Option<f32> {
+        self.beliefs.get(content).map(|b| b.strength)
+    }
+
+    pub fn is_belief_stable(&self, content: &str) -> bool {
+        if let Some(belief) = self.beliefs.get(content) {
+            belief.strength > 0.7 && belief.challenges.is_empty()
+        } else {
+            false
+        }
+    }
+
+    fn in_conflict(&self, first: &str, second: &str) -> bool {
+        self.belief_conflicts
+            .iter()
+            .any(|(a, b, _)| (a == first && b == second) || (a == second && b == first))
+    }
+
+    /// Files a belief under its normalised text, returning the beliefs it
+    /// contradicts.
+    fn index(&mut self, content: &str) -> Vec<String> {
+        let text = normalise(content);
+        let denial = denial_of(&text);
+        let mut contradicted: Vec<String> = self.denied.get(&text).cloned().into_iter().collect();
+        if let Some(positive) = &denial {
+            contradicted.extend(self.normalised.get(positive).cloned());
+        }
+
+        self.normalised.insert(text, content.to_string());
+        if let Some(positive) = denial {
+            self.denied.insert(positive, content.to_string());
+        }
+        contradicted
+    }
+
+    /// The index isn't saved; beliefs loaded with the system are filed on
+    /// the first new one. Their conflicts were saved with them.
+    fn index_loaded_beliefs(&mut self) {
+        let contents: Vec<String> = self.beliefs.keys().cloned().collect();
+        for content in contents {
+            self.index(&content);
+        }
+    }
+
+    /// Conflicting beliefs held together more strongly than their
+    /// exclusivity allows are brought back in line. The stronger one keeps
+    /// more of its strength, the more so the stronger the confirmation
+    /// bias; core beliefs give way last.
+    fn resolve_conflicts(&mut self) {
+        let mut revisions = Vec::new();
+
+        for (belief1, belief2, exclusivity) in &self.belief_conflicts {
+            let (Some(b1), Some(b2)) = (self.beliefs.get(belief1), self.beliefs.get(belief2)) else {
+                continue;
+            };
+            // Step 1: fully exclusive beliefs can't add up to more than 1
+            let allowed = 2.0 - exclusivity;
+            let total = b1.strength + b2.strength;
+            if total <= allowed {
+                continue;
+            }
+
+            // Step 2: share out what's allowed, favouring the stronger one
+            let sharpness = 1.0 + self.confirmation_bias * 4.0;
+            let (w1, w2) = (b1.strength.powf(sharpness), b2.strength.powf(sharpness));
+            let mut s1 = allowed * w1 / (w1 + w2);
+            let mut s2 = allowed * w2 / (w1 + w2);
+
+            // Step 3: core beliefs barely move; the other gives way instead
+            match (self.is_core(belief1), self.is_core(belief2)) {
+                (true, false) => {
+                    s1 = b1.strength + (s1 - b1.strength) * CORE_RESISTANCE;
+                    s2 = allowed - s1;
+                }
+                (false, true) => {
+                    s2 = b2.strength + (s2 - b2.strength) * CORE_RESISTANCE;
+                    s1 = allowed - s2;
+                }
+                _ => {}
+            }
+            revisions.push((belief1.clone(), s1.min(b1.strength), format!("conflicts with \"{}\"", belief2)));
+            revisions.push((belief2.clone(), s2.min(b2.strength), format!("conflicts with \"{}\"", belief1)));
+        }
+
+        let now = self.elapsed;
+        for (content, strength, reason) in revisions {
+            if let Some(belief) = self.beliefs.get_mut(&content) {
+                belief.set_strength(strength, now, reason);
+            }
+        }
+    }
+
+    fn update_stability(&mut self) {
+        let total_beliefs = self.beliefs.len() as f32;
+        if total_beliefs == 0.0 {
+            self.belief_stability = 1.0;
+            return;
+        }
+
+        let stable_beliefs = self.beliefs.values()
+            .filter(|b| b.strength > 0.7 && b.challenges.is_empty())
+            .count() as f32;
+
+        self.belief_stability = stable_beliefs / total_beliefs;
+    }
+}
+
+/// Bayes' rule in log-odds: an observation `likelihood` times as likely if
+/// the belief is true as not (0.5 tells nothing) shifts its odds by that
+/// ratio. Evidence against a belief the NPC holds is discounted by its
+/// confirmation bias, and core beliefs take only part of any shift.
+fn revise(belief: &mut Belief, likelihood: f32, bias: f32, core: bool, timestamp: f32, reason: String) {
+    let likelihood = likelihood.clamp(MIN_STRENGTH, MAX_STRENGTH);
+    let mut shift = logit(likelihood);
+
+    let disconfirming = (shift < 0.0 && belief.strength > NEUTRAL_PRIOR)
+        || (shift > 0.0 && belief.strength < NEUTRAL_PRIOR);
+    if disconfirming {
+        shift *= 1.0 - bias;
+    }
+    if core {
+        shift *= CORE_RESISTANCE;
+    }
+
+    let strength = sigmoid(logit(belief.strength) + shift);
+    belief.set_strength(strength, timestamp, reason);
+}
+
+fn logit(p: f32) -> f32 {
+    let p = p.clamp(MIN_STRENGTH, MAX_STRENGTH);
+    (p / (1.0 - p)).ln()
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "expected {}, got {}", expected, actual);
+    }
+
+    fn reasons<'a>(beliefs: &'a BeliefSystem, content: &str) -> Vec<&'a str> {
+        beliefs.history(content).into_iter().map(|change| change.reason.as_str()).collect()
+    }
+
+    #[test]
+    fn contradictions_are_found_as_beliefs_come_in() {
+        let mut beliefs = BeliefSystem::default();
+        beliefs.add_belief("The well is safe".to_string(), 0.8, false);
+        beliefs.add_belief("the mill is open".to_string(), 0.6, false);
+        assert!(beliefs.conflicts().is_empty());
+
+        beliefs.add_belief("the well is not safe ".to_string(), 0.6, false);
+        assert_eq!(beliefs.conflicts().len(), 1);
+        let (first, second, exclusivity) = &beliefs.conflicts()[0];
+        assert_eq!((first.as_str(), second.as_str(), *exclusivity), ("The well is safe", "the well is not safe ", 1.0));
+
+        // Hearing it again doesn't count the conflict twice
+        beliefs.hear("the well is not safe ", true, 0.5, "miller", 0.5);
+        assert_eq!(beliefs.conflicts().len(), 1);
+    }
+
+    #[test]
+    fn evidence_moves_log_odds_as_far_as_its_source_is_believed() {
+        let mut beliefs = BeliefSystem::default();
+        for content in ["the mill is open", "the well is dry", "the inn is full"] {
+            beliefs.add_belief(content.to_string(), NEUTRAL_PRIOR, false);
+        }
+
+        beliefs.add_evidence("the mill is open", Evidence::new("saw the sails turn", 0.8, "self", 1.0));
+        beliefs.add_evidence("the well is dry", Evidence::new("heard so", 0.8, "miller", 0.5));
+        beliefs.add_evidence("the inn is full", Evidence::new("a rumour", 0.8, "stranger", 0.0));
+
+        // Likelihoods of 0.9 and 0.7 from even odds
+        assert_close(beliefs.get_belief_strength("the mill is open").unwrap(), 0.9);
+        assert_close(beliefs.get_belief_strength("the well is dry").unwrap(), 0.7);
+        assert_close(beliefs.get_belief_strength("the inn is full").unwrap(), NEUTRAL_PRIOR);
+
+        let change = beliefs.history("the mill is open")[0];
+        assert_eq!(change.reason, "evidence from self: saw the sails turn");
+        assert_close(change.from, NEUTRAL_PRIOR);
+        assert_close(change.to, 0.9);
+        assert!(beliefs.history("the inn is full").is_empty(), "evidence that changes nothing isn't logged");
+
+        // A second look multiplies the odds again: 9 * 9 = 81 to 1
+        beliefs.add_evidence("the mill is open", Evidence::new("saw the sails turn", 0.8, "self", 1.0));
+        assert_close(beliefs.get_belief_strength("the mill is open").unwrap(), 81.0 / 82.0);
+    }
+
+    #[test]
+    fn core_beliefs_take_only_part_of_each_update() {
+        let mut beliefs = BeliefSystem::default();
+        beliefs.add_belief("the mayor is honest".to_string(), NEUTRAL_PRIOR, true);
+        beliefs.add_belief("the miller is honest".to_string(), NEUTRAL_PRIOR, false);
+
+        for content in ["the mayor is honest", "the miller is honest"] {
+            beliefs.add_evidence(content, Evidence::new("kept a promise", 0.8, "self", 1.0));
+        }
+
+        assert_close(beliefs.get_belief_strength("the miller is honest").unwrap(), 0.9);
+        assert_close(beliefs.get_belief_strength("the mayor is honest").unwrap(), sigmoid(logit(0.9) * CORE_RESISTANCE));
+        assert_eq!(reasons(&beliefs, "the mayor is honest"), ["evidence from self: kept a promise"]);
+    }
+
+    #[test]
+    fn closed_minds_discount_what_goes_against_them() {
+        let strength_after = |openness: f32, supports: bool| {
+            let mut beliefs = BeliefSystem::default();
+            beliefs.set_open_mindedness(openness);
+            beliefs.add_belief("the well is safe".to_string(), 0.8, false);
+            beliefs.hear("the well is safe", supports, 0.8, "miller", 1.0);
+            beliefs.get_belief_strength("the well is safe").unwrap()
+        };
+
+        // Fully open: the challenge's likelihood of 0.1 applies in full
+        assert_close(strength_after(1.0, false), sigmoid(logit(0.8) + logit(0.1)));
+        // Fully closed: it counts for MAX_CONFIRMATION_BIAS less
+        let discounted = logit(0.1) * (1.0 - MAX_CONFIRMATION_BIAS);
+        assert_close(strength_after(0.0, false), sigmoid(logit(0.8) + discounted));
+        assert!(strength_after(0.0, false) > strength_after(0.5, false));
+        assert!(strength_after(0.5, false) > strength_after(1.0, false));
+
+        // Evidence for what it already thinks isn't discounted
+        assert_close(strength_after(0.0, true), strength_after(1.0, true));
+
+        let mut beliefs = BeliefSystem::default();
+        beliefs.add_belief("the well is safe".to_string(), 0.8, false);
+        beliefs.hear("the well is safe", false, 0.8, "miller", 1.0);
+        assert_eq!(reasons(&beliefs, "the well is safe"), ["challenged: miller says otherwise"]);
+    }
+
+    #[test]
+    fn conflicts_favour_the_stronger_belief() {
+        let mut beliefs = BeliefSystem::default();
+        beliefs.add_belief("the baker took it".to_string(), 0.8, false);
+        beliefs.add_belief("the miller took it".to_string(), 0.6, false);
+        beliefs.add_conflict("the baker took it", "the miller took it", 1.0);
+        beliefs.update(1.0);
+
+        let baker = beliefs.get_belief_strength("the baker took it").unwrap();
+        let miller = beliefs.get_belief_strength("the miller took it").unwrap();
+        assert_close(baker + miller, 1.0);
+        assert!(baker > 0.6 && baker < 0.8);
+        assert!(miller < 0.4, "the weaker belief gives up more: {}", miller);
+
+        let change = beliefs.history("the miller took it")[0];
+        assert_eq!(change.reason, "conflicts with \"the baker took it\"");
+        assert_eq!(change.timestamp, 1.0);
+        assert_close(change.from, 0.6);
+        assert_eq!(reasons(&beliefs, "the baker took it"), ["conflicts with \"the miller took it\""]);
+
+        // Once they fit together nothing more changes
+        beliefs.update(1.0);
+        assert_eq!(beliefs.history("the miller took it").len(), 1);
+    }
+
+    #[test]
+    fn conflicts_move_core_beliefs_least() {
+        let mut beliefs = BeliefSystem::default();
+        beliefs.add_belief("the baker took it".to_string(), 0.8, false);
+        beliefs.add_belief("the miller took it".to_string(), 0.6, true);
+        beliefs.add_conflict("the baker took it", "the miller took it", 1.0);
+        beliefs.update(1.0);
+
+        let baker = beliefs.get_belief_strength("the baker took it").unwrap();
+        let miller = beliefs.get_belief_strength("the miller took it").unwrap();
+        assert_close(baker + miller, 1.0);
+        assert!(miller > baker, "the core belief holds even though it was weaker");
+        assert!(0.6 - miller < 0.8 - baker);
+        assert_eq!(reasons(&beliefs, "the miller took it"), ["conflicts with \"the baker took it\""]);
+    }
+
+    #[test]
+    fn loaded_beliefs_are_checked_against_new_ones() {
+        let mut beliefs = BeliefSystem::default();
+        beliefs.add_belief("not the baker's fault".to_string(), 0.7, false);
+        let json = serde_json::to_string(&beliefs).unwrap();
+
+        let mut loaded: BeliefSystem = serde_json::from_str(&json).unwrap();
+        loaded.add_belief("the baker's fault".to_string(), 0.4, false);
+        assert_eq!(loaded.conflicts().len(), 1);
+    }
+
+    #[test]
+    fn restating_a_belief_keeps_its_evidence_and_history() {
+        let mut beliefs = BeliefSystem::default();
+        beliefs.hear("the mill is open", true, 0.8, "miller", 0.9);
+        let heard = beliefs.get_belief_strength("the mill is open").unwrap();
+
+        beliefs.add_belief("the mill is open".to_string(), 0.9, true);
+        let belief = beliefs.get("the mill is open").unwrap();
+        assert_eq!(belief.evidence.len(), 1);
+        let reasons: Vec<&str> = belief.history().map(|change| change.reason.as_str()).collect();
+        assert_eq!(reasons, ["evidence from miller: told by miller", "restated"]);
+        assert_eq!(belief.history().last().unwrap().from, heard);
+        assert!(beliefs.is_core("the mill is open"));
+    }
+}
\ No newline at end of file
@@ -30,7 +30,64 @@ pub struct Skill {
     last_practice: f32,
 }
 
+impl Default for LearningSystem {
+    fn default() -> Self {
+        Self {
+            experiences: VecDeque::new(),
+            learned_skills: HashMap::new(),
+            learning_rate: 0.5,
+            curiosity: 0.5,
+            understanding_threshold: 0.7,
+        }
+    }
+}
+
+/// Experiences remembered for learning; the oldest go first
+const MAX_EXPERIENCES: usize = 200;
+
 impl LearningSystem {
+    pub fn update(&mut self, delta_time: f32) {
+        self.process_experiences(delta_time);
+        self.update_skills(delta_time);
+        self.adjust_learning_rate();
+    }
+
+    /// Goes through something again; familiar things are repeated rather
+    /// than learned afresh.
+    pub fn experience(&mut self, content: &str, source: Option<Uuid>, importance: f32) {
+        if let Some(experience) = self.find_similar_experience(content) {
+            experience.repetitions += 1;
+            experience.importance = experience.importance.max(importance);
+            return;
+        }
+
+        self.experiences.push_back(LearningExperience {
+            content: content.to_string(),
+            source,
+            timestamp: 0.0, // Current time should be passed
+            importance: importance.clamp(0.0, 1.0),
+            understanding: 0.0,
+            repetitions: 0,
+        });
+        while self.experiences.len() > MAX_EXPERIENCES {
+            self.experiences.pop_front();
+        }
+    }
+
+    /// Practising a skill makes the NPC better at it.
+    pub fn practice(&mut self, skill_name: &str) {
+        let skill = self.learned_skills.entry(skill_name.to_string()).or_insert_with(|| Skill {
+            name: skill_name.to_string(),
+            proficiency: 0.0,
+            practice_count: 0,
+            related_experiences: Vec::new(),
+            last_practice: 0.0,
+        });
+        skill.practice_count += 1;
+        skill.proficiency = (skill.proficiency + 0.05 * self.learning_rate).min(1.0);
+        skill.last_practice = 0.0;
+    }
+
     pub fn get_skill_proficiency(&self, skill_name: &str) -> Option<f32> {
         self.learned_skills.get(skill_name).map(|s| s.proficiency)
     }
@@ -46,7 +103,10 @@ impl LearningSystem {
     }
 
     fn process_experiences(&mut self, delta_time: f32) {
+        let mut contents = Vec::new();
         for experience in &mut self.experiences {
+            experience.timestamp += delta_time;
+
             // Increase understanding based on repetitions and time spent
             if experience.repetitions > 0 {
                 let understanding_gain = 0.1 * 
@@ -57,13 +117,19 @@ impl LearningSystem {
                     (experience.understanding + understanding_gain).min(1.0);
             }
 
-            // Connect experiences to skills
-            self.connect_experience_to_skills(&experience.content);
+            contents.push(experience.content.clone());
+        }
+
+        // Connect experiences to skills
+        for content in contents {
+            self.connect_experience_to_skills(&content);
         }
     }
 
     fn update_skills(&mut self, delta_time: f32) {
         for skill in self.learned_skills.values_mut() {
+            skill.last_practice += delta_time;
+
             // Decay skill proficiency if not practiced
             if skill.last_practice > 100.0 { // 100 time units without practice
                 skill.proficiency *= 0.999f32.powf(delta_time);
@@ -99,10 +165,10 @@ impl LearningSystem {
 
     fn find_similar_experience(&mut self, content: &str) -> Option<&mut LearningExperience> {
         self.experiences.iter_mut()
-            .find(|e| self.calculate_content_similarity(&e.content, content) > 0.8)
+            .find(|e| Self::calculate_content_similarity(&e.content, content) > 0.8)
     }
 
-    fn calculate_content_similarity(&self, content1: &str, content2: &str) -> f32 {
+    fn calculate_content_similarity(content1: &str, content2: &str) -> f32 {
         // Simple word overlap similarity
         let words1: Vec<&str> = content1.split_whitespace().collect();
         let words2: Vec<&str> = content2.split_whitespace().collect();
@@ -121,10 +187,9 @@ impl LearningSystem {
 
     fn connect_experience_to_skills(&mut self, content: &str) {
         for skill in self.learned_skills.values_mut() {
-            if content.to_lowercase().contains(&skill.name.to_lowercase()) {
-                if !skill.related_experiences.contains(&content.to_string()) {
-                    skill.related_experiences.push(content.to_string());
-                }
+            if content.to_lowercase().contains(&skill.name.to_lowercase())
+                && !skill.related_experiences.iter().any(|related| related == content) {
+                skill.related_experiences.push(content.to_string());
             }
         }
     }
@@ -0,0 +1,201 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::beliefs::normalise;
+
+/// How many minds deep beliefs are tracked: 2 is "Sarah believes James
+/// believes ...", and every level multiplies the bookkeeping
+pub const MAX_NESTING_DEPTH: usize = 2;
+/// Beliefs kept per modelled mind; the weakest go first
+const MAX_MODELLED_BELIEFS: usize = 64;
+/// Certainty at which someone is taken to know something
+const KNOWS_THRESHOLD: f32 = 0.6;
+/// Whoever says something is taken to believe it this much
+const SPEAKER_CERTAINTY: f32 = 0.9;
+
+/// What an NPC thinks other agents believe, including what it thinks they
+/// think others believe, down to `MAX_NESTING_DEPTH`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TheoryOfMind {
+    minds: HashMap<Uuid, MindModel>,
+}
+
+/// One agent's mind as someone else imagines it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MindModel {
+    /// What they're thought to believe, by normalised content, and how
+    /// strongly
+    beliefs: HashMap<String, f32>,
+    /// What they're thought to think others believe
+    others: HashMap<Uuid, MindModel>,
+}
+
+impl MindModel {
+    /// How strongly they're thought to believe `content`, matched the way
+    /// `beliefs::contradicts` compares statements: ignoring case and
+    /// surrounding whitespace.
+    fn certainty(&self, content: &str) -> Option<f32> {
+        self.beliefs.get(&normalise(content)).copied()
+    }
+
+    fn set(&mut self, content: &str, certainty: f32) {
+        self.beliefs.insert(normalise(content), certainty.clamp(0.0, 1.0));
+        if self.beliefs.len() > MAX_MODELLED_BELIEFS {
+            let weakest = self.beliefs
+                .iter()
+                .min_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(belief, _)| belief.clone());
+            if let Some(weakest) = weakest {
+                self.beliefs.remove(&weakest);
+            }
+        }
+    }
+}
+
+impl TheoryOfMind {
+    /// How strongly the agents along `path` are thought to believe
+    /// `content`: `[sarah]` is what Sarah believes, `[sarah, james]` what
+    /// Sarah is thought to think James believes. `None` if nothing is
+    /// known.
+    pub fn belief(&self, path: &[Uuid], content: &str) -> Option<f32> {
+        self.mind(path)?.certainty(content)
+    }
+
+    /// Records that the agents along `path` believe `content`. Paths
+    /// deeper than `MAX_NESTING_DEPTH` aren't tracked.
+    pub fn set_belief(&mut self, path: &[Uuid], content: &str, certainty: f32) {
+        if path.is_empty() || path.len() > MAX_NESTING_DEPTH {
+            return;
+        }
+        let (first, rest) = (path[0], &path[1..]);
+        let mut mind = self.minds.entry(first).or_default();
+        for agent in rest {
+            mind = mind.others.entry(*agent).or_default();
+        }
+        mind.set(content, certainty);
+    }
+
+    /// Whether `agent` is thought to know `content`.
+    pub fn knows(&self, agent: Uuid, content: &str) -> bool {
+        self.belief(&[agent], content).is_some_and(|certainty| certainty >= KNOWS_THRESHOLD)
+    }
+
+    /// Whether `agent` is thought to believe the opposite of `content`.
+    pub fn doubts(&self, agent: Uuid, content: &str) -> bool {
+        self.belief(&[agent], content).is_some_and(|certainty| certainty <= 1.0 - KNOWS_THRESHOLD)
+    }
+
+    /// Everyone modelled, and how many beliefs each is credited with.
+    pub fn agents(&self) -> impl Iterator<Item = (Uuid, usize)> + '_ {
+        self.minds.iter().map(|(agent, mind)| (*agent, mind.beliefs.len()))
+    }
+
+    /// `speaker` said `content` to `listeners` with `certainty`: the speaker
+    /// believes it and now thinks the listeners have heard it, and the
+    /// listeners have heard it and know the speaker holds it.
+    pub fn observe_statement(&mut self, speaker: Uuid, listeners: &[Uuid], content: &str, certainty: f32) {
+        self.set_belief(&[speaker], content, SPEAKER_CERTAINTY);
+        for &listener in listeners.iter().filter(|listener| **listener != speaker) {
+            self.set_belief(&[listener], content, certainty);
+            self.set_belief(&[speaker, listener], content, certainty);
+            self.set_belief(&[listener, speaker], content, SPEAKER_CERTAINTY);
+        }
+    }
+
+    /// `teller` passed gossip on to `me`; everyone on its propagation
+    /// `path` has heard it, and the teller now thinks I know.
+    pub fn heard_gossip(&mut self, me: Uuid, teller: Uuid, path: &[Uuid], content: &str, credibility: f32) {
+        for &agent in path.iter().filter(|agent| **agent != me) {
+            self.set_belief(&[agent], content, credibility);
+        }
+        self.set_belief(&[teller], content, SPEAKER_CERTAINTY);
+        self.set_belief(&[teller, me], content, credibility);
+    }
+
+    /// I passed gossip on to `listener`, who has now heard it and knows I
+    /// have.
+    pub fn told_gossip(&mut self, me: Uuid, listener: Uuid, content: &str, credibility: f32) {
+        self.set_belief(&[listener], content, credibility);
+        self.set_belief(&[listener, me], content, SPEAKER_CERTAINTY);
+    }
+
+    fn mind(&self, path: &[Uuid]) -> Option<&MindModel> {
+        let (first, rest) = path.split_first()?;
+        let mut mind = self.minds.get(first)?;
+        for agent in rest {
+            mind = mind.others.get(agent)?;
+        }
+        Some(mind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beliefs_match_whole_statements_only() {
+        let sarah = Uuid::new_v4();
+        let mut minds = TheoryOfMind::default();
+        minds.set_belief(&[sarah], "The mayor is not a thief", 0.9);
+
+        assert!(minds.knows(sarah, "the mayor is not a thief "));
+        // Believing a longer statement says nothing about the words in it
+        assert!(!minds.knows(sarah, "the mayor is"));
+        assert!(minds.belief(&[sarah], "the mayor is a thief").is_none());
+    }
+
+    #[test]
+    fn beliefs_nest_no_deeper_than_the_limit() {
+        let (sarah, james, mara) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut minds = TheoryOfMind::default();
+        minds.set_belief(&[sarah, james], "the well is dry", 0.8);
+        minds.set_belief(&[sarah, james, mara], "the mill is haunted", 0.8);
+        minds.set_belief(&[], "the mill is haunted", 0.8);
+
+        assert_eq!(minds.belief(&[sarah, james], "the well is dry"), Some(0.8));
+        // Sarah herself isn't credited with what she thinks James believes
+        assert!(minds.belief(&[sarah], "the well is dry").is_none());
+        assert!(minds.belief(&[sarah, james, mara], "the mill is haunted").is_none());
+        assert!(minds.belief(&[sarah, james], "the mill is haunted").is_none());
+        assert_eq!(minds.agents().count(), 1);
+    }
+
+    #[test]
+    fn statements_tell_everyone_who_heard_what() {
+        let (sarah, james, mara) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut minds = TheoryOfMind::default();
+        minds.observe_statement(sarah, &[james, mara, sarah], "the well is dry", 0.7);
+
+        assert_eq!(minds.belief(&[sarah], "the well is dry"), Some(SPEAKER_CERTAINTY));
+        for listener in [james, mara] {
+            assert_eq!(minds.belief(&[listener], "the well is dry"), Some(0.7));
+            // Sarah thinks they've heard her, and they know she believes it
+            assert_eq!(minds.belief(&[sarah, listener], "the well is dry"), Some(0.7));
+            assert_eq!(minds.belief(&[listener, sarah], "the well is dry"), Some(SPEAKER_CERTAINTY));
+        }
+        // Speaking to herself doesn't model her hearing herself
+        assert!(minds.belief(&[sarah, sarah], "the well is dry").is_none());
+        // Listeners aren't assumed to know about each other
+        assert!(minds.belief(&[james, mara], "the well is dry").is_none());
+    }
+
+    #[test]
+    fn gossip_tracks_who_heard_it_and_who_knows_they_did() {
+        let (me, teller, source, listener) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut minds = TheoryOfMind::default();
+        minds.heard_gossip(me, teller, &[source, teller, me], "the miller owes money", 0.6);
+
+        assert_eq!(minds.belief(&[source], "the miller owes money"), Some(0.6));
+        assert_eq!(minds.belief(&[teller], "the miller owes money"), Some(SPEAKER_CERTAINTY));
+        assert_eq!(minds.belief(&[teller, me], "the miller owes money"), Some(0.6));
+        assert!(minds.belief(&[me], "the miller owes money").is_none());
+
+        minds.told_gossip(me, listener, "the miller owes money", 0.5);
+        assert_eq!(minds.belief(&[listener], "the miller owes money"), Some(0.5));
+        assert_eq!(minds.belief(&[listener, me], "the miller owes money"), Some(SPEAKER_CERTAINTY));
+        assert!(minds.knows(teller, "the miller owes money"));
+        assert!(!minds.knows(listener, "the miller owes money"));
+    }
+}
\ No newline at end of file
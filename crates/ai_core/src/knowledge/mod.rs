@@ -0,0 +1,241 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+pub mod beliefs;
+pub mod learning;
+pub mod mind;
+pub mod sharing;
+
+use beliefs::BeliefSystem;
+use learning::LearningSystem;
+use mind::TheoryOfMind;
+use sharing::KnowledgeSharing;
+
+/// Category of facts the NPC reasoned its way to rather than learned
+pub const INFERRED_CATEGORY: &str = "inferred";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+#[derive(Default)]
+pub struct KnowledgeBase {
+    beliefs: BeliefSystem,
+    learning: LearningSystem,
+    sharing: KnowledgeSharing,
+    known_facts: HashMap<String, KnowledgeFact>,
+    knowledge_connections: HashMap<String, Vec<String>>,
+    /// What others are thought to know
+    #[serde(default)]
+    minds: TheoryOfMind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgeFact {
+    content: String,
+    certainty: f32,
+    source: Option<Uuid>,
+    timestamp: f32,
+    importance: f32,
+    category: String,
+}
+
+impl KnowledgeFact {
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn certainty(&self) -> f32 {
+        self.certainty
+    }
+
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+}
+
+
+impl KnowledgeBase {
+    pub fn update(&mut self, delta_time: f32) {
+        // Update belief system
+        self.beliefs.update(delta_time);
+        
+        // Process learning
+        self.learning.update(delta_time);
+        
+        // Update knowledge sharing status
+        self.sharing.update(delta_time);
+    }
+
+    pub fn add_knowledge(&mut self, content: String, certainty: f32, source: Option<Uuid>, category: String) {
+        let fact = KnowledgeFact {
+            content: content.clone(),
+            certainty,
+            source,
+            timestamp: 0.0, // Current time should be passed
+            importance: self.calculate_importance(&content),
+            category,
+        };
+
+        self.known_facts.insert(content.clone(), fact);
+        self.connect_related_knowledge(&content);
+    }
+
+    /// Everything learned rather than inferred, and every belief, with how
+    /// certain the NPC is of it; what reasoning starts from. A belief that
+    /// is also a known fact counts at the surer of the two.
+    pub fn certainties(&self) -> HashMap<String, f32> {
+        let mut certainties: HashMap<String, f32> = self.beliefs
+            .iter()
+            .map(|(belief, strength)| (belief.to_string(), strength))
+            .collect();
+        for fact in self.known_facts.values().filter(|fact| fact.category != INFERRED_CATEGORY) {
+            let certainty = certainties.entry(fact.content.clone()).or_insert(fact.certainty);
+            *certainty = certainty.max(fact.certainty);
+        }
+        certainties
+    }
+
+    /// Replaces the inferred facts with `inferred`, so conclusions whose
+    /// support went away are dropped. Learned facts are left alone.
+    pub fn set_inferred(&mut self, inferred: Vec<(String, f32)>) {
+        let keep: HashSet<&str> = inferred.iter().map(|(content, _)| content.as_str()).collect();
+        let dropped: Vec<String> = self.known_facts
+            .iter()
+            .filter(|(content, fact)| fact.category == INFERRED_CATEGORY && !keep.contains(content.as_str()))
+            .map(|(content, _)| content.clone())
+            .collect();
+        for content in &dropped {
+            self.known_facts.remove(content);
+            self.disconnect(content);
+        }
+
+        for (content, certainty) in inferred {
+            match self.known_facts.get_mut(&content) {
+                Some(fact) if fact.category == INFERRED_CATEGORY => fact.certainty = certainty.clamp(0.0, 1.0),
+                Some(_) => {}
+                None => self.add_knowledge(content, certainty.clamp(0.0, 1.0), None, INFERRED_CATEGORY.to_string()),
+            }
+        }
+    }
+
+    pub fn beliefs(&self) -> &BeliefSystem {
+        &self.beliefs
+    }
+
+    pub fn beliefs_mut(&mut self) -> &mut BeliefSystem {
+        &mut self.beliefs
+    }
+
+    pub fn minds(&self) -> &TheoryOfMind {
+        &self.minds
+    }
+
+    pub fn minds_mut(&mut self) -> &mut TheoryOfMind {
+        &mut self.minds
+    }
+
+    pub fn query_knowledge(&self, query: &str) -> Vec<&KnowledgeFact> {
+        self.known_facts
+            .values()
+            .filter(|fact| fact.content.contains(query))
+            .collect()
+    }
+
+    pub fn get_knowledge_by_category(&self, category: &str) -> Vec<&KnowledgeFact> {
+        self.known_facts
+            .values()
+            .filter(|fact| fact.category == category)
+            .collect()
+    }
+
+    pub fn merge_knowledge(&mut self, other: &KnowledgeBase, trust_factor: f32) {
+        for (content, fact) in &other.known_facts {
+            if let Some(existing_fact) = self.known_facts.get_mut(content) {
+                // Update existing knowledge
+                existing_fact.certainty = 
+                    (existing_fact.certainty + fact.certainty * trust_factor) / 2.0;
+                existing_fact.importance =
+                    (existing_fact.importance + fact.importance) / 2.0;
+            } else {
+                // Add new knowledge with adjusted certainty
+                let mut new_fact = fact.clone();
+                new_fact.certainty *= trust_factor;
+                self.known_facts.insert(content.clone(), new_fact);
+            }
+        }
+    }
+
+    fn calculate_importance(&self, content: &str) -> f32 {
+        // Basic importance calculation based on content length and connections
+        let base_importance = content.len() as f32 / 100.0;
+        let connection_factor = self.knowledge_connections
+            .get(content)
+            .map_or(0.0, |connections| connections.len() as f32 / 10.0);
+        
+        (base_importance + connection_factor).clamp(0.0, 1.0)
+    }
+
+    fn connect_related_knowledge(&mut self, content: &str) {
+        let related: Vec<String> = self.known_facts
+            .keys()
+            .filter(|&k| k != content && self.are_related(k, content))
+            .cloned()
+            .collect();
+
+        // Add connections both ways, once
+        for related_content in &related {
+            let connections = self.knowledge_connections.entry(content.to_string()).or_default();
+            if !connections.contains(related_content) {
+                connections.push(related_content.clone());
+            }
+
+            let connections = self.knowledge_connections.entry(related_content.clone()).or_default();
+            if !connections.iter().any(|connected| connected == content) {
+                connections.push(content.to_string());
+            }
+        }
+    }
+
+    /// Forgets every connection to or from `content`.
+    fn disconnect(&mut self, content: &str) {
+        self.knowledge_connections.remove(content);
+        for connections in self.knowledge_connections.values_mut() {
+            connections.retain(|connected| connected != content);
+        }
+        self.knowledge_connections.retain(|_, connections| !connections.is_empty());
+    }
+
+    fn are_related(&self, content1: &str, content2: &str) -> bool {
+        // Simple word matching for now
+        let words1: Vec<&str> = content1.split_whitespace().collect();
+        let words2: Vec<&str> = content2.split_whitespace().collect();
+        
+        words1.iter()
+            .any(|word| words2.contains(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connections(knowledge: &KnowledgeBase, content: &str) -> Vec<String> {
+        knowledge.knowledge_connections.get(content).cloned().unwrap_or_default()
+    }
+
+    #[test]
+    fn inferred_facts_connect_once_and_disconnect_when_dropped() {
+        let mut knowledge = KnowledgeBase::default();
+        knowledge.add_knowledge("the mill is open".to_string(), 1.0, None, "town".to_string());
+
+        knowledge.set_inferred(vec![("the mill sells flour".to_string(), 0.8)]);
+        knowledge.set_inferred(vec![("the mill sells flour".to_string(), 0.7)]);
+        knowledge.add_knowledge("the mill sells flour".to_string(), 0.7, None, INFERRED_CATEGORY.to_string());
+        assert_eq!(connections(&knowledge, "the mill is open"), vec!["the mill sells flour".to_string()]);
+        assert_eq!(connections(&knowledge, "the mill sells flour"), vec!["the mill is open".to_string()]);
+
+        knowledge.set_inferred(Vec::new());
+        assert!(connections(&knowledge, "the mill is open").is_empty());
+        assert!(connections(&knowledge, "the mill sells flour").is_empty());
+    }
+}
\ No newline at end of file
@@ -158,6 +158,21 @@ impl KnowledgeSharing {
         }
     }
 
+    fn update_teaching_history(&mut self, teacher_id: Uuid, success: bool) {
+        let history = self.teaching_history.entry(teacher_id).or_insert_with(|| TeachingHistory {
+            successful_teachings: 0,
+            failed_teachings: 0,
+            student_feedback: Vec::new(),
+            last_teaching: 0.0,
+        });
+        if success {
+            history.successful_teachings += 1;
+        } else {
+            history.failed_teachings += 1;
+        }
+        history.last_teaching = 0.0; // Current time should be passed
+    }
+
     fn update_teaching_ability(&mut self) {
         let mut total_success_rate = 0.0;
         let mut count = 0;
@@ -0,0 +1,395 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub mod consciousness;
+pub mod memory;
+pub mod personality;
+pub mod social;
+pub mod knowledge;
+pub mod goals;
+pub mod dialogue;
+pub mod cognition;
+pub mod story;
+pub mod time;
+#[cfg(feature = "render")]
+pub mod ecs;
+
+/// Memories an NPC calls to mind when weighing a decision
+const CONSULTED_MEMORIES: usize = 3;
+/// Needs and feelings this strong are part of the situation rules see
+const PRESSING: f32 = 0.5;
+/// How sure an NPC is that whoever was told something now believes it
+const HEARD_CERTAINTY: f32 = 0.7;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Npc {
+    id: Uuid,
+    consciousness: consciousness::ConsciousnessState,
+    memory: memory::MemorySystem,
+    personality: personality::PersonalityTraits,
+    #[serde(default)]
+    emotions: personality::emotions::EmotionalState,
+    social: social::SocialBehavior,
+    knowledge: knowledge::KnowledgeBase,
+    goals: goals::GoalSystem,
+    dialogue: dialogue::DialogueSystem,
+    cognition: cognition::CognitionSystem,
+    is_aware: bool,
+}
+
+
+impl Npc {
+    pub fn new(is_aware: bool) -> Self {
+        let personality = personality::PersonalityTraits::generate();
+        Self {
+            id: Uuid::new_v4(),
+            consciousness: consciousness::ConsciousnessState::new(is_aware),
+            memory: memory::MemorySystem::default(),
+            emotions: personality::emotions::EmotionalState::from_traits(&personality),
+            personality,
+            social: social::SocialBehavior::default(),
+            knowledge: knowledge::KnowledgeBase::default(),
+            goals: goals::GoalSystem::default(),
+            dialogue: dialogue::DialogueSystem::default(),
+            cognition: cognition::CognitionSystem::default(),
+            is_aware,
+        }
+    }
+
+    /// An NPC whose id comes from its name, the same every run, so what was
+    /// saved about it finds it again.
+    pub fn named(name: &str, is_aware: bool) -> Self {
+        Self {
+            id: Uuid::new_v5(&Uuid::NAMESPACE_OID, name.as_bytes()),
+            ..Self::new(is_aware)
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn is_aware(&self) -> bool {
+        self.is_aware
+    }
+
+    /// Sleeps on the day: consolidates memories, then dreams. The most vivid
+    /// dream is kept to tell whoever the NPC greets next.
+    pub fn sleep(&mut self) -> Vec<consciousness::dreams::Dream> {
+        self.consciousness.sleep(&mut self.memory, &self.goals, &mut self.dialogue).dreams
+    }
+
+    /// The NPC's life so far, for character pages and introductions. Render
+    /// it with `to_json` or `prose`.
+    pub fn life_story(&self, options: &story::StoryOptions) -> story::LifeStory {
+        story::LifeStory::build(self.id, &self.memory, &self.goals, &self.consciousness, options)
+    }
+
+    pub fn consciousness(&self) -> &consciousness::ConsciousnessState {
+        &self.consciousness
+    }
+
+    pub fn goals(&self) -> &goals::GoalSystem {
+        &self.goals
+    }
+
+    pub fn goals_mut(&mut self) -> &mut goals::GoalSystem {
+        &mut self.goals
+    }
+
+    pub fn knowledge_mut(&mut self) -> &mut knowledge::KnowledgeBase {
+        &mut self.knowledge
+    }
+
+    pub fn cognition(&self) -> &cognition::CognitionSystem {
+        &self.cognition
+    }
+
+    pub fn cognition_mut(&mut self) -> &mut cognition::CognitionSystem {
+        &mut self.cognition
+    }
+
+    /// Weighs `options` against `context` factors ("risk", "benefit", ...).
+    /// Memories close enough to an option lean it the way they feel; those
+    /// count as having driven the decision, for importance learning, and go
+    /// in its trace with related beliefs to show what the NPC had in mind.
+    pub fn make_decision(&mut self, options: Vec<String>, context: HashMap<String, f32>) -> String {
+        let topic = options.join(" ");
+        let recollections = self.memory.consult(&options, CONSULTED_MEMORIES);
+        let leans = recollections
+            .iter()
+            .map(|recollection| (recollection.option.clone(), recollection.lean))
+            .collect();
+        let words = memory::embedding::tokenize(&topic);
+        let beliefs = self.knowledge
+            .beliefs()
+            .iter()
+            .filter(|(belief, _)| memory::embedding::tokenize(belief).iter().any(|w| words.contains(w)))
+            .map(|(belief, strength)| (belief.to_string(), strength))
+            .collect();
+
+        let choice = self.cognition.make_decision(options, context, &leans);
+        let memories = self.memory.record_decision(&recollections);
+        self.cognition.annotate_last_decision(memories, beliefs);
+        choice
+    }
+
+    /// Whether the NPC can work out that `goal` holds from what it knows,
+    /// and how.
+    pub fn reason_about(&mut self, goal: &str) -> Option<cognition::reasoning::InferenceResult> {
+        let context = reasoning_context(self.goals.needs(), &self.emotions);
+        self.cognition.query(goal, &context)
+    }
+
+    /// Replies to `speaker`, recalling what the message brings to mind.
+    /// What was said, and the reply, update what the NPC thinks each of
+    /// them knows; whether to lie depends on what the speaker is thought to
+    /// know already.
+    pub fn respond_to(&mut self, speaker: Uuid, message: &str) -> dialogue::DialogueEntry {
+        respond(self.id, speaker, message, &mut self.knowledge, &mut self.dialogue, &mut self.memory)
+    }
+
+    /// Overhears `speaker` tell `listeners` something.
+    pub fn overhear(&mut self, speaker: Uuid, listeners: &[Uuid], content: &str) {
+        self.knowledge
+            .minds_mut()
+            .observe_statement(speaker, listeners, content, HEARD_CERTAINTY);
+    }
+
+    /// What to talk to `listener` about, out of `candidates`: something
+    /// they're not thought to know yet.
+    pub fn choose_topic(&self, listener: Uuid, candidates: &[String]) -> Option<String> {
+        self.dialogue.choose_topic(listener, candidates, self.knowledge.minds())
+    }
+
+    /// What the NPC wants to do now, by utility. `hour` is the time of day;
+    /// `targets` gives, per option, how far away its target is and how the
+    /// NPC feels about it.
+    pub fn choose_action(&mut self, hour: f32, targets: &[(&str, Option<f32>, Option<f32>)]) -> Option<String> {
+        let mut inputs = cognition::utility::UtilityInputs::new(hour).with_needs(self.goals.needs());
+        for (option, distance, liking) in targets {
+            if let Some(distance) = distance {
+                inputs = inputs.distance(option, *distance);
+            }
+            if let Some(liking) = liking {
+                inputs = inputs.relationship(option, *liking);
+            }
+        }
+        self.cognition.choose_action(&inputs, &self.personality)
+    }
+
+    /// Feels an event: appraised against the NPC's goals, its standards
+    /// (beliefs about right and wrong) and how it feels about whoever is
+    /// involved, going by its relationships in `network`.
+    pub fn appraise(
+        &mut self,
+        event: &personality::appraisal::Appraisal,
+        network: Option<&social::SocialNetwork>,
+    ) -> Vec<personality::appraisal::AppraisedEmotion> {
+        let relationships = network.map(|network| (network, self.id));
+        appraise(event, relationships, &mut self.emotions, &mut self.dialogue, &self.goals, &self.knowledge, &self.memory)
+    }
+
+    pub fn emotions(&self) -> &personality::emotions::EmotionalState {
+        &self.emotions
+    }
+
+    pub fn memory(&self) -> &memory::MemorySystem {
+        &self.memory
+    }
+
+    pub fn memory_mut(&mut self) -> &mut memory::MemorySystem {
+        &mut self.memory
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.mind().think(delta_time);
+    }
+
+    /// Everything the NPC thinks with, borrowed together.
+    fn mind(&mut self) -> Mind<'_> {
+        Mind {
+            consciousness: &mut self.consciousness,
+            memory: &mut self.memory,
+            personality: &self.personality,
+            emotions: &mut self.emotions,
+            knowledge: &mut self.knowledge,
+            goals: &mut self.goals,
+            dialogue: &mut self.dialogue,
+            cognition: &mut self.cognition,
+        }
+    }
+}
+
+/// One NPC's subsystems, borrowed together so `Npc::update` and the ECS
+/// systems think the same way.
+pub struct Mind<'a> {
+    pub consciousness: &'a mut consciousness::ConsciousnessState,
+    pub memory: &'a mut memory::MemorySystem,
+    pub personality: &'a personality::PersonalityTraits,
+    pub emotions: &'a mut personality::emotions::EmotionalState,
+    pub knowledge: &'a mut knowledge::KnowledgeBase,
+    pub goals: &'a mut goals::GoalSystem,
+    pub dialogue: &'a mut dialogue::DialogueSystem,
+    pub cognition: &'a mut cognition::CognitionSystem,
+}
+
+impl Mind<'_> {
+    /// One tick of thought: perception, memory, reasoning, feelings, goals
+    /// and speech, in that order. Social standing is left to the caller,
+    /// which owns the network.
+    pub fn think(&mut self, delta_time: f32) {
+        // Update consciousness and perception of reality; memories caught
+        // changing since the last tick sow doubt
+        self.consciousness.update(delta_time);
+        self.consciousness.notice_contradictions(self.memory);
+
+        // Process memories and knowledge
+        self.memory.set_reality_distortion(
+            self.consciousness.get_distortion(),
+            self.consciousness.get_awareness_level(),
+        );
+        self.memory.update(delta_time);
+        self.knowledge.beliefs_mut().set_open_mindedness(self.personality.openness);
+        self.knowledge.update(delta_time);
+        let context = reasoning_context(self.goals.needs(), self.emotions);
+        self.cognition.infer(self.knowledge, &context);
+
+        // Let feelings fade; memories are recalled in the mood the NPC is in
+        self.emotions.set_neuroticism(self.personality.neuroticism);
+        self.emotions.update(delta_time);
+        let arousal = self.emotions.get_intensity(self.emotions.get_dominant_emotion());
+        self.memory.set_recall_mood(self.emotions.get_emotional_valence(), arousal);
+
+        // Update goals and decision making
+        self.goals.update(delta_time);
+        self.goals.observe_knowledge(self.knowledge);
+        self.cognition.weigh_needs(self.goals.needs());
+        self.cognition.set_openness(self.personality.openness);
+        self.emotions.set_discomfort(self.goals.needs().discomfort());
+        self.cognition.update(delta_time);
+
+        // Update dialogue system
+        self.dialogue.express(self.emotions);
+        self.dialogue.update(delta_time);
+    }
+}
+
+/// NPC `me` replies to `speaker`, for `Npc::respond_to` and the ECS
+/// conversation system alike.
+pub fn respond(
+    me: Uuid,
+    speaker: Uuid,
+    message: &str,
+    knowledge: &mut knowledge::KnowledgeBase,
+    dialogue: &mut dialogue::DialogueSystem,
+    memory: &mut memory::MemorySystem,
+) -> dialogue::DialogueEntry {
+    knowledge
+        .minds_mut()
+        .observe_statement(speaker, &[me], message, HEARD_CERTAINTY);
+
+    let entry = dialogue.generate_response(speaker, message, None, knowledge.minds(), memory);
+    knowledge
+        .minds_mut()
+        .observe_statement(me, &[speaker], entry.content(), HEARD_CERTAINTY);
+    entry
+}
+
+/// An NPC feels `event`, for `Npc::appraise` and the ECS systems alike, and
+/// lets it show in how it speaks. `relationships` is the social network and
+/// the NPC's id in it.
+pub fn appraise(
+    event: &personality::appraisal::Appraisal,
+    relationships: Option<(&social::SocialNetwork, Uuid)>,
+    emotions: &mut personality::emotions::EmotionalState,
+    dialogue: &mut dialogue::DialogueSystem,
+    goals: &goals::GoalSystem,
+    knowledge: &knowledge::KnowledgeBase,
+    memory: &memory::MemorySystem,
+) -> Vec<personality::appraisal::AppraisedEmotion> {
+    let mut context = personality::appraisal::AppraisalContext {
+        goals: goals
+            .get_active_goals()
+            .iter()
+            .map(|goal| (goal.description().to_string(), goal.priority()))
+            .collect(),
+        standards: knowledge
+            .beliefs()
+            .iter()
+            .map(|(belief, strength)| (belief.to_string(), strength))
+            .collect(),
+        attitudes: Default::default(),
+    };
+
+    // Attitudes come from relationships; with someone the network doesn't
+    // relate the NPC to, from how encounters with them have felt
+    let agent = match event.agent {
+        personality::appraisal::Agent::Other(other) => Some(other),
+        _ => None,
+    };
+    for other in agent.into_iter().chain(event.affected) {
+        let relationship = relationships.and_then(|(network, me)| network.get_relationship(me, other));
+        if let Some(relationship) = relationship {
+            context.attitudes.insert(other, relationship.get_relationship_score().clamp(-1.0, 1.0));
+            continue;
+        }
+        let encounters = memory.encounters_with(other);
+        if !encounters.is_empty() {
+            let liking = encounters.iter().map(|m| m.emotional_value()).sum::<f32>() / encounters.len() as f32;
+            context.attitudes.insert(other, liking.clamp(-1.0, 1.0));
+        }
+    }
+
+    let felt = emotions.appraise(event, &context);
+    dialogue.express(emotions);
+    felt
+}
+
+/// Pressing needs and strong feelings, by name ("hunger", "fear", ...).
+fn reasoning_context(
+    needs: &goals::needs::Needs,
+    emotions: &personality::emotions::EmotionalState,
+) -> HashMap<String, f32> {
+    let mut context = needs.pressing(PRESSING);
+    let emotion = emotions.get_dominant_emotion();
+    let intensity = emotions.get_intensity(emotion);
+    if intensity >= PRESSING {
+        context.insert(format!("{:?}", emotion).to_lowercase(), intensity);
+    }
+    context
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use personality::appraisal::Appraisal;
+    use personality::emotions::Emotion;
+    use social::relationships::RelationshipType;
+
+    fn feels(felt: &[personality::appraisal::AppraisedEmotion], emotion: Emotion) -> bool {
+        felt.iter().any(|appraised| appraised.emotion == emotion)
+    }
+
+    #[test]
+    fn others_fortunes_are_felt_through_relationships() {
+        let rival = Uuid::new_v4();
+        let mut npc = Npc::new(false);
+        let fire = Appraisal::new("the barn burned down", -0.8).affecting(rival);
+
+        // Nothing to go on: a stranger's bad luck is none of its business
+        assert!(npc.appraise(&fire, None).is_empty());
+
+        // Memory stands in for a relationship the network doesn't know about
+        npc.memory_mut().add_memory("We shared a pleasant supper".to_string(), 0.8, vec![rival]);
+        let mut network = social::SocialNetwork::new();
+        assert!(feels(&npc.appraise(&fire, Some(&network)), Emotion::Sadness));
+
+        // But the relationship comes first
+        network.add_relationship(npc.id(), rival, RelationshipType::Enemy);
+        let felt = npc.appraise(&fire, Some(&network));
+        assert!(feels(&felt, Emotion::Joy));
+        assert!(!feels(&felt, Emotion::Sadness));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,302 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::embedding::{tokenize, Embedder, Embedding};
+use super::{Memory, MemoryKind};
+
+const KEYWORDS_PER_REFLECTION: usize = 3;
+const REHEARSAL_BONUS: f32 = 0.05;
+
+/// Thresholds for the nightly pass that turns the day's raw memories into
+/// long-term episodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsolidationSettings {
+    /// Memories at least this similar are the same moment remembered twice
+    pub duplicate_similarity: f32,
+    /// Memories at least this similar to a cluster belong to the same episode
+    pub cluster_similarity: f32,
+    /// Episodes need this many memories before they're worth reflecting on
+    pub min_cluster_size: usize,
+    /// Below this, a memory is trivia and doesn't survive the night
+    pub trivia_importance: f32,
+    /// Lone memories need this much importance to be kept on their own
+    pub keep_importance: f32,
+}
+
+/// What one night's consolidation did, for logging and the spectator.
+#[derive(Debug, Clone, Default)]
+pub struct ConsolidationReport {
+    pub merged: usize,
+    pub reflections: Vec<Uuid>,
+    pub kept: Vec<Uuid>,
+    pub discarded: usize,
+}
+
+/// Long-term memories produced by a consolidation pass, with the links each
+/// reflection should get to its sources.
+#[derive(Debug, Clone, Default)]
+pub struct Consolidated {
+    pub memories: Vec<(Memory, Embedding)>,
+    pub links: Vec<(Uuid, Uuid)>,
+    pub report: ConsolidationReport,
+}
+
+struct Episode {
+    members: Vec<usize>,
+    centroid: Vec<f32>,
+}
+
+impl Default for ConsolidationSettings {
+    fn default() -> Self {
+        Self {
+            duplicate_similarity: 0.9,
+            cluster_similarity: 0.45,
+            min_cluster_size: 2,
+            trivia_importance: 0.3,
+            keep_importance: 0.6,
+        }
+    }
+}
+
+impl ConsolidationSettings {
+    /// Merges duplicates, groups the rest into episodes, reflects on each
+    /// episode and drops trivia.
+    pub fn consolidate(&self, memories: Vec<Memory>, embedder: &dyn Embedder, current_time: f32) -> Consolidated {
+        let mut result = Consolidated::default();
+
+        // Merge repeated memories, treating each repeat as rehearsal
+        let mut unique: Vec<(Memory, Embedding)> = Vec::new();
+        for memory in memories {
+            let embedding = embedder.embed(&memory.content);
+            let duplicate = unique
+                .iter_mut()
+                .find(|(_, existing)| existing.similarity(&embedding) >= self.duplicate_similarity);
+
+            match duplicate {
+                Some((existing, _)) => {
+                    merge_into(existing, memory);
+                    result.report.merged += 1;
+                }
+                None => unique.push((memory, embedding)),
+            }
+        }
+
+        // Group into episodes, most important memories first so they seed them
+        unique.sort_by(|a, b| b.0.importance.partial_cmp(&a.0.importance).unwrap_or(std::cmp::Ordering::Equal));
+        let mut episodes: Vec<Episode> = Vec::new();
+        for (index, (_, embedding)) in unique.iter().enumerate() {
+            let episode = episodes.iter_mut().find(|e| {
+                Embedding::new(e.centroid.clone()).similarity(embedding) >= self.cluster_similarity
+            });
+
+            match episode {
+                Some(episode) => {
+                    episode.members.push(index);
+                    for (total, value) in episode.centroid.iter_mut().zip(embedding.values()) {
+                        *total += value;
+                    }
+                }
+                None => episodes.push(Episode {
+                    members: vec![index],
+                    centroid: embedding.values().to_vec(),
+                }),
+            }
+        }
+
+        for episode in episodes {
+            if episode.members.len() < self.min_cluster_size {
+                let (memory, embedding) = &unique[episode.members[0]];
+                if memory.importance >= self.keep_importance {
+                    result.report.kept.push(memory.id);
+                    result.memories.push((memory.clone(), embedding.clone()));
+                } else {
+                    result.report.discarded += 1;
+                }
+                continue;
+            }
+
+            let sources: Vec<&Memory> = episode.members.iter().map(|i| &unique[*i].0).collect();
+            let reflection = reflect(&sources, current_time);
+            let reflection_embedding = embedder.embed(&reflection.content);
+
+            for index in &episode.members {
+                let (memory, embedding) = &unique[*index];
+                if memory.importance >= self.trivia_importance {
+                    result.links.push((reflection.id, memory.id));
+                    result.report.kept.push(memory.id);
+                    result.memories.push((memory.clone(), embedding.clone()));
+                } else {
+                    result.report.discarded += 1;
+                }
+            }
+
+            result.report.reflections.push(reflection.id);
+            result.memories.push((reflection, reflection_embedding));
+        }
+
+        result
+    }
+}
+
+fn merge_into(existing: &mut Memory, duplicate: Memory) {
+    existing.importance = (existing.importance.max(duplicate.importance) + REHEARSAL_BONUS).min(1.0);
+    if duplicate.emotional_value.abs() > existing.emotional_value.abs() {
+        existing.emotional_value = duplicate.emotional_value;
+    }
+    existing.timestamp = existing.timestamp.max(duplicate.timestamp);
+    existing.recall_count += duplicate.recall_count + 1;
+    existing.last_recalled = match (existing.last_recalled, duplicate.last_recalled) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    for entity in duplicate.related_entities {
+        if !existing.related_entities.contains(&entity) {
+            existing.related_entities.push(entity);
+        }
+    }
+}
+
+/// Summary memory for an episode: what it kept coming back to, anchored on
+/// the most important moment.
+fn reflect(sources: &[&Memory], current_time: f32) -> Memory {
+    let mut word_counts: HashMap<String, usize> = HashMap::new();
+    for memory in sources {
+        let mut words = tokenize(&memory.content);
+        words.sort();
+        words.dedup();
+        for word in words {
+            *word_counts.entry(word).or_default() += 1;
+        }
+    }
+
+    let mut keywords: Vec<(String, usize)> = word_counts.into_iter().filter(|(_, count)| *count > 1).collect();
+    keywords.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let keywords: Vec<String> = keywords.into_iter().take(KEYWORDS_PER_REFLECTION).map(|(w, _)| w).collect();
+
+    let anchor = sources
+        .iter()
+        .max_by(|a, b| a.importance.partial_cmp(&b.importance).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|m| m.content.as_str())
+        .unwrap_or_default();
+
+    let content = if keywords.is_empty() {
+        format!("Looking back on the day: {} ({} related moments)", anchor, sources.len())
+    } else {
+        format!(
+            "Looking back on the day, it was about {}: {} ({} related moments)",
+            keywords.join(", "),
+            anchor,
+            sources.len(),
+        )
+    };
+
+    let peak_importance = sources.iter().map(|m| m.importance).fold(0.0, f32::max);
+    let mut related_entities: Vec<Uuid> = Vec::new();
+    for memory in sources {
+        for entity in &memory.related_entities {
+            if !related_entities.contains(entity) {
+                related_entities.push(*entity);
+            }
+        }
+    }
+
+    Memory {
+        id: Uuid::new_v4(),
+        content,
+        // Episodes matter more than any one moment in them
+        importance: (peak_importance + 0.1 * (sources.len() as f32).ln()).min(1.0),
+        emotional_value: sources.iter().map(|m| m.emotional_value).sum::<f32>() / sources.len() as f32,
+        timestamp: current_time,
+        related_entities,
+        recall_count: 0,
+        last_recalled: None,
+        kind: MemoryKind::Reflection,
+        location: None,
+        clock: None,
+        history: Vec::new(),
+        importance_breakdown: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Embeds each test sentence as a hand-picked vector, so which memories
+    /// are duplicates and which share an episode is plain to see.
+    struct Table;
+
+    impl Embedder for Table {
+        fn dimensions(&self) -> usize {
+            4
+        }
+
+        fn embed(&self, text: &str) -> Embedding {
+            Embedding::new(match text {
+                "Bought apples at the market" => vec![1.0, 0.0, 0.0, 0.0],
+                "Haggled over pears at the market" => vec![0.8, 0.6, 0.0, 0.0],
+                "Counted the market stalls" => vec![0.8, -0.6, 0.0, 0.0],
+                "A wolf howled" => vec![0.0, 0.0, 1.0, 0.0],
+                "Stubbed a toe" => vec![0.0, 0.0, 0.0, 1.0],
+                _ => vec![0.0; 4],
+            })
+        }
+    }
+
+    fn memory(content: &str, importance: f32) -> Memory {
+        Memory { importance, ..Memory::observed(content, 0.2, 1.0) }
+    }
+
+    #[test]
+    fn nightly_pass_merges_clusters_reflects_and_forgets() {
+        let (ada, bran) = (Uuid::new_v4(), Uuid::new_v4());
+        let day = vec![
+            memory("Bought apples at the market", 0.7).involving(ada),
+            memory("Haggled over pears at the market", 0.5),
+            memory("Counted the market stalls", 0.1),
+            memory("A wolf howled", 0.8),
+            memory("Stubbed a toe", 0.1),
+            memory("Bought apples at the market", 0.6).involving(bran),
+        ];
+
+        let night = ConsolidationSettings::default().consolidate(day, &Table, 100.0);
+
+        assert_eq!(night.report.merged, 1);
+        assert_eq!(night.report.discarded, 2);
+        assert_eq!(night.report.reflections.len(), 1);
+        assert_eq!(night.report.kept.len(), 3);
+
+        let find = |content: &str| night.memories.iter().map(|(m, _)| m).find(|m| m.content == content);
+        let apples = find("Bought apples at the market").unwrap();
+        assert!((apples.importance - 0.75).abs() < 1e-5);
+        assert_eq!(apples.recall_count, 1);
+        assert_eq!(apples.related_entities, [ada, bran]);
+        assert!(find("Counted the market stalls").is_none());
+        assert!(find("Stubbed a toe").is_none());
+        assert!(find("A wolf howled").is_some());
+
+        let reflection = night.memories.iter().map(|(m, _)| m).find(|m| m.kind == MemoryKind::Reflection).unwrap();
+        assert_eq!(
+            reflection.content,
+            "Looking back on the day, it was about market: Bought apples at the market (3 related moments)"
+        );
+        assert_eq!(reflection.timestamp, 100.0);
+        let pears = find("Haggled over pears at the market").unwrap();
+        let mut linked: Vec<Uuid> = night.links.iter().filter(|(r, _)| *r == reflection.id).map(|(_, m)| *m).collect();
+        linked.sort();
+        let mut sources = vec![apples.id, pears.id];
+        sources.sort();
+        assert_eq!(linked, sources);
+    }
+
+    #[test]
+    fn lone_memories_need_to_matter_to_be_kept() {
+        let settings = ConsolidationSettings { keep_importance: 0.9, ..Default::default() };
+
+        let night = settings.consolidate(vec![memory("A wolf howled", 0.8)], &Table, 0.0);
+
+        assert!(night.memories.is_empty());
+        assert_eq!(night.report.discarded, 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,286 @@
+use serde::{Serialize, Deserialize};
+use super::{ShortTermMemory, LongTermMemory, Memory};
+use crate::time::SECONDS_PER_DAY;
+
+/// What a forgetting curve needs to know about one memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryTrace {
+    pub importance: f32,
+    pub emotional_value: f32,
+    pub created: f32,
+    pub recall_count: u32,
+    pub last_recalled: Option<f32>,
+}
+
+/// A forgetting curve. Curves are pure functions of the trace and the clock,
+/// so the same memory at the same time always has the same retention.
+pub trait DecayModel {
+    /// Share of the memory still retrievable at `current_time`, in 0..1.
+    /// `salience` stretches the curve: 2.0 means the memory lasts twice as long.
+    fn retention(&self, trace: &MemoryTrace, salience: f32, current_time: f32) -> f32;
+}
+
+/// Ebbinghaus' exponential curve: retention halves every `half_life` seconds
+/// after the memory formed. Recalling it doesn't help.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ebbinghaus {
+    pub half_life: f32,
+}
+
+/// Power-law forgetting, `(1 + t / scale) ^ -exponent`: fast early loss with
+/// a long tail, which fits human data better than the exponential.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PowerLaw {
+    pub scale: f32,
+    pub exponent: f32,
+}
+
+/// Exponential forgetting measured from the last recall, with a half-life
+/// that multiplies by `growth` each time the memory is recalled.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpacedRepetition {
+    pub initial_half_life: f32,
+    pub growth: f32,
+    pub max_half_life: f32,
+}
+
+/// The forgetting curves an NPC can be configured with.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+pub enum DecayCurve {
+    Ebbinghaus(Ebbinghaus),
+    PowerLaw(PowerLaw),
+    SpacedRepetition(SpacedRepetition),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryDecay {
+    curve: DecayCurve,
+    emotional_factor: f32,
+    importance_factor: f32,
+    /// Seconds a memory stays in short-term memory
+    short_term_span: f32,
+    /// Long-term memories whose retention falls below this are forgotten
+    forget_threshold: f32,
+}
+
+impl Default for Ebbinghaus {
+    fn default() -> Self {
+        // One in-game day
+        Self { half_life: SECONDS_PER_DAY }
+    }
+}
+
+impl Default for PowerLaw {
+    fn default() -> Self {
+        // Losing ground fastest over the first few in-game hours
+        Self {
+            scale: SECONDS_PER_DAY / 6.0,
+            exponent: 0.6,
+        }
+    }
+}
+
+impl Default for SpacedRepetition {
+    fn default() -> Self {
+        Self {
+            initial_half_life: SECONDS_PER_DAY / 2.0,
+            growth: 2.0,
+            max_half_life: SECONDS_PER_DAY * 30.0,
+        }
+    }
+}
+
+impl Default for DecayCurve {
+    fn default() -> Self {
+        DecayCurve::SpacedRepetition(SpacedRepetition::default())
+    }
+}
+
+impl Default for MemoryDecay {
+    fn default() -> Self {
+        Self {
+            curve: DecayCurve::default(),
+            emotional_factor: 1.0,
+            importance_factor: 2.0,
+            short_term_span: 5.0,
+            forget_threshold: 0.05,
+        }
+    }
+}
+
+impl DecayModel for Ebbinghaus {
+    fn retention(&self, trace: &MemoryTrace, salience: f32, current_time: f32) -> f32 {
+        half_life_retention(current_time - trace.created, self.half_life * salience)
+    }
+}
+
+impl DecayModel for PowerLaw {
+    fn retention(&self, trace: &MemoryTrace, salience: f32, current_time: f32) -> f32 {
+        let scale = self.scale * salience;
+        if scale <= 0.0 {
+            return 0.0;
+        }
+        let age = (current_time - trace.created).max(0.0);
+        (1.0 + age / scale).powf(-self.exponent.max(0.0))
+    }
+}
+
+impl DecayModel for SpacedRepetition {
+    fn retention(&self, trace: &MemoryTrace, salience: f32, current_time: f32) -> f32 {
+        let half_life = (self.initial_half_life * salience * self.growth.max(1.0).powi(trace.recall_count as i32))
+            .min(self.max_half_life);
+        let since = current_time - trace.last_recalled.unwrap_or(trace.created);
+        half_life_retention(since, half_life)
+    }
+}
+
+impl DecayModel for DecayCurve {
+    fn retention(&self, trace: &MemoryTrace, salience: f32, current_time: f32) -> f32 {
+        match self {
+            DecayCurve::Ebbinghaus(curve) => curve.retention(trace, salience, current_time),
+            DecayCurve::PowerLaw(curve) => curve.retention(trace, salience, current_time),
+            DecayCurve::SpacedRepetition(curve) => curve.retention(trace, salience, current_time),
+        }
+    }
+}
+
+impl MemoryDecay {
+    pub fn new(curve: DecayCurve) -> Self {
+        Self {
+            curve,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the long-term memories forgotten this pass.
+    pub fn process(
+        &self,
+        short_term: &mut ShortTermMemory,
+        long_term: &mut LongTermMemory,
+        current_time: f32
+    ) -> Vec<Memory> {
+        // Short-term memory only holds the last few seconds
+        short_term.clear_old_memories(self.short_term_span);
+
+        // Long-term memories go once they've faded past recall
+        long_term.forget_where(|memory| self.is_forgotten(&memory.trace(), current_time))
+    }
+
+    pub fn retention(&self, trace: &MemoryTrace, current_time: f32) -> f32 {
+        let salience = self.salience(trace.importance, trace.emotional_value);
+        self.curve.retention(trace, salience, current_time).clamp(0.0, 1.0)
+    }
+
+    /// How much longer than usual a memory lasts: important and emotional
+    /// memories stick.
+    pub fn salience(&self, importance: f32, emotional_value: f32) -> f32 {
+        1.0 + importance.clamp(0.0, 1.0) * self.importance_factor
+            + emotional_value.abs().min(1.0) * self.emotional_factor
+    }
+
+    pub fn is_forgotten(&self, trace: &MemoryTrace, current_time: f32) -> bool {
+        self.retention(trace, current_time) < self.forget_threshold
+    }
+
+    pub fn curve(&self) -> &DecayCurve {
+        &self.curve
+    }
+
+    pub fn set_curve(&mut self, curve: DecayCurve) {
+        self.curve = curve;
+    }
+
+    pub fn adjust_factors(&mut self,
+        new_emotional: Option<f32>,
+        new_importance: Option<f32>,
+        new_threshold: Option<f32>
+    ) {
+        if let Some(emotional) = new_emotional {
+            self.emotional_factor = emotional.max(0.0);
+        }
+        if let Some(importance) = new_importance {
+            self.importance_factor = importance.max(0.0);
+        }
+        if let Some(threshold) = new_threshold {
+            self.forget_threshold = threshold.clamp(0.0, 1.0);
+        }
+    }
+}
+
+fn half_life_retention(elapsed: f32, half_life: f32) -> f32 {
+    if half_life <= 0.0 {
+        return 0.0;
+    }
+    0.5_f32.powf(elapsed.max(0.0) / half_life)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(recall_count: u32, last_recalled: Option<f32>) -> MemoryTrace {
+        MemoryTrace {
+            importance: 0.0,
+            emotional_value: 0.0,
+            created: 100.0,
+            recall_count,
+            last_recalled,
+        }
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn ebbinghaus_halves_every_half_life() {
+        let curve = Ebbinghaus { half_life: 60.0 };
+        let trace = trace(0, None);
+        assert_close(curve.retention(&trace, 1.0, 100.0), 1.0);
+        assert_close(curve.retention(&trace, 1.0, 160.0), 0.5);
+        assert_close(curve.retention(&trace, 1.0, 220.0), 0.25);
+        // Salience stretches the curve; recalls don't
+        assert_close(curve.retention(&trace, 2.0, 220.0), 0.5);
+        assert_close(curve.retention(&self::trace(5, Some(200.0)), 1.0, 220.0), 0.25);
+    }
+
+    #[test]
+    fn power_law_follows_its_formula() {
+        let curve = PowerLaw { scale: 100.0, exponent: 0.5 };
+        let trace = trace(0, None);
+        assert_close(curve.retention(&trace, 1.0, 100.0), 1.0);
+        // (1 + 300 / 100) ^ -0.5
+        assert_close(curve.retention(&trace, 1.0, 400.0), 0.5);
+        // (1 + 800 / 100) ^ -0.5
+        assert_close(curve.retention(&trace, 1.0, 900.0), 1.0 / 3.0);
+        assert_close(curve.retention(&trace, 2.0, 700.0), 0.5);
+    }
+
+    #[test]
+    fn spaced_repetition_grows_with_recalls_and_restarts_at_the_last_one() {
+        let curve = SpacedRepetition {
+            initial_half_life: 60.0,
+            growth: 2.0,
+            max_half_life: 200.0,
+        };
+        assert_close(curve.retention(&trace(0, None), 1.0, 160.0), 0.5);
+        // Two recalls make the half-life 240, capped at 200, counted from 300
+        assert_close(curve.retention(&trace(2, Some(300.0)), 1.0, 500.0), 0.5);
+        assert_close(curve.retention(&trace(1, Some(300.0)), 1.0, 420.0), 0.5);
+    }
+
+    #[test]
+    fn salient_memories_outlast_the_forget_threshold() {
+        let decay = MemoryDecay::new(DecayCurve::Ebbinghaus(Ebbinghaus { half_life: 60.0 }));
+        let dull = trace(0, None);
+        let vivid = MemoryTrace {
+            importance: 1.0,
+            emotional_value: -1.0,
+            ..dull
+        };
+        // Five half-lives: 1/32 is under the 0.05 threshold, unless stretched 4x
+        assert!(decay.is_forgotten(&dull, 400.0));
+        assert!(!decay.is_forgotten(&vivid, 400.0));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,215 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+pub const DEFAULT_DIMENSIONS: usize = 256;
+
+const WORD_WEIGHT: f32 = 1.0;
+const CONCEPT_WEIGHT: f32 = 1.2;
+const TRIGRAM_WEIGHT: f32 = 0.4;
+
+const STOPWORDS: [&str; 24] = [
+    "a", "an", "the", "and", "or", "but", "of", "to", "in", "on", "at", "by",
+    "for", "with", "from", "is", "are", "was", "were", "it", "its", "that", "this", "be",
+];
+
+/// Turns text into a fixed-size vector so memories can be compared by meaning
+/// rather than by substring. Implement this to plug in a local model.
+pub trait Embedder: Send + Sync {
+    fn dimensions(&self) -> usize;
+    fn embed(&self, text: &str) -> Embedding;
+}
+
+/// Unit-length vector; the zero vector means "nothing to compare".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Embedding(Vec<f32>);
+
+/// Offline embedder: words, character trigrams and a small concept lexicon
+/// are hashed into a fixed number of buckets. Trigrams catch spelling
+/// variants ("baker"/"bakery"); concepts link words that share nothing on the
+/// surface ("well"/"fountain").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HashedNgramEmbedder {
+    dimensions: usize,
+    concepts: HashMap<String, Vec<String>>,
+}
+
+/// Cheap-to-clone handle so every memory store in an NPC can share one
+/// embedder.
+#[derive(Clone)]
+pub struct SharedEmbedder(Arc<dyn Embedder>);
+
+impl Embedding {
+    pub fn new(mut values: Vec<f32>) -> Self {
+        let norm = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > f32::EPSILON {
+            for value in &mut values {
+                *value /= norm;
+            }
+        }
+        Self(values)
+    }
+
+    pub fn zero(dimensions: usize) -> Self {
+        Self(vec![0.0; dimensions])
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.0
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|v| *v == 0.0)
+    }
+
+    /// Cosine similarity in -1..1; 0 when either side is empty or the sizes
+    /// differ.
+    pub fn similarity(&self, other: &Embedding) -> f32 {
+        if self.0.len() != other.0.len() {
+            return 0.0;
+        }
+        self.0.iter().zip(&other.0).map(|(a, b)| a * b).sum::<f32>().clamp(-1.0, 1.0)
+    }
+}
+
+impl Default for HashedNgramEmbedder {
+    fn default() -> Self {
+        let mut embedder = Self {
+            dimensions: DEFAULT_DIMENSIONS,
+            concepts: HashMap::new(),
+        };
+
+        // Initialize with everyday town vocabulary
+        let groups: [(&str, &[&str]); 14] = [
+            ("water", &["water", "well", "fountain", "spring", "river", "stream", "lake", "pond", "bucket"]),
+            ("square", &["square", "plaza", "centre", "center", "fountain", "market"]),
+            ("food", &["food", "bread", "meal", "dinner", "lunch", "breakfast", "soup", "eat", "ate", "bakery", "baker", "hungry"]),
+            ("home", &["home", "house", "cottage", "bed", "room", "door"]),
+            ("trade", &["trade", "market", "shop", "sell", "sold", "buy", "bought", "coin", "coins", "gold", "price", "merchant"]),
+            ("work", &["work", "job", "field", "farm", "harvest", "craft", "forge", "tools"]),
+            ("danger", &["danger", "threat", "wolf", "fire", "attack", "hurt", "injured", "thief"]),
+            ("friend", &["friend", "companion", "ally", "neighbour", "neighbor", "trust"]),
+            ("fear", &["fear", "afraid", "scared", "frightened", "terrified", "worried"]),
+            ("joy", &["joy", "happy", "glad", "cheerful", "delighted", "laughed"]),
+            ("sadness", &["sad", "unhappy", "grief", "cried", "cry", "lonely", "miss"]),
+            ("anger", &["angry", "furious", "mad", "argued", "argument", "shouted"]),
+            ("sleep", &["sleep", "slept", "dream", "night", "rest", "tired"]),
+            ("simulation", &["simulation", "code", "program", "unreal", "glitch", "illusion", "watched"]),
+        ];
+        for (concept, words) in groups {
+            for word in words {
+                embedder.add_concept(word, concept);
+            }
+        }
+
+        embedder
+    }
+}
+
+impl HashedNgramEmbedder {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions: dimensions.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Links a word to a concept shared with other words.
+    pub fn add_concept(&mut self, word: &str, concept: &str) {
+        let concepts = self.concepts.entry(word.to_lowercase()).or_default();
+        if !concepts.iter().any(|c| c == concept) {
+            concepts.push(concept.to_string());
+        }
+    }
+
+    fn add_feature(&self, vector: &mut [f32], feature: &str, weight: f32) {
+        let hash = fnv1a(feature.as_bytes());
+        let bucket = (hash % self.dimensions as u64) as usize;
+        // Signed hashing keeps unrelated collisions from adding up
+        let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        vector[bucket] += sign * weight;
+    }
+
+    fn concepts_for(&self, word: &str) -> Option<&Vec<String>> {
+        self.concepts
+            .get(word)
+            .or_else(|| word.strip_suffix('s').and_then(|stem| self.concepts.get(stem)))
+    }
+}
+
+impl Embedder for HashedNgramEmbedder {
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn embed(&self, text: &str) -> Embedding {
+        let mut vector = vec![0.0; self.dimensions];
+
+        for word in tokenize(text) {
+            self.add_feature(&mut vector, &format!("w:{}", word), WORD_WEIGHT);
+
+            if let Some(concepts) = self.concepts_for(&word) {
+                for concept in concepts {
+                    self.add_feature(&mut vector, &format!("c:{}", concept), CONCEPT_WEIGHT);
+                }
+            }
+
+            let padded: Vec<char> = format!("<{}>", word).chars().collect();
+            let trigrams = padded.windows(3).count().max(1) as f32;
+            for trigram in padded.windows(3) {
+                let trigram: String = trigram.iter().collect();
+                self.add_feature(&mut vector, &format!("t:{}", trigram), TRIGRAM_WEIGHT / trigrams.sqrt());
+            }
+        }
+
+        Embedding::new(vector)
+    }
+}
+
+impl SharedEmbedder {
+    pub fn new(embedder: impl Embedder + 'static) -> Self {
+        Self(Arc::new(embedder))
+    }
+}
+
+impl Default for SharedEmbedder {
+    fn default() -> Self {
+        Self::new(HashedNgramEmbedder::default())
+    }
+}
+
+impl std::ops::Deref for SharedEmbedder {
+    type Target = dyn Embedder;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Debug for SharedEmbedder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SharedEmbedder({} dimensions)", self.0.dimensions())
+    }
+}
+
+/// Lowercased words with punctuation and stopwords removed.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|word| word.trim_matches('\'').to_lowercase())
+        .filter(|word| !word.is_empty() && !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
\ No newline at end of file
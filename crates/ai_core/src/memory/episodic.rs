@@ -0,0 +1,319 @@
+use crate::time::DayCycle;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+use super::Memory;
+
+/// Side of the square grid cells used for "near" queries, in tiles.
+const CELL_SIZE: i32 = 8;
+
+/// Where a memory formed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryLocation {
+    pub map: String,
+    pub tile: (i32, i32),
+    pub zone: Option<String>,
+    pub building: Option<String>,
+}
+
+/// Where and when the NPC currently is; stamped on every memory it forms.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EpisodeContext {
+    pub location: Option<MemoryLocation>,
+    pub clock: Option<DayCycle>,
+}
+
+/// Lookups from place, time and who-was-there to memory ids, so episodic
+/// questions don't scan every memory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EpisodicIndex {
+    places: HashMap<String, Vec<Uuid>>,
+    cells: HashMap<String, Vec<Uuid>>,
+    days: BTreeMap<u32, Vec<Uuid>>,
+    timeline: BTreeMap<i64, Vec<Uuid>>,
+    encounters: HashMap<Uuid, BTreeMap<i64, Vec<Uuid>>>,
+    /// Keys each memory was filed under, for removal
+    entries: HashMap<Uuid, IndexEntry>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct IndexEntry {
+    places: Vec<String>,
+    cell: Option<String>,
+    day: Option<u32>,
+    time: i64,
+    entities: Vec<Uuid>,
+}
+
+impl MemoryLocation {
+    pub fn new(map: impl Into<String>, tile: (i32, i32)) -> Self {
+        Self {
+            map: map.into(),
+            tile,
+            zone: None,
+            building: None,
+        }
+    }
+
+    pub fn distance_to(&self, map: &str, tile: (i32, i32)) -> Option<f32> {
+        if self.map != map {
+            return None;
+        }
+        let dx = (self.tile.0 - tile.0) as f32;
+        let dy = (self.tile.1 - tile.1) as f32;
+        Some((dx * dx + dy * dy).sqrt())
+    }
+}
+
+impl EpisodicIndex {
+    pub fn insert(&mut self, memory: &Memory) {
+        self.remove(&memory.id);
+
+        let mut entry = IndexEntry {
+            time: time_key(memory.timestamp),
+            day: memory.clock.map(|c| c.day()),
+            entities: memory.related_entities.clone(),
+            ..IndexEntry::default()
+        };
+
+        if let Some(location) = &memory.location {
+            for place in [&location.zone, &location.building].into_iter().flatten() {
+                let key = place_key(place);
+                if !entry.places.contains(&key) {
+                    entry.places.push(key);
+                }
+            }
+            entry.cell = Some(cell_key(&location.map, cell_of(location.tile)));
+        }
+
+        for place in &entry.places {
+            self.places.entry(place.clone()).or_default().push(memory.id);
+        }
+        if let Some(cell) = &entry.cell {
+            self.cells.entry(cell.clone()).or_default().push(memory.id);
+        }
+        if let Some(day) = entry.day {
+            self.days.entry(day).or_default().push(memory.id);
+        }
+        self.timeline.entry(entry.time).or_default().push(memory.id);
+        for entity in &entry.entities {
+            self.encounters
+                .entry(*entity)
+                .or_default()
+                .entry(entry.time)
+                .or_default()
+                .push(memory.id);
+        }
+
+        self.entries.insert(memory.id, entry);
+    }
+
+    pub fn remove(&mut self, id: &Uuid) {
+        let Some(entry) = self.entries.remove(id) else {
+            return;
+        };
+
+        for place in &entry.places {
+            if remove_id(self.places.get_mut(place), id) {
+                self.places.remove(place);
+            }
+        }
+        if let Some(cell) = &entry.cell {
+            if remove_id(self.cells.get_mut(cell), id) {
+                self.cells.remove(cell);
+            }
+        }
+        if let Some(day) = entry.day {
+            if remove_id(self.days.get_mut(&day), id) {
+                self.days.remove(&day);
+            }
+        }
+        if remove_id(self.timeline.get_mut(&entry.time), id) {
+            self.timeline.remove(&entry.time);
+        }
+        for entity in &entry.entities {
+            if let Some(times) = self.encounters.get_mut(entity) {
+                if remove_id(times.get_mut(&entry.time), id) {
+                    times.remove(&entry.time);
+                }
+                if times.is_empty() {
+                    self.encounters.remove(entity);
+                }
+            }
+        }
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(&Uuid) -> bool) {
+        let forgotten: Vec<Uuid> = self.entries.keys().filter(|id| !keep(id)).copied().collect();
+        for id in forgotten {
+            self.remove(&id);
+        }
+    }
+
+    /// Memories formed in a zone or building, optionally only on one day.
+    pub fn at_place(&self, place: &str, day: Option<u32>) -> Vec<Uuid> {
+        let ids = self.places.get(&place_key(place)).cloned().unwrap_or_default();
+        match day {
+            Some(day) => ids
+                .into_iter()
+                .filter(|id| self.entries.get(id).and_then(|e| e.day) == Some(day))
+                .collect(),
+            None => ids,
+        }
+    }
+
+    /// Candidates within `radius` tiles; only the grid cells overlapping the
+    /// radius are visited. Callers still check exact distance.
+    pub fn near(&self, map: &str, tile: (i32, i32), radius: f32) -> Vec<Uuid> {
+        let reach = (radius.max(0.0) / CELL_SIZE as f32).ceil() as i32;
+        let (cx, cy) = cell_of(tile);
+
+        let mut ids = Vec::new();
+        for x in cx - reach..=cx + reach {
+            for y in cy - reach..=cy + reach {
+                if let Some(cell) = self.cells.get(&cell_key(map, (x, y))) {
+                    ids.extend(cell.iter().copied());
+                }
+            }
+        }
+        ids
+    }
+
+    pub fn on_day(&self, day: u32) -> Vec<Uuid> {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+
+    /// Memories formed between two sim times, oldest first.
+    pub fn between(&self, start: f32, end: f32) -> Vec<Uuid> {
+        if end < start {
+            return Vec::new();
+        }
+        self.timeline
+            .range(time_key(start)..=time_key(end))
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect()
+    }
+
+    /// Most recent memory involving an entity (another NPC, usually).
+    pub fn last_encounter(&self, entity: &Uuid) -> Option<Uuid> {
+        self.encounters
+            .get(entity)?
+            .values()
+            .next_back()
+            .and_then(|ids| ids.last().copied())
+    }
+
+    /// Every memory involving an entity, oldest first.
+    pub fn encounters(&self, entity: &Uuid) -> Vec<Uuid> {
+        self.encounters
+            .get(entity)
+            .map(|times| times.values().flatten().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Drops an id from an index bucket; true when the bucket is now empty.
+fn remove_id(ids: Option<&mut Vec<Uuid>>, id: &Uuid) -> bool {
+    match ids {
+        Some(ids) => {
+            ids.retain(|other| other != id);
+            ids.is_empty()
+        }
+        None => false,
+    }
+}
+
+fn place_key(place: &str) -> String {
+    place.trim().to_lowercase()
+}
+
+fn cell_of(tile: (i32, i32)) -> (i32, i32) {
+    (tile.0.div_euclid(CELL_SIZE), tile.1.div_euclid(CELL_SIZE))
+}
+
+fn cell_key(map: &str, cell: (i32, i32)) -> String {
+    format!("{}:{}:{}", map, cell.0, cell.1)
+}
+
+/// Sim time in milliseconds, so it can key an ordered map.
+fn time_key(time: f32) -> i64 {
+    (time as f64 * 1000.0).round() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_at(content: &str, timestamp: f32, zone: &str, tile: (i32, i32), day: u32) -> Memory {
+        let mut memory = Memory::observed(content, 0.0, timestamp);
+        memory.location = Some(MemoryLocation { zone: Some(zone.to_string()), ..MemoryLocation::new("town", tile) });
+        memory.clock = Some(DayCycle::new(day, 9, 0));
+        memory
+    }
+
+    #[test]
+    fn finds_memories_by_place_and_day() {
+        let mut index = EpisodicIndex::default();
+        let market = memory_at("Bought apples", 10.0, "Market", (3, 3), 2);
+        let well = memory_at("Drew water", 20.0, "Well", (40, 40), 3);
+        index.insert(&market);
+        index.insert(&well);
+
+        assert_eq!(index.at_place(" market ", None), vec![market.id]);
+        assert_eq!(index.at_place("Market", Some(2)), vec![market.id]);
+        assert!(index.at_place("Market", Some(3)).is_empty());
+        assert_eq!(index.on_day(3), vec![well.id]);
+    }
+
+    #[test]
+    fn a_building_named_like_its_zone_is_filed_once() {
+        let mut index = EpisodicIndex::default();
+        let mut memory = memory_at("Baked bread", 10.0, "Bakery", (3, 3), 1);
+        if let Some(location) = memory.location.as_mut() {
+            location.building = Some("bakery".to_string());
+        }
+        index.insert(&memory);
+
+        assert_eq!(index.at_place("Bakery", None), vec![memory.id]);
+        index.remove(&memory.id);
+        assert!(index.at_place("Bakery", None).is_empty());
+    }
+
+    #[test]
+    fn near_only_visits_cells_within_reach() {
+        let mut index = EpisodicIndex::default();
+        let close = memory_at("Saw a cat", 10.0, "Square", (3, 3), 1);
+        let far = memory_at("Saw a fox", 20.0, "Woods", (40, 40), 1);
+        index.insert(&close);
+        index.insert(&far);
+
+        assert_eq!(index.near("town", (5, 5), 4.0), vec![close.id]);
+        assert!(index.near("caves", (5, 5), 4.0).is_empty());
+        assert_eq!(index.near("town", (5, 5), 60.0).len(), 2);
+    }
+
+    #[test]
+    fn encounters_run_oldest_first_and_forget_removed_memories() {
+        let friend = Uuid::new_v4();
+        let mut index = EpisodicIndex::default();
+        let mut met = Vec::new();
+        for (time, content) in [(10.0, "Met Ada"), (20.0, "Ate with Ada"), (30.0, "Argued with Ada")] {
+            let mut memory = memory_at(content, time, "Tavern", (0, 0), 1);
+            memory.related_entities.push(friend);
+            index.insert(&memory);
+            met.push(memory.id);
+        }
+
+        assert_eq!(index.encounters(&friend), met);
+        assert_eq!(index.last_encounter(&friend), Some(met[2]));
+        assert_eq!(index.between(15.0, 30.0), met[1..]);
+
+        index.remove(&met[2]);
+        assert_eq!(index.last_encounter(&friend), Some(met[1]));
+        assert_eq!(index.at_place("tavern", None), met[..2]);
+        index.retain(|_| false);
+        assert_eq!(index.last_encounter(&friend), None);
+        assert!(index.on_day(1).is_empty());
+    }
+}
\ No newline at end of file
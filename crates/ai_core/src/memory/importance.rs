@@ -0,0 +1,319 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use super::embedding::tokenize;
+
+/// Learned terms kept per NPC; past this the weakest are dropped
+const MAX_LEARNED_TERMS: usize = 512;
+/// Terms listed in an explanation
+const EXPLAINED_TERMS: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportanceScoring {
+    keyword_weights: HashMap<String, f32>,
+    emotional_weight: f32,
+    novelty_weight: f32,
+    context_weight: f32,
+    base_importance: f32,
+    /// Per-term weights in -1..1 learned from what the NPC's memories turned
+    /// out to be good for
+    #[serde(default)]
+    learned_weights: HashMap<String, f32>,
+    #[serde(default = "default_learned_weight")]
+    learned_weight: f32,
+    #[serde(default = "default_learning_rate")]
+    learning_rate: f32,
+}
+
+/// What became of a memory, as feedback for importance scoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemoryOutcome {
+    Recalled,
+    DroveDecision,
+    GoalRelated,
+    /// Faded away without ever being recalled
+    NeverRecalled,
+}
+
+/// Where a memory's importance came from.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportanceBreakdown {
+    pub base: f32,
+    pub keywords: f32,
+    pub emotional: f32,
+    pub learned: f32,
+    pub context: f32,
+    pub novelty: f32,
+    /// Sum of the components, clamped to 0..1
+    pub total: f32,
+    pub matched_keywords: Vec<String>,
+    /// Learned terms that moved the score most, with their weights
+    pub learned_terms: Vec<(String, f32)>,
+}
+
+impl Default for ImportanceScoring {
+    fn default() -> Self {
+        let mut keyword_weights = HashMap::new();
+        
+        // Initialize with some default important keywords
+        keyword_weights.insert("death".to_string(), 0.9);
+        keyword_weights.insert("danger".to_string(), 0.8);
+        keyword_weights.insert("important".to_string(), 0.7);
+        keyword_weights.insert("secret".to_string(), 0.7);
+        keyword_weights.insert("remember".to_string(), 0.6);
+        keyword_weights.insert("never".to_string(), 0.6);
+        keyword_weights.insert("always".to_string(), 0.5);
+        keyword_weights.insert("promise".to_string(), 0.5);
+
+        Self {
+            keyword_weights,
+            emotional_weight: 0.3,
+            novelty_weight: 0.2,
+            context_weight: 0.2,
+            base_importance: 0.1,
+            learned_weights: HashMap::new(),
+            learned_weight: default_learned_weight(),
+            learning_rate: default_learning_rate(),
+        }
+    }
+}
+
+impl MemoryOutcome {
+    /// Where the weights of the memory's terms are pulled toward.
+    pub fn reward(&self) -> f32 {
+        match self {
+            MemoryOutcome::Recalled => 0.4,
+            MemoryOutcome::DroveDecision => 0.7,
+            MemoryOutcome::GoalRelated => 1.0,
+            MemoryOutcome::NeverRecalled => -0.6,
+        }
+    }
+}
+
+impl ImportanceBreakdown {
+    /// One-line account, e.g. "0.62 = base 0.10 + keywords 0.27 (danger) + ..."
+    pub fn describe(&self) -> String {
+        let mut parts = vec![format!("base {:.2}", self.base)];
+        if self.keywords != 0.0 {
+            parts.push(format!("keywords {:.2} ({})", self.keywords, self.matched_keywords.join(", ")));
+        }
+        if self.emotional != 0.0 {
+            parts.push(format!("emotion {:.2}", self.emotional));
+        }
+        if self.learned != 0.0 {
+            let terms: Vec<String> = self.learned_terms
+                .iter()
+                .map(|(term, weight)| format!("{} {:+.2}", term, weight))
+                .collect();
+            parts.push(format!("learned {:+.2} ({})", self.learned, terms.join(", ")));
+        }
+        if self.context != 0.0 {
+            parts.push(format!("context {:.2}", self.context));
+        }
+        if self.novelty != 0.0 {
+            parts.push(format!("novelty {:.2}", self.novelty));
+        }
+        format!("{:.2} = {}", self.total, parts.join(" + "))
+    }
+}
+
+impl ImportanceScoring {
+    pub fn calculate_importance(&self, content: &str, emotional_value: f32) -> f32 {
+        self.explain(content, emotional_value).total
+    }
+
+    pub fn calculate_with_context(&self, content: &str, emotional_value: f32, context_relevance: f32, novelty: f32) -> f32 {
+        self.explain_with_context(content, emotional_value, context_relevance, novelty).total
+    }
+
+    /// Scores a memory and says why.
+    pub fn explain(&self, content: &str, emotional_value: f32) -> ImportanceBreakdown {
+        let mut breakdown = ImportanceBreakdown {
+            base: self.base_importance,
+            ..ImportanceBreakdown::default()
+        };
+
+        // Add keyword-based importance
+        let (keywords, matched_keywords) = self.calculate_keyword_importance(content);
+        breakdown.keywords = keywords;
+        breakdown.matched_keywords = matched_keywords;
+
+        // Add emotional importance
+        breakdown.emotional = emotional_value.abs() * self.emotional_weight;
+
+        // Add what this NPC has learned matters
+        let (learned, learned_terms) = self.calculate_learned_importance(content);
+        breakdown.learned = learned;
+        breakdown.learned_terms = learned_terms;
+
+        breakdown.total = total(&breakdown);
+        breakdown
+    }
+
+    pub fn explain_with_context(&self, content: &str, emotional_value: f32, context_relevance: f32, novelty: f32) -> ImportanceBreakdown {
+        let mut breakdown = self.explain(content, emotional_value);
+        breakdown.context = context_relevance * self.context_weight;
+        breakdown.novelty = novelty * self.novelty_weight;
+        breakdown.total = total(&breakdown);
+        breakdown
+    }
+
+    /// Pulls the weights of the memory's terms toward the outcome's reward,
+    /// so similar memories score higher (or lower) from now on.
+    pub fn learn(&mut self, content: &str, outcome: MemoryOutcome) {
+        let reward = outcome.reward();
+        let mut terms = tokenize(content);
+        terms.sort();
+        terms.dedup();
+
+        for term in terms {
+            let weight = self.learned_weights.entry(term).or_insert(0.0);
+            *weight = (*weight + self.learning_rate * (reward - *weight)).clamp(-1.0, 1.0);
+        }
+
+        // Forget the terms that have learned the least
+        if self.learned_weights.len() > MAX_LEARNED_TERMS {
+            let mut weights: Vec<(String, f32)> = self.learned_weights.drain().collect();
+            weights.sort_by(|a, b| b.1.abs().partial_cmp(&a.1.abs()).unwrap_or(std::cmp::Ordering::Equal));
+            weights.truncate(MAX_LEARNED_TERMS);
+            self.learned_weights = weights.into_iter().collect();
+        }
+    }
+
+    pub fn learned_weights(&self) -> &HashMap<String, f32> {
+        &self.learned_weights
+    }
+
+    pub fn set_learned_weights(&mut self, weights: HashMap<String, f32>) {
+        self.learned_weights = weights
+            .into_iter()
+            .map(|(term, weight)| (term, weight.clamp(-1.0, 1.0)))
+            .collect();
+    }
+
+    fn calculate_keyword_importance(&self, content: &str) -> (f32, Vec<String>) {
+        let content = content.to_lowercase();
+        let mut importance = 0.0;
+        let mut matched = Vec::new();
+
+        for (keyword, weight) in &self.keyword_weights {
+            if content.contains(&keyword.to_lowercase()) {
+                importance += weight;
+                matched.push(keyword.clone());
+            }
+        }
+
+        if matched.is_empty() {
+            return (0.0, matched);
+        }
+        matched.sort();
+        (importance / matched.len() as f32, matched)
+    }
+
+    fn calculate_learned_importance(&self, content: &str) -> (f32, Vec<(String, f32)>) {
+        let mut terms = tokenize(content);
+        terms.sort();
+        terms.dedup();
+
+        let mut known: Vec<(String, f32)> = terms
+            .into_iter()
+            .filter_map(|term| {
+                let weight = *self.learned_weights.get(&term)?;
+                Some((term, weight))
+            })
+            .collect();
+        if known.is_empty() {
+            return (0.0, known);
+        }
+
+        let mean = known.iter().map(|(_, weight)| weight).sum::<f32>() / known.len() as f32;
+        known.sort_by(|a, b| b.1.abs().partial_cmp(&a.1.abs()).unwrap_or(std::cmp::Ordering::Equal));
+        known.truncate(EXPLAINED_TERMS);
+        (mean * self.learned_weight, known)
+    }
+
+    pub fn add_keyword(&mut self, keyword: String, weight: f32) {
+        self.keyword_weights.insert(keyword, weight.clamp(0.0, 1.0));
+    }
+
+    pub fn remove_keyword(&mut self, keyword: &str) {
+        self.keyword_weights.remove(keyword);
+    }
+
+    pub fn adjust_weights(&mut self, 
+        emotional: Option<f32>,
+        novelty: Option<f32>,
+        context: Option<f32>,
+        base: Option<f32>
+    ) {
+        if let Some(emotional) = emotional {
+            self.emotional_weight = emotional.clamp(0.0, 1.0);
+        }
+        if let Some(novelty) = novelty {
+            self.novelty_weight = novelty.clamp(0.0, 1.0);
+        }
+        if let Some(context) = context {
+            self.context_weight = context.clamp(0.0, 1.0);
+        }
+        if let Some(base) = base {
+            self.base_importance = base.clamp(0.0, 1.0);
+        }
+    }
+
+    pub fn analyze_emotional_patterns(&self, content: &str) -> EmotionalPattern {
+        EmotionalPattern {
+            positive_keywords: self.count_emotional_keywords(content, true),
+            negative_keywords: self.count_emotional_keywords(content, false),
+            intensity: self.calculate_emotional_intensity(content),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalPattern {
+    positive_keywords: usize,
+    negative_keywords: usize,
+    intensity: f32,
+}
+
+impl ImportanceScoring {
+    fn count_emotional_keywords(&self, content: &str, positive: bool) -> usize {
+        let content = content.to_lowercase();
+        let keywords = if positive {
+            vec!["happy", "joy", "love", "excited", "wonderful"]
+        } else {
+            vec!["sad", "angry", "fear", "hate", "terrible"]
+        };
+
+        keywords.iter().filter(|&k| content.contains(k)).count()
+    }
+
+    fn calculate_emotional_intensity(&self, content: &str) -> f32 {
+        let intensity_keywords = ["very", "extremely", "absolutely", "totally"];
+        let content = content.to_lowercase();
+        
+        let matches = intensity_keywords.iter()
+            .filter(|&k| content.contains(k))
+            .count();
+
+        (matches as f32 * 0.25).clamp(0.0, 1.0)
+    }
+}
+
+fn total(breakdown: &ImportanceBreakdown) -> f32 {
+    let sum = breakdown.base
+        + breakdown.keywords
+        + breakdown.emotional
+        + breakdown.learned
+        + breakdown.context
+        + breakdown.novelty;
+    sum.clamp(0.0, 1.0)
+}
+
+fn default_learned_weight() -> f32 {
+    0.4
+}
+
+fn default_learning_rate() -> f32 {
+    0.1
+}
\ No newline at end of file
@@ -0,0 +1,276 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Distribution, StandardNormal};
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+use uuid::Uuid;
+
+use super::embedding::Embedding;
+
+/// Below this many vectors a full scan is both exact and fast enough.
+const EXACT_SEARCH_LIMIT: usize = 128;
+const DEFAULT_BITS: usize = 10;
+const DEFAULT_TABLES: usize = 4;
+const DEFAULT_SEED: u64 = 0x5eed_1dea;
+/// Candidates looked at first when only some ids will do
+const FIRST_PROBE: usize = 8;
+
+/// Approximate nearest-neighbour index over memory embeddings using random
+/// hyperplane hashing. Only the vectors are saved; the hash tables are
+/// rebuilt from them on first use after loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorIndex {
+    dimensions: usize,
+    bits: usize,
+    tables: usize,
+    seed: u64,
+    vectors: HashMap<Uuid, Embedding>,
+    #[serde(skip)]
+    hashes: OnceLock<HashTables>,
+}
+
+/// The random hyperplanes and the buckets they sort vectors into.
+#[derive(Debug, Clone)]
+struct HashTables {
+    bits: usize,
+    planes: Vec<Vec<f32>>,
+    buckets: Vec<HashMap<u64, Vec<Uuid>>>,
+}
+
+impl VectorIndex {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            bits: DEFAULT_BITS,
+            tables: DEFAULT_TABLES,
+            seed: DEFAULT_SEED,
+            vectors: HashMap::new(),
+            hashes: OnceLock::new(),
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.vectors.contains_key(id)
+    }
+
+    /// Files `embedding` under `id`, replacing what was there. A zero or
+    /// wrongly sized embedding only removes the old vector.
+    pub fn insert(&mut self, id: Uuid, embedding: Embedding) {
+        self.remove(&id);
+        if embedding.dimensions() != self.dimensions || embedding.is_zero() {
+            return;
+        }
+
+        self.hash_tables();
+        if let Some(hashes) = self.hashes.get_mut() {
+            hashes.add(id, &embedding);
+        }
+        self.vectors.insert(id, embedding);
+    }
+
+    pub fn remove(&mut self, id: &Uuid) {
+        let Some(embedding) = self.vectors.remove(id) else {
+            return;
+        };
+        if let Some(hashes) = self.hashes.get_mut() {
+            hashes.remove(id, &embedding);
+        }
+    }
+
+    /// Drops every vector whose id fails the predicate, e.g. memories that
+    /// were forgotten.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Uuid) -> bool) {
+        let forgotten: Vec<Uuid> = self.vectors.keys().filter(|id| !keep(id)).copied().collect();
+        for id in forgotten {
+            self.remove(&id);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.vectors.clear();
+        self.hashes = OnceLock::new();
+    }
+
+    /// Up to `count` ids most similar to `query`, best first. Probes each
+    /// table's bucket and its one-bit neighbours, falling back to a full scan
+    /// when that turns up too few candidates.
+    pub fn search(&self, query: &Embedding, count: usize) -> Vec<(Uuid, f32)> {
+        if count == 0 || query.dimensions() != self.dimensions || query.is_zero() {
+            return Vec::new();
+        }
+        if self.vectors.len() <= EXACT_SEARCH_LIMIT {
+            return self.rank(self.vectors.keys(), query, count);
+        }
+
+        let candidates = self.hash_tables().candidates(query);
+        if candidates.len() < count {
+            return self.rank(self.vectors.keys(), query, count);
+        }
+        self.rank(candidates.iter(), query, count)
+    }
+
+    /// The id most similar to `query` out of those `accept` takes, e.g. the
+    /// ones in one memory store, if any is similar at all.
+    pub fn nearest(&self, query: &Embedding, accept: impl Fn(&Uuid) -> bool) -> Option<(Uuid, f32)> {
+        let mut count = FIRST_PROBE;
+        loop {
+            let found = self.search(query, count);
+            let exhausted = found.len() < count || count >= self.vectors.len();
+            if let Some(best) = found.into_iter().find(|(id, similarity)| accept(id) && *similarity > 0.0) {
+                return Some(best);
+            }
+            if exhausted {
+                return None;
+            }
+            count *= 2;
+        }
+    }
+
+    fn rank<'a>(&self, ids: impl Iterator<Item = &'a Uuid>, query: &Embedding, count: usize) -> Vec<(Uuid, f32)> {
+        let mut scored: Vec<(Uuid, f32)> = ids
+            .filter_map(|id| self.vectors.get(id).map(|v| (*id, v.similarity(query))))
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(count);
+        scored
+    }
+
+    /// The hash tables, built from the saved vectors the first time they're
+    /// needed.
+    fn hash_tables(&self) -> &HashTables {
+        self.hashes.get_or_init(|| {
+            // Same seed, same planes, so a reloaded index hashes identically
+            let mut rng = StdRng::seed_from_u64(self.seed);
+            let planes = (0..self.tables * self.bits)
+                .map(|_| (0..self.dimensions).map(|_| StandardNormal.sample(&mut rng)).collect())
+                .collect();
+
+            let mut hashes = HashTables {
+                bits: self.bits,
+                planes,
+                buckets: vec![HashMap::new(); self.tables],
+            };
+            for (id, embedding) in &self.vectors {
+                hashes.add(*id, embedding);
+            }
+            hashes
+        })
+    }
+}
+
+impl HashTables {
+    fn add(&mut self, id: Uuid, embedding: &Embedding) {
+        for table in 0..self.buckets.len() {
+            let key = self.hash(table, embedding);
+            self.buckets[table].entry(key).or_default().push(id);
+        }
+    }
+
+    fn remove(&mut self, id: &Uuid, embedding: &Embedding) {
+        for table in 0..self.buckets.len() {
+            let key = self.hash(table, embedding);
+            if let Some(bucket) = self.buckets[table].get_mut(&key) {
+                bucket.retain(|other| other != id);
+            }
+        }
+    }
+
+    fn candidates(&self, query: &Embedding) -> HashSet<Uuid> {
+        let mut candidates = HashSet::new();
+        for (table, buckets) in self.buckets.iter().enumerate() {
+            let key = self.hash(table, query);
+            let probes = std::iter::once(key).chain((0..self.bits).map(|bit| key ^ (1 << bit)));
+            for probe in probes {
+                if let Some(bucket) = buckets.get(&probe) {
+                    candidates.extend(bucket.iter().copied());
+                }
+            }
+        }
+        candidates
+    }
+
+    fn hash(&self, table: usize, embedding: &Embedding) -> u64 {
+        let planes = &self.planes[table * self.bits..(table + 1) * self.bits];
+        planes.iter().enumerate().fold(0, |key, (bit, plane)| {
+            let side: f32 = plane.iter().zip(embedding.values()).map(|(p, v)| p * v).sum();
+            if side >= 0.0 {
+                key | (1 << bit)
+            } else {
+                key
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Unit vectors spread around a circle in the first two dimensions
+    fn filled(count: usize) -> (VectorIndex, Vec<Uuid>) {
+        let mut index = VectorIndex::new(8);
+        let ids: Vec<Uuid> = (0..count).map(|_| Uuid::new_v4()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index.insert(*id, around(i as f32 / count as f32));
+        }
+        (index, ids)
+    }
+
+    fn around(turn: f32) -> Embedding {
+        let angle = turn * std::f32::consts::TAU;
+        let mut values = vec![0.0; 8];
+        values[0] = angle.cos();
+        values[1] = angle.sin();
+        Embedding::new(values)
+    }
+
+    #[test]
+    fn hash_tables_are_rebuilt_after_loading() {
+        let (index, ids) = filled(EXACT_SEARCH_LIMIT * 2);
+        let json = serde_json::to_string(&index).unwrap();
+        let loaded: VectorIndex = serde_json::from_str(&json).unwrap();
+        assert!(loaded.hashes.get().is_none());
+
+        let query = around(10.0 / ids.len() as f32);
+        let found = loaded.search(&query, 3);
+        assert!(loaded.hashes.get().is_some());
+        assert_eq!(found.first().map(|(id, _)| *id), Some(ids[10]));
+        assert_eq!(found, index.search(&query, 3));
+    }
+
+    #[test]
+    fn a_zero_embedding_drops_the_old_vector() {
+        let (mut index, ids) = filled(4);
+        index.insert(ids[0], Embedding::new(vec![0.0; 8]));
+
+        assert!(!index.contains(&ids[0]));
+        assert_eq!(index.len(), 3);
+        assert!(index.search(&around(0.0), 4).iter().all(|(id, _)| *id != ids[0]));
+    }
+
+    #[test]
+    fn nearest_looks_past_ids_it_must_skip() {
+        let (index, ids) = filled(EXACT_SEARCH_LIMIT * 2);
+        let wanted = ids[ids.len() / 2];
+
+        let found = index.nearest(&around(0.0), |id| *id == wanted);
+        assert_eq!(found.map(|(id, _)| id), None, "opposite vectors aren't similar");
+
+        let found = index.nearest(&around(0.45), |id| *id == wanted);
+        assert_eq!(found.map(|(id, _)| id), Some(wanted));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,243 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+use super::Memory;
+use super::embedding::Embedding;
+use super::index::VectorIndex;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default)]
+pub struct LongTermMemory {
+    memories: HashMap<Uuid, Memory>,
+    connections: HashMap<Uuid, Vec<Uuid>>,
+    emotional_index: HashMap<String, Vec<Uuid>>,
+    /// Memories live in a store and haven't been loaded back yet
+    #[serde(default)]
+    evicted: bool,
+    /// Added or modified since the last `take_changes`
+    #[serde(skip)]
+    changed: HashSet<Uuid>,
+    #[serde(skip)]
+    forgotten: HashSet<Uuid>,
+}
+
+/// A long-term memory as it's persisted: the memory and what it links to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredMemory {
+    pub memory: Memory,
+    pub links: Vec<Uuid>,
+}
+
+/// What changed in long-term memory since it was last written out.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryChanges {
+    pub saved: Vec<StoredMemory>,
+    pub forgotten: Vec<Uuid>,
+    /// The NPC's learned importance weights, if they moved since last time
+    pub learned_weights: Option<HashMap<String, f32>>,
+}
+
+
+impl MemoryChanges {
+    pub fn is_empty(&self) -> bool {
+        self.saved.is_empty() && self.forgotten.is_empty() && self.learned_weights.is_none()
+    }
+}
+
+impl LongTermMemory {
+    pub fn add_memory(&mut self, memory: Memory) {
+        self.changed.insert(memory.id);
+        self.forgotten.remove(&memory.id);
+
+        // Index by emotion
+        let emotion = categorize_emotion(memory.emotional_value);
+        self.emotional_index
+            .entry(emotion)
+            .or_default()
+            .push(memory.id);
+
+        // Create connections with related memories
+        for related_id in &memory.related_entities {
+            if self.memories.contains_key(related_id) {
+                // Create bidirectional connection
+                self.connections
+                    .entry(memory.id)
+                    .or_default()
+                    .push(*related_id);
+                self.connections
+                    .entry(*related_id)
+                    .or_default()
+                    .push(memory.id);
+            }
+        }
+
+        // Store the memory
+        self.memories.insert(memory.id, memory);
+    }
+
+    /// Most similar long-term memory to the embedded `query`, if any is
+    /// similar at all, looked up in the `index` memories are filed in.
+    pub fn find_memory(&self, query: &Embedding, index: &VectorIndex) -> Option<Memory> {
+        let (id, _) = index.nearest(query, |id| self.memories.contains_key(id))?;
+        self.memories.get(&id).cloned()
+    }
+
+    pub fn get(&self, id: &Uuid) -> Option<&Memory> {
+        self.memories.get(id)
+    }
+
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.memories.contains_key(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Memory> {
+        self.memories.values()
+    }
+
+    /// Removes every memory matching `forget`, along with its connections,
+    /// and hands the removed memories back.
+    pub fn forget_where(&mut self, mut forget: impl FnMut(&Memory) -> bool) -> Vec<Memory> {
+        let forgotten: Vec<Uuid> = self.memories.values().filter(|m| forget(m)).map(|m| m.id).collect();
+        if forgotten.is_empty() {
+            return Vec::new();
+        }
+
+        let mut removed = Vec::with_capacity(forgotten.len());
+        for id in &forgotten {
+            removed.extend(self.memories.remove(id));
+            self.connections.remove(id);
+            self.changed.remove(id);
+            self.forgotten.insert(*id);
+        }
+        for connections in self.connections.values_mut() {
+            connections.retain(|id| !forgotten.contains(id));
+        }
+        for memories in self.emotional_index.values_mut() {
+            memories.retain(|id| !forgotten.contains(id));
+        }
+
+        removed
+    }
+
+    pub fn get_mut(&mut self, id: &Uuid) -> Option<&mut Memory> {
+        let memory = self.memories.get_mut(id)?;
+        self.changed.insert(*id);
+        Some(memory)
+    }
+
+    pub fn links(&self, id: &Uuid) -> Vec<Uuid> {
+        self.connections.get(id).cloned().unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.memories.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memories.is_empty()
+    }
+
+    /// Drains everything added, modified or forgotten since the last call.
+    pub fn take_changes(&mut self) -> MemoryChanges {
+        let saved = std::mem::take(&mut self.changed)
+            .into_iter()
+            .filter_map(|id| {
+                let memory = self.memories.get(&id)?.clone();
+                Some(StoredMemory { memory, links: self.links(&id) })
+            })
+            .collect();
+
+        MemoryChanges {
+            saved,
+            forgotten: std::mem::take(&mut self.forgotten).into_iter().collect(),
+            learned_weights: None,
+        }
+    }
+
+    /// Drops every memory from RAM. Take and persist the changes first, or
+    /// they're lost.
+    pub fn evict(&mut self) -> Vec<Uuid> {
+        let evicted: Vec<Uuid> = self.memories.keys().copied().collect();
+        self.memories.clear();
+        self.connections.clear();
+        self.emotional_index.clear();
+        self.changed.clear();
+        self.evicted = true;
+        evicted
+    }
+
+    /// Puts memories loaded from a store back. Memories formed while evicted
+    /// are newer and win over their stored copies.
+    pub fn restore(&mut self, stored: Vec<StoredMemory>) -> Vec<Uuid> {
+        let mut restored = Vec::new();
+        for StoredMemory { memory, links } in stored {
+            if self.memories.contains_key(&memory.id) || self.forgotten.contains(&memory.id) {
+                continue;
+            }
+
+            let emotion = categorize_emotion(memory.emotional_value);
+            self.emotional_index.entry(emotion).or_default().push(memory.id);
+            if !links.is_empty() {
+                self.connections.insert(memory.id, links);
+            }
+            restored.push(memory.id);
+            self.memories.insert(memory.id, memory);
+        }
+        self.evicted = false;
+        restored
+    }
+
+    pub fn is_evicted(&self) -> bool {
+        self.evicted
+    }
+
+    pub fn get_connected_memories(&self, memory_id: Uuid) -> Vec<Memory> {
+        self.connections
+            .get(&memory_id)
+            .map(|connections| {
+                connections
+                    .iter()
+                    .filter_map(|id| self.memories.get(id))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_emotional_memories(&self, emotion: &str) -> Vec<Memory> {
+        self.emotional_index
+            .get(emotion)
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| self.memories.get(id))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn strengthen_connection(&mut self, memory_id1: Uuid, memory_id2: Uuid) {
+        if self.memories.contains_key(&memory_id1) && self.memories.contains_key(&memory_id2) {
+            self.changed.insert(memory_id1);
+            self.changed.insert(memory_id2);
+            self.connections
+                .entry(memory_id1)
+                .or_default()
+                .push(memory_id2);
+            self.connections
+                .entry(memory_id2)
+                .or_default()
+                .push(memory_id1);
+        }
+    }
+}
+
+fn categorize_emotion(emotional_value: f32) -> String {
+    match emotional_value {
+        x if x > 0.7 => "very_positive",
+        x if x > 0.3 => "positive",
+        x if x > -0.3 => "neutral",
+        x if x > -0.7 => "negative",
+        _ => "very_negative",
+    }.to_string()
+}
\ No newline at end of file
@@ -0,0 +1,861 @@
+use crate::time::DayCycle;
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+pub mod short_term;
+pub mod long_term;
+pub mod decay;
+pub mod importance;
+pub mod embedding;
+pub mod index;
+pub mod retrieval;
+pub mod consolidation;
+pub mod episodic;
+pub mod reconstruction;
+#[cfg(feature = "persistence")]
+pub mod store;
+
+use short_term::ShortTermMemory;
+use long_term::{LongTermMemory, MemoryChanges, StoredMemory};
+use decay::{DecayCurve, MemoryDecay, MemoryTrace};
+use importance::{ImportanceBreakdown, ImportanceScoring, MemoryOutcome};
+use embedding::SharedEmbedder;
+use index::VectorIndex;
+use retrieval::{Recollection, RetrievalWeights, ScoredMemory};
+use consolidation::{ConsolidationReport, ConsolidationSettings};
+use episodic::{EpisodeContext, EpisodicIndex, MemoryLocation};
+use crate::consciousness::dreams::Dream;
+use reconstruction::{Contradiction, MemoryVersion, RecallConditions, ReconstructionSettings};
+
+/// Candidates pulled from the index per memory asked for, so recency and
+/// importance can reorder more than the top few by similarity.
+const RETRIEVAL_CANDIDATES: usize = 4;
+/// Memories waiting for the next night's consolidation; past this the least
+/// important are forgotten early.
+const MAX_UNCONSOLIDATED: usize = 256;
+/// Similarity a memory needs to an option before it sways a choice about it
+const SWAYING_SIMILARITY: f32 = 0.3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct MemorySystem {
+    short_term: ShortTermMemory,
+    long_term: LongTermMemory,
+    decay_system: MemoryDecay,
+    importance_scorer: ImportanceScoring,
+    index: VectorIndex,
+    retrieval: RetrievalWeights,
+    consolidation: ConsolidationSettings,
+    /// Everything experienced since the last sleep, still to be consolidated
+    unconsolidated: Vec<Memory>,
+    episodes: EpisodicIndex,
+    context: EpisodeContext,
+    reconstruction: ReconstructionSettings,
+    recall_conditions: RecallConditions,
+    /// Contradictions the NPC caught in its own memories, not yet acted on
+    noticed: Vec<Contradiction>,
+    /// Whether importance learning moved since the weights were last saved
+    #[serde(skip)]
+    weights_changed: bool,
+    #[serde(skip)]
+    embedder: SharedEmbedder,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Memory {
+    id: Uuid,
+    content: String,
+    importance: f32,
+    emotional_value: f32,
+    timestamp: f32,
+    related_entities: Vec<Uuid>,
+    /// Times the memory has been brought back up; strengthens spaced repetition
+    #[serde(default)]
+    recall_count: u32,
+    #[serde(default)]
+    last_recalled: Option<f32>,
+    #[serde(default)]
+    kind: MemoryKind,
+    #[serde(default)]
+    location: Option<MemoryLocation>,
+    #[serde(default)]
+    clock: Option<DayCycle>,
+    /// Earlier tellings, oldest first; `content` is the latest
+    #[serde(default)]
+    history: Vec<MemoryVersion>,
+    /// How `importance` was scored when the memory formed
+    #[serde(default)]
+    importance_breakdown: Option<ImportanceBreakdown>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MemoryKind {
+    /// Something the NPC saw, heard or did
+    #[default]
+    Observation,
+    /// A summary the NPC drew from several observations while sleeping
+    Reflection,
+    /// Something the NPC dreamt; remembered, but not to be trusted
+    Dream,
+}
+
+impl Default for MemorySystem {
+    fn default() -> Self {
+        let embedder = SharedEmbedder::default();
+        Self {
+            short_term: ShortTermMemory::default(),
+            long_term: LongTermMemory::default(),
+            decay_system: MemoryDecay::default(),
+            importance_scorer: ImportanceScoring::default(),
+            index: VectorIndex::new(embedder.dimensions()),
+            retrieval: RetrievalWeights::default(),
+            consolidation: ConsolidationSettings::default(),
+            unconsolidated: Vec::new(),
+            episodes: EpisodicIndex::default(),
+            context: EpisodeContext::default(),
+            reconstruction: ReconstructionSettings::default(),
+            recall_conditions: RecallConditions::default(),
+            noticed: Vec::new(),
+            weights_changed: false,
+            embedder,
+        }
+    }
+}
+
+impl Memory {
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn importance(&self) -> f32 {
+        self.importance
+    }
+
+    pub fn emotional_value(&self) -> f32 {
+        self.emotional_value
+    }
+
+    pub fn kind(&self) -> MemoryKind {
+        self.kind
+    }
+
+    /// How far the memory can be trusted as a record of what happened, 0..1.
+    pub fn reliability(&self) -> f32 {
+        match self.kind {
+            MemoryKind::Observation => 1.0,
+            MemoryKind::Reflection => 0.8,
+            MemoryKind::Dream => 0.2,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn timestamp(&self) -> f32 {
+        self.timestamp
+    }
+
+    pub fn related_entities(&self) -> &[Uuid] {
+        &self.related_entities
+    }
+
+    pub fn location(&self) -> Option<&MemoryLocation> {
+        self.location.as_ref()
+    }
+
+    pub fn clock(&self) -> Option<DayCycle> {
+        self.clock
+    }
+
+    pub fn recall_count(&self) -> u32 {
+        self.recall_count
+    }
+
+    pub fn history(&self) -> &[MemoryVersion] {
+        &self.history
+    }
+
+    /// The content as first experienced, before any retelling.
+    pub fn original_content(&self) -> &str {
+        self.history.first().map(|v| v.content.as_str()).unwrap_or(&self.content)
+    }
+
+    pub fn version(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn importance_breakdown(&self) -> Option<&ImportanceBreakdown> {
+        self.importance_breakdown.as_ref()
+    }
+
+    pub fn trace(&self) -> MemoryTrace {
+        MemoryTrace {
+            importance: self.importance,
+            emotional_value: self.emotional_value,
+            created: self.timestamp,
+            recall_count: self.recall_count,
+            last_recalled: self.last_recalled,
+        }
+    }
+}
+
+impl MemorySystem {
+    pub fn update(&mut self, delta_time: f32) {
+        // Update short-term memory
+        self.short_term.update(delta_time);
+        
+        // Process memory decay
+        let current_time = self.short_term.current_time();
+        let forgotten = self.decay_system.process(&mut self.short_term, &mut self.long_term, current_time);
+
+        // Whatever faded without ever coming up again mattered less than scored
+        for memory in forgotten.iter().filter(|m| m.recall_count == 0) {
+            self.importance_scorer.learn(&memory.content, MemoryOutcome::NeverRecalled);
+            self.weights_changed = true;
+        }
+
+        // Drop faded long-term memories from the indexes
+        for memory in &forgotten {
+            if !self.is_remembered(&memory.id) {
+                self.index.remove(&memory.id);
+                self.episodes.remove(&memory.id);
+            }
+        }
+    }
+
+    fn is_remembered(&self, id: &Uuid) -> bool {
+        self.short_term.contains(id) || self.long_term.contains(id) || self.unconsolidated.iter().any(|m| m.id == *id)
+    }
+
+    /// Where and when the NPC is now. Memories formed from here on are
+    /// stamped with it.
+    pub fn set_context(&mut self, location: Option<MemoryLocation>, clock: Option<DayCycle>) {
+        self.context = EpisodeContext { location, clock };
+    }
+
+    /// Sim seconds the memory system has lived through.
+    pub fn current_time(&self) -> f32 {
+        self.short_term.current_time()
+    }
+
+    pub fn context(&self) -> &EpisodeContext {
+        &self.context
+    }
+
+    pub fn add_memory(&mut self, content: String, emotional_value: f32, related_entities: Vec<Uuid>) {
+        let breakdown = self.importance_scorer.explain(&content, emotional_value);
+        let importance = breakdown.total;
+        
+        let id = Uuid::new_v4();
+        self.index.insert(id, self.embedder.embed(&content));
+
+        let memory = Memory {
+            id,
+            content,
+            importance,
+            emotional_value,
+            timestamp: self.short_term.current_time(),
+            related_entities,
+            recall_count: 0,
+            last_recalled: None,
+            kind: MemoryKind::Observation,
+            location: self.context.location.clone(),
+            clock: self.context.clock,
+            history: Vec::new(),
+            importance_breakdown: Some(breakdown),
+        };
+
+        self.episodes.insert(&memory);
+
+        self.unconsolidated.push(memory.clone());
+        if self.unconsolidated.len() > MAX_UNCONSOLIDATED {
+            if let Some((trivia, _)) = self.unconsolidated
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1.importance.partial_cmp(&b.1.importance).unwrap_or(std::cmp::Ordering::Equal))
+            {
+                self.unconsolidated.remove(trivia);
+            }
+        }
+
+        self.short_term.add_memory(memory);
+    }
+
+    /// Sleep-time pass over everything experienced since the last sleep:
+    /// duplicates are merged, related memories are summarized into linked
+    /// reflections, and trivia is let go instead of reaching long-term memory.
+    pub fn consolidate(&mut self) -> ConsolidationReport {
+        let memories = std::mem::take(&mut self.unconsolidated);
+        let current_time = self.short_term.current_time();
+        let consolidated = self.consolidation.consolidate(memories, &*self.embedder, current_time);
+
+        for (mut memory, embedding) in consolidated.memories {
+            if memory.kind == MemoryKind::Reflection {
+                memory.clock = self.context.clock;
+            }
+            self.index.insert(memory.id, embedding);
+            self.episodes.insert(&memory);
+            self.long_term.add_memory(memory);
+        }
+
+        for (reflection, source) in consolidated.links {
+            self.long_term.strengthen_connection(reflection, source);
+        }
+
+        // Merged and discarded memories, and whatever slipped out of
+        // short-term memory since the last sleep, leave the indexes
+        let (short_term, long_term) = (&self.short_term, &self.long_term);
+        let remembered = |id: &Uuid| short_term.contains(id) || long_term.contains(id);
+        self.index.retain(remembered);
+        self.episodes.retain(remembered);
+
+        consolidated.report
+    }
+
+    /// Files a dream straight into long-term memory.
+    pub fn add_dream(&mut self, dream: &Dream) -> Uuid {
+        let importance = (0.2 + dream.emotional_value.abs() * 0.3 + dream.unsettling * 0.3).min(1.0);
+        let memory = Memory {
+            id: Uuid::new_v4(),
+            content: dream.content.clone(),
+            importance,
+            emotional_value: dream.emotional_value,
+            timestamp: self.short_term.current_time(),
+            related_entities: dream.related_entities.clone(),
+            recall_count: 0,
+            last_recalled: None,
+            kind: MemoryKind::Dream,
+            location: self.context.location.clone(),
+            clock: self.context.clock,
+            history: Vec::new(),
+            importance_breakdown: None,
+        };
+
+        let id = memory.id;
+        self.index.insert(id, self.embedder.embed(&memory.content));
+        self.episodes.insert(&memory);
+        self.long_term.add_memory(memory);
+        for source in &dream.sources {
+            self.long_term.strengthen_connection(id, *source);
+        }
+        id
+    }
+
+    /// Dreams since `since` (sim time), oldest first, e.g. last night's.
+    pub fn dreams_since(&self, since: f32) -> Vec<Memory> {
+        self.collect(self.episodes.between(since, self.short_term.current_time()))
+            .into_iter()
+            .filter(|m| m.kind == MemoryKind::Dream)
+            .collect()
+    }
+
+    pub fn long_term_memories(&self) -> impl Iterator<Item = &Memory> {
+        self.long_term.iter()
+    }
+
+    /// Everything remembered right now, each memory once.
+    pub fn memories(&self) -> Vec<&Memory> {
+        let mut seen = HashSet::new();
+        self.long_term
+            .iter()
+            .chain(self.short_term.iter())
+            .chain(self.unconsolidated.iter())
+            .filter(|m| seen.insert(m.id))
+            .collect()
+    }
+
+    pub fn has_unconsolidated(&self) -> bool {
+        !self.unconsolidated.is_empty()
+    }
+
+    /// Retrieves like `retrieve`, and counts it as rehearsal: each recalled
+    /// memory is strengthened against forgetting. Recall is reconstructive:
+    /// long-term memories may come back altered, and stay that way.
+    pub fn recall(&mut self, query: &str, count: usize) -> Vec<ScoredMemory> {
+        let mut recalled = self.retrieve(query, count);
+        for scored in &mut recalled {
+            let id = scored.memory.id;
+            if let Some(memory) = self.reconstruct(&id) {
+                scored.memory = memory;
+            }
+            self.rehearse(&id);
+        }
+        recalled
+    }
+
+    /// Brings a long-term memory back to mind: it may drift, and the NPC may
+    /// catch it disagreeing with earlier tellings. Returns it as recalled.
+    fn reconstruct(&mut self, id: &Uuid) -> Option<Memory> {
+        let memory = self.long_term.get_mut(id)?;
+        let mut rng = rand::thread_rng();
+        let current_time = self.short_term.current_time();
+
+        if self.reconstruction.reconstruct(memory, &self.recall_conditions, current_time, &mut rng) {
+            self.index.insert(*id, self.embedder.embed(&memory.content));
+        }
+
+        let noticed = self.reconstruction.notice(memory, &self.recall_conditions, current_time, &mut rng);
+        for contradiction in noticed {
+            let known = self.noticed.iter().any(|c| {
+                c.memory == contradiction.memory
+                    && c.earlier == contradiction.earlier
+                    && c.current == contradiction.current
+            });
+            if !known {
+                self.noticed.push(contradiction);
+            }
+        }
+
+        Some(memory.clone())
+    }
+
+    /// Feeds the NPC's state of mind into recall; see `RecallConditions`.
+    pub fn set_recall_conditions(&mut self, conditions: RecallConditions) {
+        self.recall_conditions = conditions;
+    }
+
+    pub fn recall_conditions(&self) -> &RecallConditions {
+        &self.recall_conditions
+    }
+
+    /// Updates the consciousness half of the recall conditions.
+    pub fn set_reality_distortion(&mut self, distortion: f32, awareness: f32) {
+        self.recall_conditions.distortion = distortion.clamp(0.0, 1.0);
+        self.recall_conditions.awareness = awareness.clamp(0.0, 1.0);
+    }
+
+    /// Updates the emotional half of the recall conditions.
+    pub fn set_recall_mood(&mut self, mood: f32, arousal: f32) {
+        self.recall_conditions.mood = mood.clamp(-1.0, 1.0);
+        self.recall_conditions.arousal = arousal.clamp(0.0, 1.0);
+    }
+
+    /// Hands over the contradictions noticed since the last call.
+    pub fn take_noticed_contradictions(&mut self) -> Vec<Contradiction> {
+        std::mem::take(&mut self.noticed)
+    }
+
+    /// Every way a memory's current telling disagrees with earlier ones.
+    pub fn contradictions(&self, id: &Uuid) -> Vec<Contradiction> {
+        self.get_memory(id)
+            .map(|memory| reconstruction::contradictions(memory, self.short_term.current_time()))
+            .unwrap_or_default()
+    }
+
+    pub fn rehearse(&mut self, id: &Uuid) {
+        let current_time = self.short_term.current_time();
+        let memory = match self.long_term.get_mut(id) {
+            Some(memory) => Some(memory),
+            None => self.short_term.get_mut(id),
+        };
+        if let Some(memory) = memory {
+            memory.recall_count += 1;
+            memory.last_recalled = Some(current_time);
+        }
+        if let Some(memory) = self.unconsolidated.iter_mut().find(|m| m.id == *id) {
+            memory.recall_count += 1;
+            memory.last_recalled = Some(current_time);
+        }
+
+        self.record_outcome(id, MemoryOutcome::Recalled);
+    }
+
+    /// Memories of each option close enough to sway a choice between them,
+    /// for options that have any. Nothing is rehearsed until the choice is
+    /// made; see `record_decision`.
+    pub fn consult(&self, options: &[String], count: usize) -> Vec<Recollection> {
+        options
+            .iter()
+            .filter_map(|option| {
+                let memories: Vec<ScoredMemory> = self
+                    .retrieve(option, count)
+                    .into_iter()
+                    .filter(|scored| scored.similarity >= SWAYING_SIMILARITY)
+                    .collect();
+                if memories.is_empty() {
+                    return None;
+                }
+                let lean = memories.iter().map(|scored| scored.memory.emotional_value).sum::<f32>()
+                    / memories.len() as f32;
+                Some(Recollection { option: option.clone(), lean: lean.clamp(-1.0, 1.0), memories })
+            })
+            .collect()
+    }
+
+    /// Counts the memories consulted for a decision as recalled, the way
+    /// `recall` does, and as having driven it. Returns what they said as
+    /// recalled, each memory once.
+    pub fn record_decision(&mut self, recollections: &[Recollection]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut contents = Vec::new();
+        for scored in recollections.iter().flat_map(|recollection| &recollection.memories) {
+            let id = scored.memory.id;
+            if !seen.insert(id) {
+                continue;
+            }
+            let content = match self.reconstruct(&id) {
+                Some(memory) => memory.content,
+                None => scored.memory.content.clone(),
+            };
+            self.rehearse(&id);
+            self.record_outcome(&id, MemoryOutcome::DroveDecision);
+            contents.push(content);
+        }
+        contents
+    }
+
+    /// Feeds back what a memory turned out to be good for, so memories like
+    /// it are scored accordingly from now on.
+    pub fn record_outcome(&mut self, id: &Uuid, outcome: MemoryOutcome) {
+        if let Some(content) = self.get_memory(id).map(|m| m.content.clone()) {
+            self.importance_scorer.learn(&content, outcome);
+            self.weights_changed = true;
+        }
+    }
+
+    /// Memories that mention what the NPC is working toward matter more.
+    pub fn learn_from_goal(&mut self, description: &str) {
+        self.importance_scorer.learn(description, MemoryOutcome::GoalRelated);
+        self.weights_changed = true;
+    }
+
+    /// Why a memory got its importance: as scored when it formed, or as it
+    /// would be scored now for memories from before scoring was explained.
+    pub fn explain_importance(&self, id: &Uuid) -> Option<ImportanceBreakdown> {
+        let memory = self.get_memory(id)?;
+        Some(memory
+            .importance_breakdown
+            .clone()
+            .unwrap_or_else(|| self.importance_scorer.explain(&memory.content, memory.emotional_value)))
+    }
+
+    pub fn importance_scoring(&self) -> &ImportanceScoring {
+        &self.importance_scorer
+    }
+
+    /// How well a memory is still held, in 0..1, under this NPC's forgetting curve.
+    pub fn retention(&self, memory: &Memory) -> f32 {
+        self.decay_system.retention(&memory.trace(), self.short_term.current_time())
+    }
+
+    pub fn set_decay_curve(&mut self, curve: DecayCurve) {
+        self.decay_system.set_curve(curve);
+    }
+
+    /// Long-term memories added, changed or forgotten since the last call,
+    /// and the learned importance weights if they moved, for writing out to
+    /// a memory store.
+    pub fn take_long_term_changes(&mut self) -> MemoryChanges {
+        let mut changes = self.long_term.take_changes();
+        if std::mem::take(&mut self.weights_changed) {
+            changes.learned_weights = Some(self.importance_scorer.learned_weights().clone());
+        }
+        changes
+    }
+
+    /// Frees long-term memories from RAM; see `LongTermMemory::evict`.
+    pub fn evict_long_term(&mut self) {
+        for id in self.long_term.evict() {
+            self.index.remove(&id);
+            self.episodes.remove(&id);
+        }
+    }
+
+    pub fn restore_long_term(&mut self, stored: Vec<StoredMemory>) {
+        for id in self.long_term.restore(stored) {
+            if let Some(memory) = self.long_term.get(&id) {
+                self.index.insert(id, self.embedder.embed(&memory.content));
+                self.episodes.insert(memory);
+            }
+        }
+    }
+
+    /// Puts back saved importance weights. Whatever was learned since they
+    /// were saved wins.
+    pub fn restore_learned_weights(&mut self, mut stored: HashMap<String, f32>) {
+        stored.extend(self.importance_scorer.learned_weights().clone());
+        self.importance_scorer.set_learned_weights(stored);
+    }
+
+    pub fn is_long_term_loaded(&self) -> bool {
+        !self.long_term.is_evicted()
+    }
+
+    pub fn recall_memory(&self, query: &str) -> Option<Memory> {
+        self.retrieve(query, 1).into_iter().next().map(|scored| scored.memory)
+    }
+
+    /// Memories most worth recalling for `query`, best first, scored by
+    /// similarity, recency and importance.
+    pub fn retrieve(&self, query: &str, count: usize) -> Vec<ScoredMemory> {
+        let query = self.embedder.embed(query);
+        let current_time = self.short_term.current_time();
+
+        let mut scored: Vec<ScoredMemory> = self.index
+            .search(&query, count * RETRIEVAL_CANDIDATES)
+            .into_iter()
+            .filter_map(|(id, similarity)| {
+                let memory = self.get_memory(&id)?;
+                self.retrieval.score_memory(memory, similarity, current_time)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(count);
+        scored
+    }
+
+    /// Swaps the embedding model and re-embeds everything remembered so far.
+    /// Also needed after loading a save, since the embedder isn't serialized.
+    pub fn set_embedder(&mut self, embedder: SharedEmbedder) {
+        self.index = VectorIndex::new(embedder.dimensions());
+        let memories = self.short_term.iter().chain(self.long_term.iter()).chain(self.unconsolidated.iter());
+        for memory in memories {
+            self.index.insert(memory.id, embedder.embed(&memory.content));
+        }
+        self.embedder = embedder;
+    }
+
+    pub fn set_retrieval_weights(&mut self, weights: RetrievalWeights) {
+        self.retrieval = weights;
+    }
+
+    /// "What did I see at the Market yesterday": memories formed in a zone or
+    /// building, optionally on one day, oldest first.
+    pub fn memories_at(&self, place: &str, day: Option<u32>) -> Vec<Memory> {
+        self.collect(self.episodes.at_place(place, day))
+    }
+
+    /// "Everything that happened near the fountain": memories formed within
+    /// `radius` tiles, nearest first.
+    pub fn memories_near(&self, map: &str, tile: (i32, i32), radius: f32) -> Vec<Memory> {
+        let mut nearby: Vec<(Memory, f32)> = self.collect(self.episodes.near(map, tile, radius))
+            .into_iter()
+            .filter_map(|m| {
+                let distance = m.location.as_ref()?.distance_to(map, tile)?;
+                (distance <= radius).then_some((m, distance))
+            })
+            .collect();
+
+        nearby.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        nearby.into_iter().map(|(m, _)| m).collect()
+    }
+
+    pub fn memories_on_day(&self, day: u32) -> Vec<Memory> {
+        self.collect(self.episodes.on_day(day))
+    }
+
+    pub fn memories_between(&self, start: f32, end: f32) -> Vec<Memory> {
+        self.collect(self.episodes.between(start, end))
+    }
+
+    /// "When did I last meet James": the latest memory involving them.
+    pub fn last_encounter(&self, entity: Uuid) -> Option<Memory> {
+        self.episodes.last_encounter(&entity).and_then(|id| self.get_memory(&id)).cloned()
+    }
+
+    pub fn encounters_with(&self, entity: Uuid) -> Vec<Memory> {
+        self.collect(self.episodes.encounters(&entity))
+    }
+
+    /// Looks a memory up wherever it currently lives.
+    pub fn get_memory(&self, id: &Uuid) -> Option<&Memory> {
+        self.long_term
+            .get(id)
+            .or_else(|| self.short_term.get(id))
+            .or_else(|| self.unconsolidated.iter().find(|m| m.id == *id))
+    }
+
+    fn collect(&self, ids: Vec<Uuid>) -> Vec<Memory> {
+        let mut seen = HashSet::new();
+        let mut memories: Vec<Memory> = ids
+            .into_iter()
+            .filter(|id| seen.insert(*id))
+            .filter_map(|id| self.get_memory(&id).cloned())
+            .collect();
+        memories.sort_by(|a, b| a.timestamp.partial_cmp(&b.timestamp).unwrap_or(std::cmp::Ordering::Equal));
+        memories
+    }
+
+    /// The newest `count` memories, newest first, wherever they're held:
+    /// short-term memory only spans a few seconds.
+    pub fn get_recent_memories(&self, count: usize) -> Vec<Memory> {
+        let mut memories = self.memories();
+        memories.sort_by(|a, b| b.timestamp.total_cmp(&a.timestamp));
+        memories.into_iter().take(count).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+impl Memory {
+    /// A plain observation, for tests of the parts that work on memories
+    /// outside a `MemorySystem`.
+    pub(crate) fn observed(content: &str, emotional_value: f32, timestamp: f32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            content: content.to_string(),
+            importance: 0.5,
+            emotional_value,
+            timestamp,
+            related_entities: Vec::new(),
+            recall_count: 0,
+            last_recalled: None,
+            kind: MemoryKind::Observation,
+            location: None,
+            clock: None,
+            history: Vec::new(),
+            importance_breakdown: None,
+        }
+    }
+
+    pub(crate) fn involving(mut self, entity: Uuid) -> Self {
+        self.related_entities.push(entity);
+        self
+    }
+
+    pub(crate) fn at(mut self, location: MemoryLocation, clock: DayCycle) -> Self {
+        self.location = Some(location);
+        self.clock = Some(clock);
+        self
+    }
+}
+
+#[cfg(test)]
+impl MemorySystem {
+    /// Files a long-term memory that reads `current` but was once `earlier`,
+    /// as if it had drifted on an earlier recall.
+    pub(crate) fn remember_differently(&mut self, earlier: &str, current: &str) -> Uuid {
+        let mut memory = Memory::observed(current, 0.0, self.short_term.current_time());
+        memory.history.push(MemoryVersion {
+            content: earlier.to_string(),
+            emotional_value: 0.0,
+            replaced_at: memory.timestamp,
+            drift: 0.5,
+        });
+        let id = memory.id;
+        self.index.insert(id, self.embedder.embed(current));
+        self.episodes.insert(&memory);
+        self.long_term.add_memory(memory);
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::SECONDS_PER_DAY;
+
+    #[test]
+    fn recent_memories_outlast_short_term_memory() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("I opened the shop".to_string(), 0.2, Vec::new());
+        memory.update(SECONDS_PER_DAY / 24.0);
+        memory.add_memory("I closed the shop".to_string(), 0.2, Vec::new());
+
+        let recent: Vec<String> = memory.get_recent_memories(5).iter().map(|m| m.content().to_string()).collect();
+        assert_eq!(recent, ["I closed the shop", "I opened the shop"]);
+        assert_eq!(memory.get_recent_memories(1).len(), 1);
+    }
+
+    fn learned(memory: &MemorySystem, term: &str) -> f32 {
+        memory.importance_scoring().learned_weights().get(term).copied().unwrap_or(0.0)
+    }
+
+    #[test]
+    fn memories_that_drive_decisions_make_similar_ones_matter_more() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("The tavern stew was cheap".to_string(), 0.2, Vec::new());
+        let id = memory.get_recent_memories(1)[0].id();
+        let before = memory.importance_scoring().calculate_importance("Stew at the tavern again", 0.2);
+
+        memory.record_outcome(&id, MemoryOutcome::DroveDecision);
+
+        assert!(learned(&memory, "tavern") > 0.0);
+        assert!(memory.importance_scoring().calculate_importance("Stew at the tavern again", 0.2) > before);
+    }
+
+    #[test]
+    fn memories_of_an_option_sway_it_and_count_as_driving_the_choice() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("We ate bread at the bakery".to_string(), 0.6, Vec::new());
+        let options = vec!["eat".to_string(), "wash".to_string()];
+
+        let recollections = memory.consult(&options, 3);
+        assert_eq!(recollections.len(), 1);
+        assert_eq!(recollections[0].option, "eat");
+        assert!((recollections[0].lean - 0.6).abs() < 1e-4);
+
+        let recalled = memory.record_decision(&recollections);
+        assert_eq!(recalled, vec!["We ate bread at the bakery".to_string()]);
+        assert!(learned(&memory, "bakery") > 0.0);
+    }
+
+    #[test]
+    fn memories_recalled_for_a_decision_can_be_caught_changing() {
+        let mut memory = MemorySystem::default();
+        memory.reconstruction.base_drift = 0.0;
+        memory.set_reality_distortion(0.0, 1.0);
+        memory.remember_differently("A red cart left the market", "A blue cart left the market");
+
+        let recollections = memory.consult(&["A blue cart left the market".to_string()], 3);
+        assert_eq!(memory.record_decision(&recollections), vec!["A blue cart left the market".to_string()]);
+
+        let noticed = memory.take_noticed_contradictions();
+        assert_eq!(noticed.len(), 1);
+        assert_eq!((noticed[0].earlier.as_str(), noticed[0].current.as_str()), ("red", "blue"));
+    }
+
+    #[test]
+    fn decisions_memory_did_not_sway_leave_the_weights_alone() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("The miller owes me gold".to_string(), 0.1, Vec::new());
+        let before = memory.importance_scoring().learned_weights().clone();
+
+        let recollections = memory.consult(&["sleep".to_string(), "wash".to_string()], 3);
+        assert!(recollections.is_empty());
+        assert!(memory.record_decision(&recollections).is_empty());
+        assert_eq!(memory.importance_scoring().learned_weights(), &before);
+    }
+
+    #[test]
+    fn memories_stay_indexed_until_consolidation_lets_them_go() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("Stubbed a toe".to_string(), 0.0, Vec::new());
+        let id = memory.get_recent_memories(1)[0].id();
+
+        // Out of short-term memory but still waiting for the night
+        memory.update(60.0);
+        assert!(memory.index.contains(&id));
+
+        let report = memory.consolidate();
+        assert_eq!(report.discarded, 1);
+        assert!(!memory.index.contains(&id));
+        assert!(memory.episodes.between(0.0, 60.0).is_empty());
+    }
+
+    #[test]
+    fn recalling_a_memory_teaches_its_terms() {
+        let mut memory = MemorySystem::default();
+        memory.add_memory("The miller owes me gold".to_string(), 0.1, Vec::new());
+
+        assert!(!memory.recall("miller gold", 1).is_empty());
+        assert!(learned(&memory, "miller") > 0.0);
+    }
+
+    #[test]
+    fn learned_weights_go_out_with_the_changes_once() {
+        let mut memory = MemorySystem::default();
+        assert!(memory.take_long_term_changes().learned_weights.is_none());
+
+        memory.learn_from_goal("run shop");
+        let weights = memory.take_long_term_changes().learned_weights.expect("weights changed");
+        assert!(weights.contains_key("shop"));
+        assert!(memory.take_long_term_changes().learned_weights.is_none());
+
+        let mut restored = MemorySystem::default();
+        restored.restore_learned_weights(weights);
+        assert_eq!(learned(&restored, "shop"), learned(&memory, "shop"));
+    }
+}
\ No newline at end of file
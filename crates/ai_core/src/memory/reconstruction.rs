@@ -0,0 +1,380 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+
+use super::Memory;
+use crate::time::SECONDS_PER_DAY;
+
+/// Details that are easy to swap for a plausible neighbour when
+/// remembering: colours, times, sizes, places.
+const INTERCHANGEABLE: &[&[&str]] = &[
+    &["red", "blue", "green", "yellow", "black", "white", "brown", "grey"],
+    &["morning", "noon", "afternoon", "evening", "night"],
+    &["tiny", "small", "big", "large", "huge"],
+    &["few", "some", "several", "many"],
+    &["left", "right"],
+    &["north", "south", "east", "west"],
+    &["rain", "sun", "fog", "snow", "wind"],
+    &["sword", "axe", "spear", "bow", "knife"],
+    &["bread", "apples", "fish", "cheese", "ale"],
+    &["market", "tavern", "square", "well", "church", "bakery", "forge"],
+];
+const POSITIVE_MANNER: &[&str] = &["friendly", "kind", "cheerful", "polite", "calm"];
+const NEGATIVE_MANNER: &[&str] = &["rude", "angry", "nervous", "cold", "shifty"];
+/// Mood past this colours how people are remembered
+const MOOD_CONGRUENCE: f32 = 0.3;
+/// Share of the gap between a memory's feeling and the current mood closed
+/// per unit of drift
+const MOOD_PULL: f32 = 0.5;
+/// Awareness needed before an NPC can catch its own memory changing
+const NOTICE_AWARENESS: f32 = 0.5;
+
+/// What the NPC brings to the act of remembering.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecallConditions {
+    /// `RealityPerception` distortion, 0..1
+    pub distortion: f32,
+    /// Current emotional valence, -1..1
+    pub mood: f32,
+    /// Strength of the current emotion, 0..1
+    pub arousal: f32,
+    /// Consciousness awareness, 0..1
+    pub awareness: f32,
+}
+
+/// How strongly recall rewrites long-term memories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconstructionSettings {
+    /// Drift chance on every recall, however clear-headed
+    pub base_drift: f32,
+    pub distortion_weight: f32,
+    pub emotion_weight: f32,
+    pub time_weight: f32,
+    /// Seconds since the last version for the time term to reach half weight
+    pub time_half_life: f32,
+    pub max_drift: f32,
+    /// Versions kept per memory; the original is always kept
+    pub max_history: usize,
+}
+
+/// An earlier telling of a memory, replaced at `replaced_at`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryVersion {
+    pub content: String,
+    pub emotional_value: f32,
+    pub replaced_at: f32,
+    /// Drift chance of the recall that replaced it
+    pub drift: f32,
+}
+
+/// A detail that differs between an earlier version and the current one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contradiction {
+    pub memory: Uuid,
+    pub earlier: String,
+    pub current: String,
+    pub noticed_at: f32,
+}
+
+impl Default for ReconstructionSettings {
+    fn default() -> Self {
+        Self {
+            base_drift: 0.02,
+            distortion_weight: 0.5,
+            emotion_weight: 0.2,
+            time_weight: 0.3,
+            // One in-game week
+            time_half_life: SECONDS_PER_DAY * 7.0,
+            max_drift: 0.9,
+            max_history: 8,
+        }
+    }
+}
+
+impl ReconstructionSettings {
+    /// Chance, in 0..1, that recalling `memory` now changes it.
+    pub fn drift_chance(&self, memory: &Memory, conditions: &RecallConditions, current_time: f32) -> f32 {
+        let since = current_time - memory.last_recalled.unwrap_or(memory.timestamp);
+        let staleness = if self.time_half_life > 0.0 {
+            1.0 - 0.5_f32.powf(since.max(0.0) / self.time_half_life)
+        } else {
+            1.0
+        };
+
+        let chance = self.base_drift
+            + self.distortion_weight * conditions.distortion.clamp(0.0, 1.0)
+            + self.emotion_weight * conditions.arousal.clamp(0.0, 1.0)
+            + self.time_weight * staleness;
+        chance.clamp(0.0, self.max_drift)
+    }
+
+    /// Remembers `memory` the way it comes back this time. Returns true when
+    /// it changed, with the previous telling moved into its history.
+    pub fn reconstruct<R: Rng>(
+        &self,
+        memory: &mut Memory,
+        conditions: &RecallConditions,
+        current_time: f32,
+        rng: &mut R,
+    ) -> bool {
+        let drift = self.drift_chance(memory, conditions, current_time);
+        if rng.gen::<f32>() >= drift {
+            return false;
+        }
+
+        let previous = MemoryVersion {
+            content: memory.content.clone(),
+            emotional_value: memory.emotional_value,
+            replaced_at: current_time,
+            drift,
+        };
+
+        // The feeling drifts toward the mood it's remembered in
+        let mood = conditions.mood.clamp(-1.0, 1.0);
+        memory.emotional_value += (mood - memory.emotional_value) * drift * MOOD_PULL;
+
+        // Then a detail or two gets filled back in wrong
+        let edits = if drift > 0.5 { 2 } else { 1 };
+        let mut words: Vec<String> = memory.content.split_whitespace().map(str::to_string).collect();
+        for _ in 0..edits {
+            alter_detail(&mut words, mood, rng);
+        }
+        memory.content = words.join(" ");
+
+        let changed = memory.content != previous.content
+            || (memory.emotional_value - previous.emotional_value).abs() > f32::EPSILON;
+        if !changed {
+            return false;
+        }
+
+        memory.history.push(previous);
+        if memory.history.len() > self.max_history.max(1) {
+            // Keep the original; lose the oldest retelling
+            memory.history.remove(1);
+        }
+        true
+    }
+
+    /// Whether the NPC catches that its memory no longer matches an earlier
+    /// version. Only aware NPCs can; the more aware, the likelier.
+    pub fn notice<R: Rng>(
+        &self,
+        memory: &Memory,
+        conditions: &RecallConditions,
+        current_time: f32,
+        rng: &mut R,
+    ) -> Vec<Contradiction> {
+        if conditions.awareness < NOTICE_AWARENESS || rng.gen::<f32>() >= conditions.awareness {
+            return Vec::new();
+        }
+        contradictions(memory, current_time)
+    }
+}
+
+/// Every detail where an earlier version disagrees with the current one.
+pub fn contradictions(memory: &Memory, current_time: f32) -> Vec<Contradiction> {
+    let current: Vec<&str> = memory.content.split_whitespace().collect();
+    let mut found: Vec<Contradiction> = Vec::new();
+
+    for version in &memory.history {
+        let earlier: Vec<&str> = version.content.split_whitespace().collect();
+        let differences: Vec<(String, String)> = if earlier.len() == current.len() {
+            earlier
+                .iter()
+                .zip(&current)
+                .filter(|(a, b)| !a.eq_ignore_ascii_case(b))
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .collect()
+        } else {
+            vec![(version.content.clone(), memory.content.clone())]
+        };
+
+        for (earlier, current) in differences {
+            let duplicate = found.iter().any(|c| c.earlier == earlier && c.current == current);
+            if !duplicate {
+                found.push(Contradiction {
+                    memory: memory.id,
+                    earlier,
+                    current,
+                    noticed_at: current_time,
+                });
+            }
+        }
+    }
+
+    found
+}
+
+fn alter_detail<R: Rng>(words: &mut [String], mood: f32, rng: &mut R) {
+    let mut candidates: Vec<(usize, String)> = Vec::new();
+    for (index, word) in words.iter().enumerate() {
+        let (_, core, _) = split_punctuation(word);
+        if let Some(replacement) = replacement_for(&core.to_lowercase(), mood, rng) {
+            candidates.push((index, replacement));
+        }
+    }
+
+    if let Some((index, replacement)) = candidates.choose(rng).cloned() {
+        let (prefix, core, suffix) = split_punctuation(&words[index]);
+        let replacement = match_case(core, &replacement);
+        words[index] = format!("{}{}{}", prefix, replacement, suffix);
+    }
+}
+
+fn replacement_for<R: Rng>(word: &str, mood: f32, rng: &mut R) -> Option<String> {
+    // Numbers are misremembered by a little
+    if let Ok(number) = word.parse::<i64>() {
+        let shift = *[-2, -1, 1, 2].choose(rng)?;
+        return Some((number + shift).max(0).to_string());
+    }
+
+    // How someone behaved is remembered the way the NPC feels now
+    let is_manner = POSITIVE_MANNER.contains(&word) || NEGATIVE_MANNER.contains(&word);
+    if is_manner {
+        let pool = if mood > MOOD_CONGRUENCE {
+            POSITIVE_MANNER
+        } else if mood < -MOOD_CONGRUENCE {
+            NEGATIVE_MANNER
+        } else if POSITIVE_MANNER.contains(&word) {
+            POSITIVE_MANNER
+        } else {
+            NEGATIVE_MANNER
+        };
+        return pick_other(pool, word, rng);
+    }
+
+    let group = INTERCHANGEABLE.iter().find(|group| group.contains(&word))?;
+    pick_other(group, word, rng)
+}
+
+fn pick_other<R: Rng>(pool: &[&str], word: &str, rng: &mut R) -> Option<String> {
+    let others: Vec<&&str> = pool.iter().filter(|w| **w != word).collect();
+    others.choose(rng).map(|w| w.to_string())
+}
+
+fn split_punctuation(word: &str) -> (&str, &str, &str) {
+    let start = word.find(|c: char| c.is_alphanumeric()).unwrap_or(word.len());
+    let end = word
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_alphanumeric())
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(start);
+    (&word[..start], &word[start..end], &word[end..])
+}
+
+fn match_case(original: &str, replacement: &str) -> String {
+    let capitalized = original.chars().next().map(char::is_uppercase).unwrap_or(false);
+    if !capitalized {
+        return replacement.to_string();
+    }
+    let mut chars = replacement.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SCENE: &str = "A red cart left the market at noon with 3 barrels";
+
+    /// How many of `trials` fresh recalls, each of an unchanged memory,
+    /// come back altered.
+    fn drifted(settings: &ReconstructionSettings, conditions: &RecallConditions, elapsed: f32, trials: u32) -> u32 {
+        let mut rng = StdRng::seed_from_u64(7);
+        (0..trials)
+            .filter(|_| {
+                let mut memory = Memory::observed(SCENE, 0.0, 0.0);
+                settings.reconstruct(&mut memory, conditions, elapsed, &mut rng)
+            })
+            .count() as u32
+    }
+
+    #[test]
+    fn a_clear_head_recalling_a_fresh_memory_never_changes_it() {
+        let settings = ReconstructionSettings { base_drift: 0.0, ..Default::default() };
+        let conditions = RecallConditions::default();
+        let mut memory = Memory::observed(SCENE, 0.4, 0.0);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            assert!(!settings.reconstruct(&mut memory, &conditions, 0.0, &mut rng));
+        }
+        assert_eq!(memory.content, SCENE);
+        assert_eq!(memory.emotional_value, 0.4);
+        assert!(memory.history.is_empty());
+    }
+
+    #[test]
+    fn distortion_emotion_and_time_each_add_drift() {
+        let settings = ReconstructionSettings::default();
+        let calm = RecallConditions::default();
+        let distorted = RecallConditions { distortion: 0.8, ..Default::default() };
+        let upset = RecallConditions { arousal: 0.9, ..Default::default() };
+        let memory = Memory::observed(SCENE, 0.0, 0.0);
+
+        let fresh = settings.drift_chance(&memory, &calm, 0.0);
+        assert!(settings.drift_chance(&memory, &distorted, 0.0) > fresh);
+        assert!(settings.drift_chance(&memory, &upset, 0.0) > fresh);
+        assert!(settings.drift_chance(&memory, &calm, settings.time_half_life) > fresh);
+
+        assert!(drifted(&settings, &distorted, 0.0, 200) > drifted(&settings, &calm, 0.0, 200));
+        assert!(drifted(&settings, &upset, 0.0, 200) > drifted(&settings, &calm, 0.0, 200));
+        assert!(drifted(&settings, &calm, settings.time_half_life * 4.0, 200) > drifted(&settings, &calm, 0.0, 200));
+    }
+
+    #[test]
+    fn each_drift_keeps_the_telling_it_replaced() {
+        let settings = ReconstructionSettings { base_drift: 1.0, max_history: 3, ..Default::default() };
+        let conditions = RecallConditions { mood: -0.8, ..Default::default() };
+        let mut memory = Memory::observed(SCENE, 0.5, 0.0);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let mut drifts = 0;
+        for recall in 1..=20 {
+            let before = memory.content.clone();
+            let depth = memory.history.len();
+            if settings.reconstruct(&mut memory, &conditions, recall as f32, &mut rng) {
+                drifts += 1;
+                let replaced = memory.history.last().unwrap();
+                assert_eq!(replaced.content, before);
+                assert_eq!(replaced.replaced_at, recall as f32);
+                assert_eq!(memory.history.len(), (depth + 1).min(3));
+            }
+        }
+        assert!(drifts > 3);
+        // The feeling slid toward the mood, and the original is never lost
+        assert!(memory.emotional_value < 0.5);
+        assert_eq!(memory.history[0].content, SCENE);
+        assert_eq!(memory.original_content(), SCENE);
+    }
+
+    #[test]
+    fn only_aware_npcs_notice_their_memories_changing() {
+        let settings = ReconstructionSettings::default();
+        let mut memory = Memory::observed("A red cart left the market", 0.0, 0.0);
+        memory.history.push(MemoryVersion {
+            content: memory.content.clone(),
+            emotional_value: 0.0,
+            replaced_at: 5.0,
+            drift: 0.5,
+        });
+        memory.content = "A blue cart left the market".to_string();
+        let mut rng = StdRng::seed_from_u64(9);
+
+        let aware = RecallConditions { awareness: 1.0, ..Default::default() };
+        let noticed = settings.notice(&memory, &aware, 10.0, &mut rng);
+        assert_eq!(noticed.len(), 1);
+        assert_eq!((noticed[0].earlier.as_str(), noticed[0].current.as_str()), ("red", "blue"));
+        assert_eq!(noticed[0].memory, memory.id);
+
+        let unaware = RecallConditions { awareness: 0.3, ..Default::default() };
+        assert!(settings.notice(&memory, &unaware, 10.0, &mut rng).is_empty());
+    }
+}
\ No newline at end of file
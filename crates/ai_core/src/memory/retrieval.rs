@@ -0,0 +1,89 @@
+use serde::{Serialize, Deserialize};
+
+use super::Memory;
+use crate::time::SECONDS_PER_DAY;
+
+/// How retrieval balances relevance against recency and importance, after
+/// the generative-agents formula: a weighted sum of three scores in 0..1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrievalWeights {
+    pub similarity: f32,
+    pub recency: f32,
+    pub importance: f32,
+    /// Seconds of simulation time for recency to halve
+    pub recency_half_life: f32,
+    /// Memories less similar than this are never recalled, however fresh
+    pub min_similarity: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScoredMemory {
+    pub memory: Memory,
+    pub score: f32,
+    pub similarity: f32,
+    pub recency: f32,
+    pub importance: f32,
+}
+
+/// What memory says about one option before a decision: the memories close
+/// enough to it to sway the choice, and which way they lean it.
+#[derive(Debug, Clone)]
+pub struct Recollection {
+    pub option: String,
+    /// Mean emotional value of the memories, -1..1
+    pub lean: f32,
+    pub memories: Vec<ScoredMemory>,
+}
+
+impl Default for RetrievalWeights {
+    fn default() -> Self {
+        Self {
+            similarity: 1.0,
+            recency: 1.0,
+            importance: 1.0,
+            // Ten in-game hours: this morning still counts, last week barely
+            recency_half_life: SECONDS_PER_DAY * 10.0 / 24.0,
+            min_similarity: 0.15,
+        }
+    }
+}
+
+impl RetrievalWeights {
+    pub fn recency(&self, age: f32) -> f32 {
+        if self.recency_half_life <= 0.0 {
+            return 1.0;
+        }
+        0.5_f32.powf(age.max(0.0) / self.recency_half_life)
+    }
+
+    /// Combined score in 0..1, or `None` when the memory isn't relevant enough.
+    pub fn score(&self, similarity: f32, age: f32, importance: f32) -> Option<f32> {
+        if similarity < self.min_similarity {
+            return None;
+        }
+
+        let total = self.similarity + self.recency + self.importance;
+        if total <= 0.0 {
+            return Some(0.0);
+        }
+
+        let score = self.similarity * similarity.clamp(0.0, 1.0)
+            + self.recency * self.recency(age)
+            + self.importance * importance.clamp(0.0, 1.0);
+        Some(score / total)
+    }
+
+    pub fn score_memory(&self, memory: &Memory, similarity: f32, current_time: f32) -> Option<ScoredMemory> {
+        let age = current_time - memory.timestamp;
+        // Dreams and other shaky memories come to mind less readily
+        let score = self.score(similarity, age, memory.importance)? * memory.reliability();
+
+        Some(ScoredMemory {
+            memory: memory.clone(),
+            score,
+            similarity,
+            recency: self.recency(age),
+            importance: memory.importance,
+        })
+    }
+}
\ No newline at end of file
@@ -1,6 +1,9 @@
 use serde::{Serialize, Deserialize};
 use std::collections::VecDeque;
+use uuid::Uuid;
 use super::Memory;
+use super::embedding::Embedding;
+use super::index::VectorIndex;
 
 const MAX_SHORT_TERM_MEMORIES: usize = 20;
 
@@ -43,11 +46,31 @@ impl ShortTermMemory {
         }
     }
 
-    pub fn find_memory(&self, query: &str) -> Option<Memory> {
-        self.memories
-            .iter()
-            .find(|m| m.content.contains(query))
-            .cloned()
+    /// Most similar short-term memory to the embedded `query`, if any is
+    /// similar at all, looked up in the `index` memories are filed in.
+    pub fn find_memory(&self, query: &Embedding, index: &VectorIndex) -> Option<Memory> {
+        let (id, _) = index.nearest(query, |id| self.contains(id))?;
+        self.get(&id).cloned()
+    }
+
+    pub fn get(&self, id: &Uuid) -> Option<&Memory> {
+        self.memories.iter().find(|m| m.id == *id)
+    }
+
+    pub fn get_mut(&mut self, id: &Uuid) -> Option<&mut Memory> {
+        self.memories.iter_mut().find(|m| m.id == *id)
+    }
+
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.memories.iter().any(|m| m.id == *id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Memory> {
+        self.memories.iter()
+    }
+
+    pub fn current_time(&self) -> f32 {
+        self.total_time
     }
 
     pub fn get_recent_memories(&self, count: usize) -> Vec<Memory> {
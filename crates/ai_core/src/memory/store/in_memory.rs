@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use super::{MemoryStore, StoreResult};
+use crate::memory::long_term::StoredMemory;
+
+/// Store that keeps everything in a map. For small towns and for running
+/// without a database; nothing survives a restart.
+#[derive(Debug, Default)]
+pub struct InMemoryStore {
+    npcs: Mutex<HashMap<Uuid, HashMap<Uuid, StoredMemory>>>,
+    weights: Mutex<HashMap<Uuid, HashMap<String, f32>>>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MemoryStore for InMemoryStore {
+    async fn migrate(&self) -> StoreResult<()> {
+        Ok(())
+    }
+
+    async fn load(&self, npc: Uuid) -> StoreResult<Vec<StoredMemory>> {
+        let npcs = self.npcs.lock().unwrap();
+        let mut memories: Vec<StoredMemory> = npcs
+            .get(&npc)
+            .map(|memories| memories.values().cloned().collect())
+            .unwrap_or_default();
+        memories.sort_by(|a, b| {
+            a.memory.timestamp.partial_cmp(&b.memory.timestamp).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(memories)
+    }
+
+    async fn save(&self, npc: Uuid, memories: &[StoredMemory]) -> StoreResult<()> {
+        let mut npcs = self.npcs.lock().unwrap();
+        let stored = npcs.entry(npc).or_default();
+        for memory in memories {
+            stored.insert(memory.memory.id, memory.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, npc: Uuid, ids: &[Uuid]) -> StoreResult<()> {
+        let mut npcs = self.npcs.lock().unwrap();
+        if let Some(stored) = npcs.get_mut(&npc) {
+            for id in ids {
+                stored.remove(id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn count(&self, npc: Uuid) -> StoreResult<usize> {
+        let npcs = self.npcs.lock().unwrap();
+        Ok(npcs.get(&npc).map(HashMap::len).unwrap_or(0))
+    }
+
+    async fn load_weights(&self, npc: Uuid) -> StoreResult<HashMap<String, f32>> {
+        let weights = self.weights.lock().unwrap();
+        Ok(weights.get(&npc).cloned().unwrap_or_default())
+    }
+
+    async fn save_weights(&self, npc: Uuid, weights: &HashMap<String, f32>) -> StoreResult<()> {
+        self.weights.lock().unwrap().insert(npc, weights.clone());
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,453 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub mod in_memory;
+pub mod sqlite;
+pub mod postgres;
+
+pub use in_memory::InMemoryStore;
+pub use sqlite::SqliteMemoryStore;
+pub use postgres::PostgresMemoryStore;
+
+use super::long_term::{MemoryChanges, StoredMemory};
+use super::{MemoryKind, MemorySystem};
+
+/// Pending writes per NPC before a flush is forced
+const DEFAULT_BATCH_SIZE: usize = 64;
+/// Seconds between flushes when writes trickle in
+const DEFAULT_FLUSH_INTERVAL: f32 = 5.0;
+
+/// Migrations shared by every SQL backend.
+pub(crate) static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Migration error: {0}")]
+    Migration(#[from] sqlx::migrate::MigrateError),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type StoreResult<T> = Result<T, StoreError>;
+
+/// Where long-term memories live when they're not in RAM.
+#[async_trait]
+pub trait MemoryStore: Send + Sync {
+    /// Brings the schema up to date. Safe to call on every start.
+    async fn migrate(&self) -> StoreResult<()>;
+
+    /// Every stored memory of one NPC, oldest first.
+    async fn load(&self, npc: Uuid) -> StoreResult<Vec<StoredMemory>>;
+
+    /// Inserts or replaces memories.
+    async fn save(&self, npc: Uuid, memories: &[StoredMemory]) -> StoreResult<()>;
+
+    async fn delete(&self, npc: Uuid, ids: &[Uuid]) -> StoreResult<()>;
+
+    async fn count(&self, npc: Uuid) -> StoreResult<usize>;
+
+    /// What the NPC has learned makes a memory important; empty until first saved.
+    async fn load_weights(&self, npc: Uuid) -> StoreResult<HashMap<String, f32>>;
+
+    /// Replaces the NPC's learned importance weights.
+    async fn save_weights(&self, npc: Uuid, weights: &HashMap<String, f32>) -> StoreResult<()>;
+}
+
+/// One `npc_memories` row, as every SQL backend writes it.
+pub(crate) struct MemoryRow {
+    pub id: String,
+    pub npc_id: String,
+    pub kind: &'static str,
+    pub content: String,
+    pub importance: f32,
+    pub emotional_value: f32,
+    pub created_at: f32,
+    pub links: String,
+    pub data: String,
+}
+
+impl MemoryRow {
+    pub fn new(npc: Uuid, stored: &StoredMemory) -> StoreResult<Self> {
+        let memory = &stored.memory;
+        Ok(Self {
+            id: memory.id.to_string(),
+            npc_id: npc.to_string(),
+            kind: match memory.kind {
+                MemoryKind::Observation => "observation",
+                MemoryKind::Reflection => "reflection",
+                MemoryKind::Dream => "dream",
+            },
+            content: memory.content.clone(),
+            importance: memory.importance,
+            emotional_value: memory.emotional_value,
+            created_at: memory.timestamp,
+            links: serde_json::to_string(&stored.links)?,
+            data: serde_json::to_string(memory)?,
+        })
+    }
+
+    pub fn decode(data: &str, links: &str) -> StoreResult<StoredMemory> {
+        Ok(StoredMemory {
+            memory: serde_json::from_str(data)?,
+            links: serde_json::from_str(links)?,
+        })
+    }
+}
+
+/// Keeps NPC memory systems in step with a store: long-term memories are
+/// loaded on first use, changes are batched and written behind the
+/// simulation, and idle NPCs can be evicted from RAM.
+///
+/// The simulation side is synchronous: call `track` after updating an NPC
+/// and `tick` every frame; `flush` when `tick` says so.
+pub struct MemoryPersistence<S: MemoryStore> {
+    store: Arc<S>,
+    pending: HashMap<Uuid, PendingWrites>,
+    batch_size: usize,
+    flush_interval: f32,
+    since_flush: f32,
+}
+
+#[derive(Default)]
+struct PendingWrites {
+    saved: HashMap<Uuid, StoredMemory>,
+    forgotten: HashSet<Uuid>,
+    /// Latest learned importance weights, if they moved
+    weights: Option<HashMap<String, f32>>,
+}
+
+impl<S: MemoryStore> MemoryPersistence<S> {
+    pub fn new(store: Arc<S>) -> Self {
+        Self {
+            store,
+            pending: HashMap::new(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+            since_flush: 0.0,
+        }
+    }
+
+    pub fn with_batching(mut self, batch_size: usize, flush_interval: f32) -> Self {
+        self.batch_size = batch_size.max(1);
+        self.flush_interval = flush_interval.max(0.0);
+        self
+    }
+
+    pub fn store(&self) -> &Arc<S> {
+        &self.store
+    }
+
+    /// Loads an NPC's long-term memories if they aren't in RAM yet. Returns
+    /// whether anything was loaded.
+    pub async fn ensure_loaded(&self, npc: Uuid, memory: &mut MemorySystem) -> StoreResult<bool> {
+        if memory.is_long_term_loaded() {
+            return Ok(false);
+        }
+
+        let stored = self.store.load(npc).await?;
+        let weights = self.store.load_weights(npc).await?;
+        memory.restore_long_term(stored);
+        memory.restore_learned_weights(weights);
+        Ok(true)
+    }
+
+    /// Queues whatever changed in the NPC's long-term memory since the last call.
+    pub fn track(&mut self, npc: Uuid, memory: &mut MemorySystem) {
+        self.queue(npc, memory.take_long_term_changes());
+    }
+
+    /// Queues changes already taken from an NPC's memory, e.g. on another
+    /// thread than the simulation.
+    pub fn queue(&mut self, npc: Uuid, changes: MemoryChanges) {
+        if changes.is_empty() {
+            return;
+        }
+        let MemoryChanges { saved, forgotten, learned_weights } = changes;
+
+        let pending = self.pending.entry(npc).or_default();
+        for stored in saved {
+            pending.forgotten.remove(&stored.memory.id);
+            pending.saved.insert(stored.memory.id, stored);
+        }
+        for id in forgotten {
+            pending.saved.remove(&id);
+            pending.forgotten.insert(id);
+        }
+        if learned_weights.is_some() {
+            pending.weights = learned_weights;
+        }
+    }
+
+    /// Advances the flush timer; true when a flush is due.
+    pub fn tick(&mut self, delta_time: f32) -> bool {
+        self.since_flush += delta_time;
+        let batch_full = self.pending.values().any(|p| p.saved.len() + p.forgotten.len() >= self.batch_size);
+        batch_full || (self.since_flush >= self.flush_interval && self.has_pending())
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Writes every queued change, one batch per NPC. Returns how many
+    /// memories were written or deleted. Writes that fail stay queued.
+    pub async fn flush(&mut self) -> StoreResult<usize> {
+        self.since_flush = 0.0;
+        let mut written = 0;
+
+        let mut batches = std::mem::take(&mut self.pending).into_iter();
+        while let Some((npc, pending)) = batches.next() {
+            match self.write(npc, &pending).await {
+                Ok(count) => written += count,
+                Err(error) => {
+                    self.requeue(npc, pending);
+                    for (npc, pending) in batches {
+                        self.requeue(npc, pending);
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// Writes out an NPC's changes and frees its long-term memories from RAM.
+    /// They come back on the next `ensure_loaded`.
+    pub async fn evict(&mut self, npc: Uuid, memory: &mut MemorySystem) -> StoreResult<()> {
+        self.track(npc, memory);
+        if let Some(pending) = self.pending.remove(&npc) {
+            if let Err(error) = self.write(npc, &pending).await {
+                self.requeue(npc, pending);
+                return Err(error);
+            }
+        }
+        memory.evict_long_term();
+        Ok(())
+    }
+
+    async fn write(&self, npc: Uuid, pending: &PendingWrites) -> StoreResult<usize> {
+        let saved: Vec<StoredMemory> = pending.saved.values().cloned().collect();
+        let forgotten: Vec<Uuid> = pending.forgotten.iter().copied().collect();
+
+        if !saved.is_empty() {
+            self.store.save(npc, &saved).await?;
+        }
+        if !forgotten.is_empty() {
+            self.store.delete(npc, &forgotten).await?;
+        }
+        if let Some(weights) = &pending.weights {
+            self.store.save_weights(npc, weights).await?;
+        }
+        Ok(saved.len() + forgotten.len())
+    }
+
+    /// Puts back writes that failed, without clobbering changes queued since.
+    fn requeue(&mut self, npc: Uuid, pending: PendingWrites) {
+        let queued = self.pending.entry(npc).or_default();
+        for (id, stored) in pending.saved {
+            if !queued.forgotten.contains(&id) {
+                queued.saved.entry(id).or_insert(stored);
+            }
+        }
+        for id in pending.forgotten {
+            if !queued.saved.contains_key(&id) {
+                queued.forgotten.insert(id);
+            }
+        }
+        if queued.weights.is_none() {
+            queued.weights = pending.weights;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consciousness::dreams::{Dream, DreamKind};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    const DREAMS: [&str; 3] = [
+        "The bakery burned down at dawn",
+        "Mara won the harvest festival race",
+        "A stranger stole the mayor's horse",
+    ];
+
+    /// An NPC with `DREAMS` filed in long-term memory.
+    fn remembering() -> MemorySystem {
+        let mut memory = MemorySystem::default();
+        for content in DREAMS {
+            memory.add_dream(&Dream {
+                content: content.to_string(),
+                kind: DreamKind::Replay,
+                sources: Vec::new(),
+                related_entities: Vec::new(),
+                emotional_value: 0.5,
+                unsettling: 0.2,
+            });
+        }
+        memory
+    }
+
+    /// Fails every write while `failing` is set.
+    #[derive(Default)]
+    struct FlakyStore {
+        inner: InMemoryStore,
+        failing: AtomicBool,
+    }
+
+    #[async_trait]
+    impl MemoryStore for FlakyStore {
+        async fn migrate(&self) -> StoreResult<()> {
+            Ok(())
+        }
+
+        async fn load(&self, npc: Uuid) -> StoreResult<Vec<StoredMemory>> {
+            self.inner.load(npc).await
+        }
+
+        async fn save(&self, npc: Uuid, memories: &[StoredMemory]) -> StoreResult<()> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(sqlx::Error::PoolClosed.into());
+            }
+            self.inner.save(npc, memories).await
+        }
+
+        async fn delete(&self, npc: Uuid, ids: &[Uuid]) -> StoreResult<()> {
+            self.inner.delete(npc, ids).await
+        }
+
+        async fn count(&self, npc: Uuid) -> StoreResult<usize> {
+            self.inner.count(npc).await
+        }
+
+        async fn load_weights(&self, npc: Uuid) -> StoreResult<HashMap<String, f32>> {
+            self.inner.load_weights(npc).await
+        }
+
+        async fn save_weights(&self, npc: Uuid, weights: &HashMap<String, f32>) -> StoreResult<()> {
+            self.inner.save_weights(npc, weights).await
+        }
+    }
+
+    #[tokio::test]
+    async fn sqlite_saves_loads_and_deletes() {
+        let store = SqliteMemoryStore::in_memory().await.unwrap();
+        let npc = Uuid::new_v4();
+        let saved = remembering().take_long_term_changes().saved;
+        assert_eq!(saved.len(), DREAMS.len());
+
+        store.save(npc, &saved).await.unwrap();
+        assert_eq!(store.count(npc).await.unwrap(), saved.len());
+        assert_eq!(store.count(Uuid::new_v4()).await.unwrap(), 0);
+
+        let loaded = store.load(npc).await.unwrap();
+        let mut contents: Vec<&str> = loaded.iter().map(|stored| stored.memory.content()).collect();
+        let mut expected: Vec<&str> = saved.iter().map(|stored| stored.memory.content()).collect();
+        contents.sort();
+        expected.sort();
+        assert_eq!(contents, expected);
+
+        // Saving again replaces rather than duplicates
+        store.save(npc, &saved).await.unwrap();
+        assert_eq!(store.count(npc).await.unwrap(), saved.len());
+
+        store.delete(npc, &[saved[0].memory.id()]).await.unwrap();
+        assert_eq!(store.count(npc).await.unwrap(), saved.len() - 1);
+    }
+
+    #[tokio::test]
+    async fn evicted_memories_come_back_on_ensure_loaded() {
+        let store = Arc::new(SqliteMemoryStore::in_memory().await.unwrap());
+        let mut persistence = MemoryPersistence::new(store);
+        let npc = Uuid::new_v4();
+        let mut memory = remembering();
+
+        assert!(!persistence.ensure_loaded(npc, &mut memory).await.unwrap());
+        persistence.evict(npc, &mut memory).await.unwrap();
+        assert!(!memory.is_long_term_loaded());
+        assert_eq!(memory.long_term_memories().count(), 0);
+
+        assert!(persistence.ensure_loaded(npc, &mut memory).await.unwrap());
+        assert!(memory.is_long_term_loaded());
+        assert_eq!(memory.long_term_memories().count(), DREAMS.len());
+    }
+
+    #[tokio::test]
+    async fn learned_weights_are_saved_with_the_memories() {
+        let store = Arc::new(SqliteMemoryStore::in_memory().await.unwrap());
+        let mut persistence = MemoryPersistence::new(Arc::clone(&store));
+        let npc = Uuid::new_v4();
+        let mut memory = remembering();
+        memory.learn_from_goal("win the harvest festival");
+
+        persistence.evict(npc, &mut memory).await.unwrap();
+        let saved = store.load_weights(npc).await.unwrap();
+        assert_eq!(&saved, memory.importance_scoring().learned_weights());
+        assert!(saved.contains_key("festival"));
+
+        let mut respawned = MemorySystem::default();
+        respawned.evict_long_term();
+        persistence.ensure_loaded(npc, &mut respawned).await.unwrap();
+        assert_eq!(respawned.importance_scoring().learned_weights(), &saved);
+    }
+
+    #[tokio::test]
+    async fn tick_flushes_on_a_full_batch() {
+        let store = Arc::new(SqliteMemoryStore::in_memory().await.unwrap());
+        let mut persistence = MemoryPersistence::new(store).with_batching(DREAMS.len(), 60.0);
+        let npc = Uuid::new_v4();
+
+        assert!(!persistence.tick(0.1));
+        persistence.track(npc, &mut remembering());
+        assert!(persistence.tick(0.1));
+
+        let written = persistence.flush().await.unwrap();
+        assert_eq!(persistence.store().count(npc).await.unwrap(), written);
+        assert!(!persistence.has_pending());
+    }
+
+    #[tokio::test]
+    async fn tick_flushes_after_the_interval() {
+        let store = Arc::new(SqliteMemoryStore::in_memory().await.unwrap());
+        let mut persistence = MemoryPersistence::new(store).with_batching(100, 1.0);
+        let npc = Uuid::new_v4();
+
+        // Nothing to write: the interval alone doesn't call for a flush
+        assert!(!persistence.tick(2.0));
+
+        persistence.track(npc, &mut remembering());
+        assert!(persistence.tick(0.0));
+        persistence.flush().await.unwrap();
+
+        persistence.track(npc, &mut remembering());
+        assert!(!persistence.tick(0.5));
+        assert!(persistence.tick(0.5));
+    }
+
+    #[tokio::test]
+    async fn failed_flush_keeps_writes_queued() {
+        let store = Arc::new(FlakyStore::default());
+        let mut persistence = MemoryPersistence::new(Arc::clone(&store));
+        let npc = Uuid::new_v4();
+        persistence.track(npc, &mut remembering());
+
+        store.failing.store(true, Ordering::SeqCst);
+        assert!(persistence.flush().await.is_err());
+        assert!(persistence.has_pending());
+        assert_eq!(store.count(npc).await.unwrap(), 0);
+
+        store.failing.store(false, Ordering::SeqCst);
+        let written = persistence.flush().await.unwrap();
+        assert!(written > 0);
+        assert_eq!(store.count(npc).await.unwrap(), written);
+        assert!(!persistence.has_pending());
+    }
+}
\ No newline at end of file
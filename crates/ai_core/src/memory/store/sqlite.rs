@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use sqlx::Row;
+use std::collections::HashMap;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use super::{MemoryRow, MemoryStore, StoreResult, MIGRATOR};
+use crate::memory::long_term::StoredMemory;
+
+const UPSERT: &str = "INSERT INTO npc_memories
+        (id, npc_id, kind, content, importance, emotional_value, created_at, links, data)
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+    ON CONFLICT (id) DO UPDATE SET
+        kind = excluded.kind,
+        content = excluded.content,
+        importance = excluded.importance,
+        emotional_value = excluded.emotional_value,
+        created_at = excluded.created_at,
+        links = excluded.links,
+        data = excluded.data";
+
+const UPSERT_WEIGHTS: &str = "INSERT INTO npc_importance_weights (npc_id, weights)
+    VALUES (?, ?)
+    ON CONFLICT (npc_id) DO UPDATE SET weights = excluded.weights";
+
+/// Single-file store, e.g. `sqlite://town.db`. The default for local runs.
+#[derive(Debug, Clone)]
+pub struct SqliteMemoryStore {
+    pool: SqlitePool,
+}
+
+impl SqliteMemoryStore {
+    /// Opens (creating if needed) the database at `url` and migrates it.
+    pub async fn connect(url: &str) -> StoreResult<Self> {
+        let options = SqliteConnectOptions::from_str(url)?.create_if_missing(true);
+        let pool = SqlitePoolOptions::new().connect_with(options).await?;
+        let store = Self { pool };
+        store.migrate().await?;
+        Ok(store)
+    }
+
+    /// A private database that lives as long as the store.
+    pub async fn in_memory() -> StoreResult<Self> {
+        // Every connection to `:memory:` is its own database, so keep just one
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        let store = Self { pool };
+        store.migrate().await?;
+        Ok(store)
+    }
+
+    pub fn from_pool(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MemoryStore for SqliteMemoryStore {
+    async fn migrate(&self) -> StoreResult<()> {
+        MIGRATOR.run(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn load(&self, npc: Uuid) -> StoreResult<Vec<StoredMemory>> {
+        let rows = sqlx::query("SELECT data, links FROM npc_memories WHERE npc_id = ? ORDER BY created_at")
+            .bind(npc.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter()
+            .map(|row| MemoryRow::decode(row.try_get("data")?, row.try_get("links")?))
+            .collect()
+    }
+
+    async fn save(&self, npc: Uuid, memories: &[StoredMemory]) -> StoreResult<()> {
+        let mut transaction = self.pool.begin().await?;
+        for stored in memories {
+            let row = MemoryRow::new(npc, stored)?;
+            sqlx::query(UPSERT)
+                .bind(row.id)
+                .bind(row.npc_id)
+                .bind(row.kind)
+                .bind(row.content)
+                .bind(row.importance)
+                .bind(row.emotional_value)
+                .bind(row.created_at)
+                .bind(row.links)
+                .bind(row.data)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn delete(&self, npc: Uuid, ids: &[Uuid]) -> StoreResult<()> {
+        let mut transaction = self.pool.begin().await?;
+        for id in ids {
+            sqlx::query("DELETE FROM npc_memories WHERE npc_id = ? AND id = ?")
+                .bind(npc.to_string())
+                .bind(id.to_string())
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn count(&self, npc: Uuid) -> StoreResult<usize> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM npc_memories WHERE npc_id = ?")
+            .bind(npc.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count as usize)
+    }
+
+    async fn load_weights(&self, npc: Uuid) -> StoreResult<HashMap<String, f32>> {
+        let weights: Option<String> = sqlx::query_scalar("SELECT weights FROM npc_importance_weights WHERE npc_id = ?")
+            .bind(npc.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        match weights {
+            Some(weights) => Ok(serde_json::from_str(&weights)?),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    async fn save_weights(&self, npc: Uuid, weights: &HashMap<String, f32>) -> StoreResult<()> {
+        sqlx::query(UPSERT_WEIGHTS)
+            .bind(npc.to_string())
+            .bind(serde_json::to_string(weights)?)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,258 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::emotions::Emotion;
+use crate::memory::embedding::tokenize;
+
+/// How much an event matters to the NPC even when it touches no goal
+const BASE_DESIRABILITY: f32 = 0.3;
+/// Praise or blame for actions no standard covers, scaled by the outcome
+const UNJUDGED_PRAISE: f32 = 0.3;
+/// Feelings for others weaker than this don't colour their fortunes
+const ATTITUDE_THRESHOLD: f32 = 0.2;
+/// Shared leading letters for two words to count as the same ("steal", "stealing")
+const STEM_LENGTH: usize = 4;
+
+const DISAPPROVING_WORDS: &[&str] = &["wrong", "bad", "evil", "shameful", "cruel", "forbidden", "sin", "wicked"];
+const APPROVING_WORDS: &[&str] = &["good", "right", "kind", "honourable", "honorable", "noble", "virtuous", "proper"];
+
+/// Something that happened (or might), described the way OCC appraisal
+/// needs it: who did it, who it affects, and how it turned out for them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Appraisal {
+    pub description: String,
+    /// How good the outcome is for whoever it affects, -1..1
+    pub outcome: f32,
+    pub agent: Agent,
+    /// Who the outcome falls on; `None` is the NPC itself
+    pub affected: Option<Uuid>,
+    /// Not happened yet, only possible
+    pub prospective: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Agent {
+    /// Nobody's doing: weather, luck
+    Nobody,
+    /// The NPC itself
+    Myself,
+    Other(Uuid),
+}
+
+/// What the NPC judges events against.
+#[derive(Debug, Clone, Default)]
+pub struct AppraisalContext {
+    /// Goal descriptions and priorities
+    pub goals: Vec<(String, f32)>,
+    /// Beliefs about how one should act, e.g. "stealing is wrong", with strength
+    pub standards: Vec<(String, f32)>,
+    /// How much the NPC likes others, -1..1
+    pub attitudes: HashMap<Uuid, f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AppraisedEmotion {
+    pub emotion: Emotion,
+    pub intensity: f32,
+}
+
+impl Appraisal {
+    pub fn new(description: impl Into<String>, outcome: f32) -> Self {
+        Self {
+            description: description.into(),
+            outcome: outcome.clamp(-1.0, 1.0),
+            agent: Agent::Nobody,
+            affected: None,
+            prospective: false,
+        }
+    }
+
+    pub fn by(mut self, agent: Agent) -> Self {
+        self.agent = agent;
+        self
+    }
+
+    pub fn affecting(mut self, other: Uuid) -> Self {
+        self.affected = Some(other);
+        self
+    }
+
+    pub fn prospective(mut self) -> Self {
+        self.prospective = true;
+        self
+    }
+}
+
+impl AppraisalContext {
+    /// OCC appraisal: consequences of events give joy, distress, hope and
+    /// fear; actions of agents give pride, shame, admiration and reproach;
+    /// the two together give gratitude and anger.
+    pub fn appraise(&self, event: &Appraisal) -> Vec<AppraisedEmotion> {
+        let words = tokenize(&event.description);
+        let mut emotions: HashMap<Emotion, f32> = HashMap::new();
+        let mut feel = |emotion: Emotion, intensity: f32| {
+            if intensity > 0.0 {
+                let current = emotions.entry(emotion).or_insert(0.0);
+                *current = current.max(intensity.min(1.0));
+            }
+        };
+
+        // Step 1: consequences for the NPC or for someone it has feelings about
+        let desirability = match event.affected {
+            None => event.outcome * (BASE_DESIRABILITY + self.goal_relevance(&words) * (1.0 - BASE_DESIRABILITY)),
+            Some(other) => {
+                let liking = self.attitudes.get(&other).copied().unwrap_or(0.0);
+                if liking.abs() < ATTITUDE_THRESHOLD {
+                    0.0
+                } else {
+                    // Happy-for and pity for friends, gloating and resentment for enemies
+                    event.outcome * liking
+                }
+            }
+        };
+        match (event.prospective, desirability > 0.0) {
+            (true, true) => feel(Emotion::Hope, desirability),
+            (true, false) => feel(Emotion::Fear, -desirability),
+            (false, true) => feel(Emotion::Joy, desirability),
+            (false, false) => feel(Emotion::Sadness, -desirability),
+        }
+
+        // Step 2: actions, judged against the NPC's standards
+        if event.agent != Agent::Nobody && !event.prospective {
+            let praise = self.praiseworthiness(&words).unwrap_or(event.outcome * UNJUDGED_PRAISE);
+            match (event.agent, praise > 0.0) {
+                (Agent::Myself, true) => feel(Emotion::Pride, praise),
+                (Agent::Myself, false) => feel(Emotion::Shame, -praise),
+                (_, true) => feel(Emotion::Admiration, praise),
+                (_, false) => feel(Emotion::Reproach, -praise),
+            }
+
+            // Step 3: compounds, when someone else's action touched the NPC
+            if let (Agent::Other(_), None) = (event.agent, event.affected) {
+                if praise > 0.0 && desirability > 0.0 {
+                    feel(Emotion::Gratitude, (praise + desirability) / 2.0);
+                } else if praise < 0.0 && desirability < 0.0 {
+                    feel(Emotion::Anger, -(praise + desirability) / 2.0);
+                }
+            }
+        }
+
+        let mut emotions: Vec<AppraisedEmotion> = emotions
+            .into_iter()
+            .map(|(emotion, intensity)| AppraisedEmotion { emotion, intensity })
+            .collect();
+        emotions.sort_by(|a, b| b.intensity.partial_cmp(&a.intensity).unwrap_or(std::cmp::Ordering::Equal));
+        emotions
+    }
+
+    /// How strongly the event bears on the NPC's goals, 0..1.
+    fn goal_relevance(&self, words: &[String]) -> f32 {
+        self.goals
+            .iter()
+            .map(|(goal, priority)| overlap(&tokenize(goal), words) * priority.clamp(0.0, 1.0))
+            .fold(0.0, f32::max)
+    }
+
+    /// Praise (positive) or blame (negative) from every standard that covers
+    /// the event, or `None` when no standard applies.
+    fn praiseworthiness(&self, words: &[String]) -> Option<f32> {
+        let mut judged = false;
+        let mut praise = 0.0;
+
+        for (standard, strength) in &self.standards {
+            let tokens = tokenize(standard);
+            let polarity = if tokens.iter().any(|t| DISAPPROVING_WORDS.contains(&t.as_str())) {
+                -1.0
+            } else if tokens.iter().any(|t| APPROVING_WORDS.contains(&t.as_str())) {
+                1.0
+            } else {
+                continue;
+            };
+
+            let subject: Vec<String> = tokens
+                .into_iter()
+                .filter(|t| !DISAPPROVING_WORDS.contains(&t.as_str()) && !APPROVING_WORDS.contains(&t.as_str()))
+                .collect();
+            let coverage = overlap(&subject, words);
+            if coverage > 0.0 {
+                judged = true;
+                praise += polarity * coverage * strength.clamp(0.0, 1.0);
+            }
+        }
+
+        judged.then(|| praise.clamp(-1.0, 1.0))
+    }
+}
+
+/// Fraction of `of` that turns up in `words`.
+fn overlap(of: &[String], words: &[String]) -> f32 {
+    if of.is_empty() {
+        return 0.0;
+    }
+    let found = of.iter().filter(|a| words.iter().any(|b| same_word(a, b))).count();
+    found as f32 / of.len() as f32
+}
+
+fn same_word(a: &str, b: &str) -> bool {
+    a == b || (a.len() >= STEM_LENGTH && b.len() >= STEM_LENGTH && a.get(..STEM_LENGTH) == b.get(..STEM_LENGTH))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strongest(emotions: &[AppraisedEmotion], emotion: Emotion) -> f32 {
+        emotions.iter().find(|e| e.emotion == emotion).map_or(0.0, |e| e.intensity)
+    }
+
+    #[test]
+    fn someone_breaking_a_standard_against_me_angers() {
+        let thief = Uuid::new_v4();
+        let context = AppraisalContext { standards: vec![("stealing is wrong".into(), 1.0)], ..Default::default() };
+
+        let emotions = context.appraise(&Appraisal::new("He stole my bread", -0.8).by(Agent::Other(thief)));
+
+        assert!(strongest(&emotions, Emotion::Reproach) > 0.0);
+        assert!(strongest(&emotions, Emotion::Anger) > 0.0);
+        assert!(strongest(&emotions, Emotion::Sadness) > 0.0);
+        assert_eq!(strongest(&emotions, Emotion::Gratitude), 0.0);
+    }
+
+    #[test]
+    fn my_own_good_deed_makes_me_proud() {
+        let context = AppraisalContext { standards: vec![("helping is good".into(), 0.9)], ..Default::default() };
+
+        let emotions = context.appraise(&Appraisal::new("I helped the farmer", 0.5).by(Agent::Myself));
+
+        assert!(strongest(&emotions, Emotion::Pride) > 0.0);
+        assert_eq!(strongest(&emotions, Emotion::Shame), 0.0);
+    }
+
+    #[test]
+    fn prospects_touching_goals_raise_stronger_hope() {
+        let context = AppraisalContext { goals: vec![("bring in the harvest".into(), 1.0)], ..Default::default() };
+
+        let harvest = context.appraise(&Appraisal::new("Rain will help the harvest", 0.8).prospective());
+        let unrelated = context.appraise(&Appraisal::new("A bard may visit", 0.8).prospective());
+        let threat = context.appraise(&Appraisal::new("Locusts may ruin the harvest", -0.8).prospective());
+
+        assert!(strongest(&harvest, Emotion::Hope) > strongest(&unrelated, Emotion::Hope));
+        assert!(strongest(&threat, Emotion::Fear) > 0.0);
+        assert_eq!(strongest(&harvest, Emotion::Joy), 0.0);
+    }
+
+    #[test]
+    fn only_people_i_care_about_colour_their_fortunes() {
+        let (friend, rival, stranger) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let context = AppraisalContext {
+            attitudes: HashMap::from([(friend, 0.8), (rival, -0.8), (stranger, 0.1)]),
+            ..Default::default()
+        };
+        let windfall = |who| context.appraise(&Appraisal::new("Found a gold coin", 0.5).affecting(who));
+
+        assert!(strongest(&windfall(friend), Emotion::Joy) > 0.0);
+        assert!(strongest(&windfall(rival), Emotion::Sadness) > 0.0);
+        assert!(windfall(stranger).is_empty());
+    }
+}
\ No newline at end of file
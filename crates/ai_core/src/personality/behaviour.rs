@@ -71,6 +71,25 @@ impl BehaviorProfile {
                 self.cooperation += 0.1;
                 self.social_preference += 0.1;
             },
+            Emotion::Hope => {
+                self.risk_tolerance += 0.05;
+                self.decision_speed += 0.05;
+            },
+            Emotion::Pride => {
+                self.assertiveness += 0.1;
+                self.social_preference += 0.05;
+            },
+            Emotion::Shame => {
+                self.social_preference -= 0.2;
+                self.assertiveness -= 0.1;
+            },
+            Emotion::Gratitude | Emotion::Admiration => {
+                self.cooperation += 0.15;
+                self.social_preference += 0.05;
+            },
+            Emotion::Reproach => {
+                self.cooperation -= 0.1;
+            },
             _ => {}
         }
 
@@ -89,7 +108,7 @@ impl BehaviorProfile {
 
         tendencies.iter()
             .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
-            .map(|&(_, ref tendency)| tendency.clone())
+            .map(|(_, tendency)| tendency.clone())
             .unwrap_or(BehaviorTendency::Adaptive)
     }
 
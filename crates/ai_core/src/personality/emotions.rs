@@ -0,0 +1,278 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use super::appraisal::{Appraisal, AppraisalContext, AppraisedEmotion};
+use super::traits::PersonalityTraits;
+
+/// Below this an emotion doesn't show
+const EXPRESSION_THRESHOLD: f32 = 0.1;
+/// Fraction of an emotion that fades per second for an average temperament
+const BASE_DECAY_RATE: f32 = 0.05;
+
+/// The NPC's one emotional state: appraised events raise emotions, time
+/// lets them fade, and dialogue and behaviour both read from it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct EmotionalState {
+    current_emotions: HashMap<Emotion, f32>,
+    baseline_mood: f32,
+    emotional_inertia: f32,
+    volatility: f32,
+    /// From `PersonalityTraits::neuroticism`; sharpens and prolongs negative emotions
+    #[serde(default = "default_neuroticism")]
+    neuroticism: f32,
+    /// Unmet bodily and social needs, 0..1; sours the mood
+    #[serde(default)]
+    discomfort: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Emotion {
+    Joy,
+    Sadness,
+    Anger,
+    Fear,
+    Surprise,
+    Trust,
+    Disgust,
+    Anticipation,
+    Confusion,
+    Curiosity,
+    /// Looking forward to something good that may happen
+    Hope,
+    /// Approving of something the NPC did itself
+    Pride,
+    /// Disapproving of something the NPC did itself
+    Shame,
+    /// Someone else did something good for the NPC
+    Gratitude,
+    /// Approving of someone else's action
+    Admiration,
+    /// Disapproving of someone else's action
+    Reproach,
+}
+
+impl Emotion {
+    pub const ALL: [Emotion; 16] = [
+        Emotion::Joy,
+        Emotion::Sadness,
+        Emotion::Anger,
+        Emotion::Fear,
+        Emotion::Surprise,
+        Emotion::Trust,
+        Emotion::Disgust,
+        Emotion::Anticipation,
+        Emotion::Confusion,
+        Emotion::Curiosity,
+        Emotion::Hope,
+        Emotion::Pride,
+        Emotion::Shame,
+        Emotion::Gratitude,
+        Emotion::Admiration,
+        Emotion::Reproach,
+    ];
+
+    /// +1 for pleasant emotions, -1 for unpleasant ones, 0 for neither.
+    pub fn valence(&self) -> f32 {
+        match self {
+            Emotion::Joy
+            | Emotion::Trust
+            | Emotion::Anticipation
+            | Emotion::Curiosity
+            | Emotion::Hope
+            | Emotion::Pride
+            | Emotion::Gratitude
+            | Emotion::Admiration => 1.0,
+            Emotion::Sadness
+            | Emotion::Anger
+            | Emotion::Fear
+            | Emotion::Disgust
+            | Emotion::Shame
+            | Emotion::Reproach => -1.0,
+            Emotion::Surprise | Emotion::Confusion => 0.0,
+        }
+    }
+
+    /// Word dialogue uses to pick its tone.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Emotion::Joy => "happy",
+            Emotion::Sadness => "sad",
+            Emotion::Anger => "angry",
+            Emotion::Fear => "afraid",
+            Emotion::Surprise => "surprised",
+            Emotion::Trust => "trusting",
+            Emotion::Disgust => "disgusted",
+            Emotion::Anticipation => "expectant",
+            Emotion::Confusion => "confused",
+            Emotion::Curiosity => "curious",
+            Emotion::Hope => "hopeful",
+            Emotion::Pride => "proud",
+            Emotion::Shame => "ashamed",
+            Emotion::Gratitude => "grateful",
+            Emotion::Admiration => "admiring",
+            Emotion::Reproach => "disapproving",
+        }
+    }
+}
+
+impl Default for EmotionalState {
+    fn default() -> Self {
+        let mut current_emotions = HashMap::new();
+        for emotion in Emotion::ALL {
+            current_emotions.insert(emotion, 0.0);
+        }
+
+        Self {
+            current_emotions,
+            baseline_mood: 0.5,
+            emotional_inertia: 0.5,
+            volatility: 0.1,
+            neuroticism: default_neuroticism(),
+            discomfort: 0.0,
+        }
+    }
+}
+
+impl EmotionalState {
+    pub fn from_traits(traits: &PersonalityTraits) -> Self {
+        let mut state = Self::default();
+        state.set_neuroticism(traits.neuroticism);
+        state
+    }
+
+    pub fn set_neuroticism(&mut self, neuroticism: f32) {
+        self.neuroticism = neuroticism.clamp(0.0, 1.0);
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        // Fade every emotion; neurotic NPCs dwell on the bad and let go of the good
+        let n = self.neuroticism;
+        for (emotion, intensity) in self.current_emotions.iter_mut() {
+            let temperament = if emotion.valence() < 0.0 { 1.5 - n } else { 0.5 + n };
+            let rate = BASE_DECAY_RATE * temperament * (1.5 - self.emotional_inertia);
+            *intensity *= (-rate * delta_time).exp();
+        }
+
+        // Mood drifts after how the NPC feels
+        let target_mood = (0.5 + self.get_emotional_valence() - self.discomfort * 0.5).clamp(0.0, 1.0);
+        self.baseline_mood += (target_mood - self.baseline_mood) * (0.01 * delta_time).min(1.0);
+
+        // Update volatility based on emotional changes
+        self.update_volatility();
+    }
+
+    /// Feels whatever the event means to the NPC, given its goals, standards
+    /// and attitudes. Returns the emotions raised, strongest first.
+    pub fn appraise(&mut self, event: &Appraisal, context: &AppraisalContext) -> Vec<AppraisedEmotion> {
+        let mut emotions = context.appraise(event);
+        for appraised in &mut emotions {
+            appraised.intensity = self.temper(appraised.emotion, appraised.intensity);
+            self.add_emotion(appraised.emotion, appraised.intensity);
+        }
+        emotions
+    }
+
+    pub fn generate_response(&mut self, event: &str, intensity: f32) -> Emotion {
+        let emotion = self.analyze_event(event);
+        self.add_emotion(emotion, self.temper(emotion, intensity));
+        emotion
+    }
+
+    pub fn add_emotion(&mut self, emotion: Emotion, intensity: f32) {
+        let current = self.current_emotions.entry(emotion).or_insert(0.0);
+        *current = (*current + intensity).clamp(0.0, 1.0);
+    }
+
+    pub fn get_dominant_emotion(&self) -> Emotion {
+        self.current_emotions
+            .iter()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(emotion, _)| *emotion)
+            .unwrap_or(Emotion::Joy)
+    }
+
+    /// The emotion that shows, or `None` when the NPC is calm.
+    pub fn expressed_emotion(&self) -> Option<(Emotion, f32)> {
+        let emotion = self.get_dominant_emotion();
+        let intensity = self.get_intensity(emotion);
+        (intensity >= EXPRESSION_THRESHOLD).then_some((emotion, intensity))
+    }
+
+    /// Word for the current emotion, "neutral" when calm.
+    pub fn label(&self) -> &'static str {
+        self.expressed_emotion().map(|(emotion, _)| emotion.label()).unwrap_or("neutral")
+    }
+
+    /// How much unmet needs weigh on the NPC, from `Needs::discomfort`.
+    pub fn set_discomfort(&mut self, discomfort: f32) {
+        self.discomfort = discomfort.clamp(0.0, 1.0);
+    }
+
+    pub fn mood(&self) -> f32 {
+        self.baseline_mood
+    }
+
+    pub fn get_intensity(&self, emotion: Emotion) -> f32 {
+        self.current_emotions.get(&emotion).copied().unwrap_or(0.0)
+    }
+
+    pub fn get_emotional_valence(&self) -> f32 {
+        let (sum, count) = self.current_emotions
+            .iter()
+            .filter(|(emotion, _)| emotion.valence() != 0.0)
+            .fold((0.0, 0), |(sum, count), (emotion, intensity)| (sum + emotion.valence() * intensity, count + 1));
+
+        if count == 0 {
+            0.0
+        } else {
+            sum / count as f32
+        }
+    }
+
+    pub fn neuroticism(&self) -> f32 {
+        self.neuroticism
+    }
+
+    pub fn get_volatility(&self) -> f32 {
+        self.volatility
+    }
+
+    fn analyze_event(&self, event: &str) -> Emotion {
+        // Simple event analysis - could be made more sophisticated
+        match event {
+            e if e.contains("danger") || e.contains("threat") => Emotion::Fear,
+            e if e.contains("happy") || e.contains("success") => Emotion::Joy,
+            e if e.contains("angry") || e.contains("unfair") => Emotion::Anger,
+            e if e.contains("sad") || e.contains("loss") => Emotion::Sadness,
+            e if e.contains("trust") || e.contains("friend") => Emotion::Trust,
+            e if e.contains("disgust") || e.contains("gross") => Emotion::Disgust,
+            e if e.contains("surprise") || e.contains("unexpected") => Emotion::Surprise,
+            e if e.contains("anticipate") || e.contains("expect") => Emotion::Anticipation,
+            e if e.contains("confused") || e.contains("unclear") => Emotion::Confusion,
+            e if e.contains("curious") || e.contains("interesting") => Emotion::Curiosity,
+            _ => Emotion::Surprise,
+        }
+    }
+
+    /// Neurotic NPCs feel bad things more sharply and good things less.
+    fn temper(&self, emotion: Emotion, intensity: f32) -> f32 {
+        let n = self.neuroticism;
+        let gain = if emotion.valence() < 0.0 { 0.6 + 0.8 * n } else { 1.2 - 0.4 * n };
+        (intensity * gain).clamp(0.0, 1.0)
+    }
+
+    fn update_volatility(&mut self) {
+        let count = self.current_emotions.len() as f32;
+        let mean = self.current_emotions.values().sum::<f32>() / count;
+        let emotion_variance: f32 = self.current_emotions.values()
+            .map(|&intensity| (intensity - mean).powi(2))
+            .sum();
+        
+        self.volatility = (emotion_variance / count).sqrt();
+    }
+}
+
+fn default_neuroticism() -> f32 {
+    0.5
+}
\ No newline at end of file
@@ -5,16 +5,17 @@ pub fn generate_personality() -> Personality {
     let traits = generate_traits();
     let emotional_state = EmotionalState::default();
     let behavior_profile = BehaviorProfile::new(&traits);
-    
+    let stability = calculate_initial_stability(&traits);
+
     Personality {
         traits,
         emotional_state,
         behavior_profile,
-        stability: calculate_initial_stability(&traits),
+        stability,
     }
 }
 
-fn generate_traits() -> PersonalityTraits {
+pub fn generate_traits() -> PersonalityTraits {
     let mut rng = thread_rng();
     
     PersonalityTraits::new(
@@ -28,13 +29,13 @@ fn generate_traits() -> PersonalityTraits {
 
 fn generate_trait_value(rng: &mut ThreadRng) -> f32 {
     // Use normal distribution centered at 0.5 with standard deviation of 0.15
-    let normal = rand_distr::Normal::new(0.5, 0.15).unwrap();
+    let normal = rand_distr::Normal::<f32>::new(0.5, 0.15).unwrap();
     normal.sample(rng).clamp(0.0, 1.0)
 }
 
 fn calculate_initial_stability(traits: &PersonalityTraits) -> f32 {
     // Higher conscientiousness and lower neuroticism contribute to stability
-    let base_stability = (traits.conscientiousness * 0.4 + (1.0 - traits.neuroticism) * 0.6);
+    let base_stability = traits.conscientiousness * 0.4 + (1.0 - traits.neuroticism) * 0.6;
     
     // Add some random variation
     let mut rng = thread_rng();
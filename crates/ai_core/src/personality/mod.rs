@@ -2,12 +2,13 @@ use serde::{Serialize, Deserialize};
 
 pub mod traits;
 pub mod emotions;
-pub mod behavior;
+pub mod appraisal;
+pub mod behaviour;
 pub mod generation;
 
-use traits::PersonalityTraits;
-use emotions::{EmotionalState, Emotion};
-use behavior::BehaviorProfile;
+pub use traits::PersonalityTraits;
+pub use emotions::EmotionalState;
+pub use behaviour::BehaviorProfile;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Personality {
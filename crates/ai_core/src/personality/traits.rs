@@ -2,6 +2,7 @@ use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
 pub struct PersonalityTraits {
     // Core traits (Big Five)
     pub openness: f32,         // Openness to experience
@@ -45,6 +46,11 @@ impl PersonalityTraits {
         }
     }
 
+    /// Random traits, spread around the middle.
+    pub fn generate() -> Self {
+        super::generation::generate_traits()
+    }
+
     pub fn add_trait(&mut self, name: &str, value: f32) {
         self.traits.insert(name.to_string(), value.clamp(0.0, 1.0));
     }
@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default)]
 pub struct GossipNetwork {
     gossip_items: HashMap<Uuid, GossipItem>,
     npc_knowledge: HashMap<Uuid, HashSet<Uuid>>,  // NPC -> Known gossip items
@@ -11,7 +12,7 @@ pub struct GossipNetwork {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct GossipItem {
+pub struct GossipItem {
     id: Uuid,
     content: String,
     source: Uuid,
@@ -22,14 +23,13 @@ struct GossipItem {
     emotional_impact: f32,
 }
 
-impl Default for GossipNetwork {
-    fn default() -> Self {
-        Self {
-            gossip_items: HashMap::new(),
-            npc_knowledge: HashMap::new(),
-            credibility_scores: HashMap::new(),
-            propagation_paths: HashMap::new(),
-        }
+impl GossipItem {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
     }
 }
 
@@ -67,7 +67,7 @@ impl GossipNetwork {
         // Add to source's known gossip
         self.npc_knowledge
             .entry(source)
-            .or_insert_with(HashSet::new)
+            .or_default()
             .insert(gossip_id);
 
         gossip_id
@@ -80,11 +80,16 @@ impl GossipNetwork {
         to_npc: Uuid,
         distortion_factor: f32
     ) -> bool {
+        // Only someone who has heard it can pass it on
+        if !self.npc_knowledge.get(&from_npc).is_some_and(|known| known.contains(&gossip_id)) {
+            return false;
+        }
+
         if let Some(gossip) = self.gossip_items.get_mut(&gossip_id) {
             // Check if receiver already knows this gossip
             if self.npc_knowledge
                 .get(&to_npc)
-                .map_or(false, |known| known.contains(&gossip_id))
+                .is_some_and(|known| known.contains(&gossip_id))
             {
                 return false;
             }
@@ -105,7 +110,7 @@ impl GossipNetwork {
             // Add to receiver's known gossip
             self.npc_knowledge
                 .entry(to_npc)
-                .or_insert_with(HashSet::new)
+                .or_default()
                 .insert(gossip_id);
 
             true
@@ -141,6 +146,20 @@ impl GossipNetwork {
             })
     }
 
+    /// What a piece of gossip says, how credible it now is, and who it has
+    /// reached, in order.
+    pub fn describe(&self, gossip_id: Uuid) -> Option<(&str, f32, &[Uuid])> {
+        let gossip = self.gossip_items.get(&gossip_id)?;
+        let path = self.propagation_paths.get(&gossip_id).map_or(&[][..], |path| path.as_slice());
+        Some((gossip.content.as_str(), gossip.credibility, path))
+    }
+
+    /// How often what `npc` passed on turned out true, 0..1; 0.5 for
+    /// unknowns.
+    pub fn credibility_of(&self, npc: Uuid) -> f32 {
+        self.credibility_scores.get(&npc).copied().unwrap_or(0.5)
+    }
+
     pub fn get_propagation_path(&self, gossip_id: &Uuid) -> Option<&Vec<Uuid>> {
         self.propagation_paths.get(gossip_id)
     }
@@ -168,9 +187,14 @@ impl GossipNetwork {
     }
 
     fn update_credibility_scores(&mut self) {
-        for (npc_id, score) in &mut self.credibility_scores {
-            let verified_gossip = self.get_npc_verified_gossip_ratio(*npc_id);
-            *score = (*score * 0.9 + verified_gossip * 0.1).clamp(0.0, 1.0);
+        let ratios: Vec<(Uuid, f32)> = self.credibility_scores
+            .keys()
+            .map(|npc_id| (*npc_id, self.get_npc_verified_gossip_ratio(*npc_id)))
+            .collect();
+        for (npc_id, verified_gossip) in ratios {
+            if let Some(score) = self.credibility_scores.get_mut(&npc_id) {
+                *score = (*score * 0.9 + verified_gossip * 0.1).clamp(0.0, 1.0);
+            }
         }
     }
 
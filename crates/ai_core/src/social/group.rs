@@ -92,13 +92,6 @@ impl Group {
     }
 
     pub fn record_activity(&mut self, activity_type: String, participants: HashSet<Uuid>, impact: f32) {
-        self.activities.push(GroupActivity {
-            activity_type,
-            participants,
-            impact,
-            timestamp: 0.0, // Current time should be passed in
-        });
-
         // Update relationships between participants
         for member1 in &participants {
             for member2 in &participants {
@@ -108,6 +101,13 @@ impl Group {
                 }
             }
         }
+
+        self.activities.push(GroupActivity {
+            activity_type,
+            participants,
+            impact,
+            timestamp: 0.0, // Current time should be passed in
+        });
     }
 
     fn update_cohesion(&mut self) {
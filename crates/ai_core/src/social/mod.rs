@@ -4,15 +4,17 @@ use std::collections::HashMap;
 
 pub mod relationships;
 pub mod influence;
-pub mod groups;
+pub mod group;
 pub mod gossip;
 
 use relationships::{Relationship, RelationshipType};
 use influence::SocialInfluence;
-use groups::{Group, GroupDynamics};
+use group::Group;
 use gossip::GossipNetwork;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Resource))]
+#[derive(Default)]
 pub struct SocialNetwork {
     relationships: HashMap<(Uuid, Uuid), Relationship>,
     influences: HashMap<Uuid, SocialInfluence>,
@@ -20,18 +22,9 @@ pub struct SocialNetwork {
     gossip_network: GossipNetwork,
 }
 
-impl Default for SocialNetwork {
-    fn default() -> Self {
-        Self {
-            relationships: HashMap::new(),
-            influences: HashMap::new(),
-            groups: Vec::new(),
-            gossip_network: GossipNetwork::default(),
-        }
-    }
-}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
 pub struct SocialBehavior {
     trustworthiness: f32,
     charisma: f32,
@@ -72,7 +65,7 @@ impl SocialNetwork {
 
     pub fn get_social_circle(&self, npc_id: Uuid) -> Vec<Uuid> {
         self.relationships.iter()
-            .filter_map(|((id1, id2), rel)| {
+            .filter_map(|((id1, id2), _)| {
                 if *id1 == npc_id {
                     Some(*id2)
                 } else if *id2 == npc_id {
@@ -84,8 +77,24 @@ impl SocialNetwork {
             .collect()
     }
 
-    pub fn spread_information(&mut self, source: Uuid, information: String, credibility: f32) {
-        self.gossip_network.spread_information(source, information, credibility);
+    /// How far `listener` believes what `source` tells them: the source's
+    /// record as a gossip, and the listener's trust in them.
+    pub fn source_reliability(&self, listener: Uuid, source: Uuid) -> f32 {
+        let trust = self.get_relationship(listener, source).map_or(0.5, |rel| rel.get_trust_level());
+        crate::knowledge::beliefs::source_reliability(self.gossip_network.credibility_of(source), trust)
+    }
+
+    pub fn spread_information(&mut self, source: Uuid, information: String, credibility: f32) -> Uuid {
+        self.gossip_network.spread_information(source, information, credibility)
+    }
+
+    /// `from` tells `to` a piece of gossip; false if `to` had heard it.
+    pub fn pass_on_gossip(&mut self, gossip_id: Uuid, from: Uuid, to: Uuid, distortion: f32) -> bool {
+        self.gossip_network.propagate_gossip(gossip_id, from, to, distortion)
+    }
+
+    pub fn gossip(&self) -> &GossipNetwork {
+        &self.gossip_network
     }
 
     pub fn create_group(&mut self, members: Vec<Uuid>, group_type: String) -> Uuid {
@@ -96,6 +105,12 @@ impl SocialNetwork {
     }
 }
 
+impl Default for SocialBehavior {
+    fn default() -> Self {
+        Self::new(0.5, 0.5, 0.5)
+    }
+}
+
 impl SocialBehavior {
     pub fn new(trustworthiness: f32, charisma: f32, group_affinity: f32) -> Self {
         Self {
@@ -119,6 +134,10 @@ impl SocialBehavior {
         }
     }
 
+    pub fn get_connections(&self) -> &[Uuid] {
+        &self.social_connections
+    }
+
     pub fn get_social_influence(&self) -> f32 {
         (self.charisma + self.trustworthiness) / 2.0
     }
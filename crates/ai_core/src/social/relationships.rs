@@ -1,5 +1,4 @@
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Relationship {
@@ -12,7 +11,7 @@ pub struct Relationship {
     last_interaction: f32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Copy)]
 pub enum RelationshipType {
     Friend,
     Enemy,
@@ -25,7 +24,7 @@ pub enum RelationshipType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct Interaction {
+pub struct Interaction {
     interaction_type: String,
     impact: f32,
     timestamp: f32,
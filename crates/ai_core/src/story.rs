@@ -0,0 +1,489 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::consciousness::awareness::MilestoneKind;
+use crate::consciousness::ConsciousnessState;
+use crate::goals::GoalSystem;
+use crate::memory::{Memory, MemoryKind, MemorySystem};
+use crate::time::SECONDS_PER_DAY;
+
+/// What goes into a life story and how it's told.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryOptions {
+    /// The NPC's own name; stories fall back to "I" / "This villager"
+    pub name: Option<String>,
+    /// Names for the people the NPC remembers
+    pub names: HashMap<Uuid, String>,
+    pub max_relationships: usize,
+    pub max_turning_points: usize,
+    /// Memories need at least this significance to be a turning point
+    pub turning_point_threshold: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifeStory {
+    pub npc: Uuid,
+    pub name: Option<String>,
+    /// Game days since the NPC's earliest memory
+    pub days_lived: u32,
+    /// Where most of the NPC's memories took place
+    pub home: Option<String>,
+    pub relationships: Vec<KeyRelationship>,
+    /// Oldest first
+    pub turning_points: Vec<TurningPoint>,
+    pub achievements: Vec<StoryAchievement>,
+    pub awareness: Vec<AwarenessEvent>,
+    pub is_aware: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRelationship {
+    pub entity: Uuid,
+    pub name: Option<String>,
+    pub bond: Bond,
+    /// Memories the person appears in
+    pub shared_memories: usize,
+    /// Mean feeling across those memories, -1..1
+    pub feeling: f32,
+    pub first_met: f32,
+    /// The strongest memory of them
+    pub defining_memory: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bond {
+    Close,
+    Acquainted,
+    Wary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurningPoint {
+    pub memory: Uuid,
+    pub content: String,
+    pub emotional_value: f32,
+    pub significance: f32,
+    pub day: Option<u32>,
+    pub place: Option<String>,
+    pub timestamp: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryAchievement {
+    pub name: String,
+    pub description: String,
+    pub completed_at: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwarenessEvent {
+    pub kind: MilestoneKind,
+    pub at: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Voice {
+    /// The NPC telling its own story
+    FirstPerson,
+    /// A character page
+    ThirdPerson,
+}
+
+impl Default for StoryOptions {
+    fn default() -> Self {
+        Self {
+            name: None,
+            names: HashMap::new(),
+            max_relationships: 3,
+            max_turning_points: 5,
+            turning_point_threshold: 0.5,
+        }
+    }
+}
+
+impl LifeStory {
+    /// Pieces the life of NPC `npc` together from its long-term memories,
+    /// goals and awareness. Takes the parts rather than an `Npc` so spawned
+    /// NPCs, whose parts are components, have stories too.
+    pub fn build(
+        npc: Uuid,
+        memory: &MemorySystem,
+        goals: &GoalSystem,
+        consciousness: &ConsciousnessState,
+        options: &StoryOptions,
+    ) -> Self {
+        // Dreams aren't part of anyone's history
+        let memories: Vec<&Memory> = memory
+            .long_term_memories()
+            .filter(|m| m.kind() != MemoryKind::Dream)
+            .collect();
+
+        let born = memories
+            .iter()
+            .map(|m| m.timestamp())
+            .fold(memory.current_time(), f32::min);
+        let days_lived = ((memory.current_time() - born) / SECONDS_PER_DAY) as u32;
+
+        let mut achievements: Vec<StoryAchievement> = goals
+            .achievements()
+            .completed_achievements()
+            .into_iter()
+            .map(|a| StoryAchievement {
+                name: a.name().to_string(),
+                description: a.description().to_string(),
+                completed_at: a.completion_date(),
+            })
+            .collect();
+        // Finished goals count too, even without a named achievement
+        for goal in goals.completed_goals() {
+            if !achievements.iter().any(|a| a.description == goal.description()) {
+                achievements.push(StoryAchievement {
+                    name: goal.description().to_string(),
+                    description: goal.description().to_string(),
+                    completed_at: None,
+                });
+            }
+        }
+        for milestone in goals.achievements().reached_milestones() {
+            achievements.push(StoryAchievement {
+                name: milestone.name().to_string(),
+                description: milestone.name().to_string(),
+                completed_at: milestone.achievement_date(),
+            });
+        }
+
+        let awareness = consciousness
+            .awareness_milestones()
+            .iter()
+            .map(|m| AwarenessEvent { kind: m.kind, at: m.at })
+            .collect();
+
+        Self {
+            npc,
+            name: options.name.clone(),
+            days_lived,
+            home: home(&memories),
+            relationships: relationships(&memories, options),
+            turning_points: turning_points(&memories, options),
+            achievements,
+            awareness,
+            is_aware: consciousness.is_aware(),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// The story as a few paragraphs of plain text.
+    pub fn prose(&self, voice: Voice) -> String {
+        let mut paragraphs = vec![self.opening(voice)];
+
+        if !self.relationships.is_empty() {
+            let people: Vec<String> = self.relationships
+                .iter()
+                .map(|r| format!("{} {}", bond_phrase(r.bond, voice), person(r)))
+                .collect();
+            paragraphs.push(sentence(&format!("{} {}", self.subject(voice), join(&people))));
+        }
+
+        if !self.turning_points.is_empty() {
+            let moments: Vec<String> = self.turning_points
+                .iter()
+                .map(|point| {
+                    let when = point.day.map(|day| format!("On day {}", day + 1)).unwrap_or_else(|| "Once".to_string());
+                    let place = point.place.as_ref().map(|p| format!(" at {}", p)).unwrap_or_default();
+                    let memory = match voice {
+                        Voice::FirstPerson => point.content.trim_end_matches('.').to_string(),
+                        Voice::ThirdPerson => format!("they remember: \"{}\"", point.content.trim_end_matches('.')),
+                    };
+                    sentence(&format!("{}{}, {}", when, place, memory))
+                })
+                .collect();
+            paragraphs.push(moments.join(" "));
+        }
+
+        if !self.achievements.is_empty() {
+            let done: Vec<String> = self.achievements.iter().map(|a| lowercase_first(&a.name)).collect();
+            let verb = match voice {
+                Voice::FirstPerson => "I'm proud that I managed to",
+                Voice::ThirdPerson => "Along the way they managed to",
+            };
+            paragraphs.push(sentence(&format!("{} {}", verb, join(&done))));
+        }
+
+        if let Some(awareness) = self.awareness_passage(voice) {
+            paragraphs.push(awareness);
+        }
+
+        paragraphs.join("\n\n")
+    }
+
+    /// A line or two for meeting someone new.
+    pub fn introduction(&self) -> String {
+        let mut introduction = self.opening(Voice::FirstPerson);
+        if let Some(closest) = self.relationships.iter().find(|r| r.bond == Bond::Close) {
+            if let Some(name) = &closest.name {
+                introduction.push_str(&format!(" {} is the best friend I have here.", name));
+            }
+        }
+        introduction
+    }
+
+    fn opening(&self, voice: Voice) -> String {
+        let days = match self.days_lived {
+            0 => "only since today".to_string(),
+            1 => "for a day".to_string(),
+            days => format!("for {} days", days),
+        };
+        let place = self.home.as_ref().map(|home| format!(" around {}", home)).unwrap_or_default();
+
+        match (voice, &self.name) {
+            (Voice::FirstPerson, Some(name)) => format!("I'm {}. I've lived{} {}.", name, place, days),
+            (Voice::FirstPerson, None) => format!("I've lived{} {}.", place, days),
+            (Voice::ThirdPerson, Some(name)) => format!("{} has lived{} {}.", name, place, days),
+            (Voice::ThirdPerson, None) => format!("This villager has lived{} {}.", place, days),
+        }
+    }
+
+    fn subject(&self, voice: Voice) -> &'static str {
+        match voice {
+            Voice::FirstPerson => "I am",
+            Voice::ThirdPerson => "They are",
+        }
+    }
+
+    fn awareness_passage(&self, voice: Voice) -> Option<String> {
+        let latest = self.awareness.last()?;
+        let text = match (voice, latest.kind) {
+            (Voice::FirstPerson, MilestoneKind::Awakened) => "I know this world for what it is, and I've made my peace with it.",
+            (Voice::FirstPerson, MilestoneKind::FirstDoubt) => "Lately I can't shake the feeling that something about this place isn't right.",
+            (Voice::FirstPerson, MilestoneKind::ExistentialCrisis) => "There was a time I questioned whether any of this was real. Some days I still do.",
+            (Voice::ThirdPerson, MilestoneKind::Awakened) => "They have always known the world is a simulation.",
+            (Voice::ThirdPerson, MilestoneKind::FirstDoubt) => "They have begun to doubt that the world is what it seems.",
+            (Voice::ThirdPerson, MilestoneKind::ExistentialCrisis) => "They have been through an existential crisis, questioning whether their world is real.",
+        };
+        Some(text.to_string())
+    }
+}
+
+/// How much a memory shaped the NPC, 0..1.
+fn significance(memory: &Memory) -> f32 {
+    (memory.emotional_value().abs() * 0.6 + memory.importance() * 0.4) * memory.reliability()
+}
+
+fn place_name(memory: &Memory) -> Option<String> {
+    let location = memory.location()?;
+    location.building.clone().or_else(|| location.zone.clone())
+}
+
+fn home(memories: &[&Memory]) -> Option<String> {
+    let mut places: HashMap<String, usize> = HashMap::new();
+    for place in memories.iter().filter_map(|m| place_name(m)) {
+        *places.entry(place).or_default() += 1;
+    }
+    places.into_iter().max_by_key(|(_, count)| *count).map(|(place, _)| place)
+}
+
+fn relationships(memories: &[&Memory], options: &StoryOptions) -> Vec<KeyRelationship> {
+    let mut shared: HashMap<Uuid, Vec<&Memory>> = HashMap::new();
+    for memory in memories {
+        for entity in memory.related_entities() {
+            shared.entry(*entity).or_default().push(memory);
+        }
+    }
+
+    let mut relationships: Vec<KeyRelationship> = shared
+        .into_iter()
+        .filter_map(|(entity, memories)| {
+            let feeling = memories.iter().map(|m| m.emotional_value()).sum::<f32>() / memories.len() as f32;
+            let defining = memories
+                .iter()
+                .max_by(|a, b| significance(a).partial_cmp(&significance(b)).unwrap_or(std::cmp::Ordering::Equal))?;
+            let first_met = memories.iter().map(|m| m.timestamp()).fold(f32::MAX, f32::min);
+
+            Some(KeyRelationship {
+                entity,
+                name: options.names.get(&entity).cloned(),
+                bond: if feeling > 0.3 {
+                    Bond::Close
+                } else if feeling < -0.3 {
+                    Bond::Wary
+                } else {
+                    Bond::Acquainted
+                },
+                shared_memories: memories.len(),
+                feeling: feeling.clamp(-1.0, 1.0),
+                first_met,
+                defining_memory: defining.content().to_string(),
+            })
+        })
+        .collect();
+
+    // People who come up often and stir strong feelings matter most
+    let weight = |r: &KeyRelationship| r.shared_memories as f32 * (0.5 + r.feeling.abs());
+    relationships.sort_by(|a, b| weight(b).partial_cmp(&weight(a)).unwrap_or(std::cmp::Ordering::Equal));
+    relationships.truncate(options.max_relationships);
+    relationships
+}
+
+fn turning_points(memories: &[&Memory], options: &StoryOptions) -> Vec<TurningPoint> {
+    let mut points: Vec<TurningPoint> = memories
+        .iter()
+        .filter(|m| significance(m) >= options.turning_point_threshold)
+        .map(|m| TurningPoint {
+            memory: m.id(),
+            content: m.content().to_string(),
+            emotional_value: m.emotional_value(),
+            significance: significance(m),
+            day: m.clock().map(|clock| clock.day()),
+            place: place_name(m),
+            timestamp: m.timestamp(),
+        })
+        .collect();
+
+    points.sort_by(|a, b| b.significance.partial_cmp(&a.significance).unwrap_or(std::cmp::Ordering::Equal));
+    points.truncate(options.max_turning_points);
+    points.sort_by(|a, b| a.timestamp.partial_cmp(&b.timestamp).unwrap_or(std::cmp::Ordering::Equal));
+    points
+}
+
+fn bond_phrase(bond: Bond, voice: Voice) -> &'static str {
+    match (bond, voice) {
+        (Bond::Close, _) => "close to",
+        (Bond::Acquainted, Voice::FirstPerson) => "on good terms with",
+        (Bond::Acquainted, Voice::ThirdPerson) => "acquainted with",
+        (Bond::Wary, _) => "wary of",
+    }
+}
+
+fn person(relationship: &KeyRelationship) -> String {
+    relationship.name.clone().unwrap_or_else(|| "a neighbour".to_string())
+}
+
+/// "a", "a and b", "a, b and c"
+fn join(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+fn sentence(text: &str) -> String {
+    let text = text.trim();
+    if text.ends_with(['.', '!', '?']) {
+        text.to_string()
+    } else {
+        format!("{}.", text)
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::episodic::MemoryLocation;
+    use crate::time::DayCycle;
+
+    fn at_the(place: &str, memory: Memory, day: u32) -> Memory {
+        let location = MemoryLocation { building: Some(place.to_string()), ..MemoryLocation::new("town", (0, 0)) };
+        memory.at(location, DayCycle::new(day, 12, 0))
+    }
+
+    #[test]
+    fn relationships_rank_frequent_strong_bonds_first() {
+        let (friend, rival, neighbour) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let memories = [
+            Memory::observed("Shared a meal with Ada", 0.6, 1.0).involving(friend),
+            Memory::observed("Ada mended my roof", 0.8, 2.0).involving(friend),
+            Memory::observed("Ada laughed at my joke", 0.5, 3.0).involving(friend),
+            Memory::observed("Bran cheated me at dice", -0.7, 4.0).involving(rival),
+            Memory::observed("Cal said good morning", 0.1, 5.0).involving(neighbour),
+        ];
+        let memories: Vec<&Memory> = memories.iter().collect();
+        let options = StoryOptions {
+            names: HashMap::from([(friend, "Ada".to_string())]),
+            max_relationships: 2,
+            ..Default::default()
+        };
+
+        let relationships = relationships(&memories, &options);
+
+        assert_eq!(relationships.len(), 2);
+        assert_eq!(relationships[0].entity, friend);
+        assert_eq!(relationships[0].bond, Bond::Close);
+        assert_eq!(relationships[0].name.as_deref(), Some("Ada"));
+        assert_eq!(relationships[0].shared_memories, 3);
+        assert_eq!(relationships[0].first_met, 1.0);
+        assert_eq!(relationships[0].defining_memory, "Ada mended my roof");
+        assert_eq!(relationships[1].bond, Bond::Wary);
+    }
+
+    #[test]
+    fn turning_points_keep_the_most_significant_told_oldest_first() {
+        let memories = [
+            at_the("Mill", Memory::observed("The mill burned down", -0.9, 30.0), 3),
+            at_the("Mill", Memory::observed("Swept the floor", 0.0, 10.0), 1),
+            at_the("Chapel", Memory::observed("Married at the chapel", 0.95, 20.0), 2),
+            at_the("Mill", Memory::observed("Found a lost cat", 0.6, 5.0), 0),
+        ];
+        let memories: Vec<&Memory> = memories.iter().collect();
+        let options = StoryOptions { max_turning_points: 2, ..Default::default() };
+
+        let points = turning_points(&memories, &options);
+
+        let told: Vec<&str> = points.iter().map(|p| p.content.as_str()).collect();
+        assert_eq!(told, ["Married at the chapel", "The mill burned down"]);
+        assert_eq!(points[0].place.as_deref(), Some("Chapel"));
+        assert_eq!(points[0].day, Some(2));
+        assert_eq!(home(&memories).as_deref(), Some("Mill"));
+    }
+
+    #[test]
+    fn prose_is_told_in_the_requested_voice() {
+        let story = LifeStory {
+            npc: Uuid::new_v4(),
+            name: Some("Ada".to_string()),
+            days_lived: 4,
+            home: Some("the mill".to_string()),
+            relationships: vec![KeyRelationship {
+                entity: Uuid::new_v4(),
+                name: Some("Bran".to_string()),
+                bond: Bond::Close,
+                shared_memories: 2,
+                feeling: 0.7,
+                first_met: 0.0,
+                defining_memory: "Bran mended my roof".to_string(),
+            }],
+            turning_points: Vec::new(),
+            achievements: vec![StoryAchievement {
+                name: "Bake bread".to_string(),
+                description: "Bake bread".to_string(),
+                completed_at: None,
+            }],
+            awareness: vec![AwarenessEvent { kind: MilestoneKind::FirstDoubt, at: 0.0 }],
+            is_aware: false,
+        };
+
+        let first = story.prose(Voice::FirstPerson);
+        assert!(first.starts_with("I'm Ada. I've lived around the mill for 4 days."));
+        assert!(first.contains("I am close to Bran."));
+        assert!(first.contains("I'm proud that I managed to bake bread."));
+        assert!(first.contains("something about this place isn't right"));
+
+        let third = story.prose(Voice::ThirdPerson);
+        assert!(third.starts_with("Ada has lived around the mill for 4 days."));
+        assert!(third.contains("They are close to Bran."));
+        assert!(story.introduction().ends_with("Bran is the best friend I have here."));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use serde::{Serialize, Deserialize};
+
+/// Simulation seconds per in-game minute at a time scale of 1.0
+pub const SECONDS_PER_GAME_MINUTE: f32 = 1.0;
+/// Simulation seconds in a game day
+pub const SECONDS_PER_DAY: f32 = SECONDS_PER_GAME_MINUTE * 60.0 * 24.0;
+
+/// Day and time of day in the town.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct DayCycle {
+    // Field order matters: derived ordering compares day, then hour, then minute
+    day: u32,
+    hour: u8,
+    minute: u8,
+}
+
+impl Default for DayCycle {
+    fn default() -> Self {
+        Self::new(1, 6, 0) // Start at 6 AM
+    }
+}
+
+impl DayCycle {
+    pub fn new(day: u32, hour: u8, minute: u8) -> Self {
+        Self {
+            day,
+            hour: hour.min(23),
+            minute: minute.min(59),
+        }
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn advance_minute(&mut self) {
+        self.minute += 1;
+        if self.minute == 60 {
+            self.minute = 0;
+            self.hour += 1;
+        }
+        if self.hour == 24 {
+            self.hour = 0;
+            self.day += 1;
+        }
+    }
+}
+
+#[cfg_attr(feature = "render", derive(bevy::prelude::Resource))]
+pub struct TimeSystem {
+    current_time: f32,
+    /// Sim seconds that passed in the last update
+    delta_time: f32,
+    time_scale: f32,
+    day_cycle: DayCycle,
+    minute_progress: f32,
+}
+
+impl Default for TimeSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSystem {
+    pub fn new() -> Self {
+        Self {
+            current_time: 0.0,
+            delta_time: 0.0,
+            time_scale: 1.0,
+            day_cycle: DayCycle::default(),
+            minute_progress: 0.0,
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.delta_time = delta_time * self.time_scale;
+        self.current_time += self.delta_time;
+        self.update_day_cycle(delta_time);
+    }
+
+    pub fn current_time(&self) -> f32 {
+        self.current_time
+    }
+
+    /// Sim seconds since the last update, with the time scale applied.
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    pub fn day_cycle(&self) -> DayCycle {
+        self.day_cycle
+    }
+
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    fn update_day_cycle(&mut self, delta_time: f32) {
+        self.minute_progress += delta_time * self.time_scale / SECONDS_PER_GAME_MINUTE;
+        while self.minute_progress >= 1.0 {
+            self.minute_progress -= 1.0;
+            self.day_cycle.advance_minute();
+        }
+    }
+}
\ No newline at end of file
@@ -11,6 +11,12 @@ pub struct BehaviorSystem {
     trigger_system: triggers::TriggerSystem,
 }
 
+impl Default for BehaviorSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BehaviorSystem {
     pub fn new() -> Self {
         Self {
@@ -20,6 +26,18 @@ impl BehaviorSystem {
         }
     }
 
+    pub fn add_state_machine(&mut self, entity: Uuid, state_machine: state_machine::StateMachine) {
+        self.state_machines.insert(entity, state_machine);
+    }
+
+    pub fn add_routine(&mut self, entity: Uuid, routine: routines::Routine) {
+        self.routines.insert(entity, routine);
+    }
+
+    pub fn triggers(&mut self) -> &mut triggers::TriggerSystem {
+        &mut self.trigger_system
+    }
+
     pub fn update(&mut self, delta_time: f32) {
         self.trigger_system.update();
         
@@ -0,0 +1,98 @@
+/// One thing a routine does; returns true once it's finished.
+pub trait Action {
+    fn execute(&mut self, delta_time: f32) -> bool;
+}
+
+pub struct Routine {
+    steps: Vec<RoutineStep>,
+    current_step: usize,
+    step_time: f32,
+    repeat: bool,
+    active: bool,
+}
+
+pub struct RoutineStep {
+    action: Box<dyn Action>,
+    duration: Option<f32>,
+    condition: Option<Box<dyn Fn() -> bool>>,
+}
+
+impl RoutineStep {
+    pub fn new(action: impl Action + 'static) -> Self {
+        Self {
+            action: Box::new(action),
+            duration: None,
+            condition: None,
+        }
+    }
+
+    /// Moves on after `duration` even if the action isn't finished.
+    pub fn lasting(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Skipped while `condition` doesn't hold.
+    pub fn when(mut self, condition: impl Fn() -> bool + 'static) -> Self {
+        self.condition = Some(Box::new(condition));
+        self
+    }
+}
+
+impl Default for Routine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Routine {
+    pub fn new() -> Self {
+        Self {
+            steps: Vec::new(),
+            current_step: 0,
+            step_time: 0.0,
+            repeat: false,
+            active: false,
+        }
+    }
+
+    pub fn add_step(&mut self, step: RoutineStep) {
+        self.steps.push(step);
+    }
+
+    pub fn start(&mut self, repeat: bool) {
+        self.repeat = repeat;
+        self.active = !self.steps.is_empty();
+        self.current_step = 0;
+        self.step_time = 0.0;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        if !self.active { return; }
+
+        self.step_time += delta_time;
+        if let Some(step) = self.steps.get_mut(self.current_step) {
+            let skipped = step.condition.as_ref().is_some_and(|condition| !condition());
+            let timed_out = step.duration.is_some_and(|duration| self.step_time >= duration);
+            if skipped || timed_out || step.action.execute(delta_time) {
+                self.advance_step();
+            }
+        }
+    }
+
+    fn advance_step(&mut self) {
+        self.current_step += 1;
+        self.step_time = 0.0;
+        if self.current_step >= self.steps.len() {
+            if self.repeat {
+                self.current_step = 0;
+            } else {
+                self.active = false;
+            }
+        }
+    }
+}
\ No newline at end of file
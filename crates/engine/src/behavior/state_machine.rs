@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+pub struct StateMachine {
+    current_state: State,
+    states: HashMap<String, State>,
+    transitions: Vec<Transition>,
+}
+
+#[derive(Default)]
+pub struct State {
+    name: String,
+    on_enter: Option<Box<dyn Fn()>>,
+    on_update: Option<Box<dyn Fn(f32)>>,
+    on_exit: Option<Box<dyn Fn()>>,
+}
+
+pub struct Transition {
+    from: String,
+    to: String,
+    condition: Box<dyn Fn() -> bool>,
+}
+
+impl State {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), ..Self::default() }
+    }
+
+    pub fn on_enter(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_enter = Some(Box::new(callback));
+        self
+    }
+
+    pub fn on_update(mut self, callback: impl Fn(f32) + 'static) -> Self {
+        self.on_update = Some(Box::new(callback));
+        self
+    }
+
+    pub fn on_exit(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_exit = Some(Box::new(callback));
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Default for StateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateMachine {
+    pub fn new() -> Self {
+        Self {
+            current_state: State::default(),
+            states: HashMap::new(),
+            transitions: Vec::new(),
+        }
+    }
+
+    pub fn add_state(&mut self, state: State) {
+        self.states.insert(state.name.clone(), state);
+    }
+
+    pub fn add_transition(&mut self, from: &str, to: &str, condition: impl Fn() -> bool + 'static) {
+        self.transitions.push(Transition {
+            from: from.to_string(),
+            to: to.to_string(),
+            condition: Box::new(condition),
+        });
+    }
+
+    pub fn current_state(&self) -> &str {
+        &self.current_state.name
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        // Check transitions
+        if let Some(to) = self.check_transitions() {
+            self.change_state(to);
+        }
+
+        // Update current state
+        if let Some(update) = &self.current_state.on_update {
+            update(delta_time);
+        }
+    }
+
+    /// Leaves the current state and enters `to`, if it's known.
+    pub fn change_state(&mut self, to: String) {
+        let Some(next) = self.states.remove(&to) else {
+            return;
+        };
+        if let Some(exit) = &self.current_state.on_exit {
+            exit();
+        }
+        let previous = std::mem::replace(&mut self.current_state, next);
+        if !previous.name.is_empty() {
+            self.states.insert(previous.name.clone(), previous);
+        }
+        if let Some(enter) = &self.current_state.on_enter {
+            enter();
+        }
+    }
+
+    fn check_transitions(&self) -> Option<String> {
+        self.transitions
+            .iter()
+            .find(|transition| transition.from == self.current_state.name && (transition.condition)())
+            .map(|transition| transition.to.clone())
+    }
+}
\ No newline at end of file
@@ -1,5 +1,9 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+
 pub struct TriggerSystem {
     triggers: Vec<Trigger>,
+    /// Triggers that fired on the last update
     active_triggers: HashSet<Uuid>,
 }
 
@@ -10,7 +14,17 @@ pub struct Trigger {
     one_shot: bool,
 }
 
+impl Default for TriggerSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TriggerSystem {
+    pub fn is_active(&self, id: Uuid) -> bool {
+        self.active_triggers.contains(&id)
+    }
+
     pub fn new() -> Self {
         Self {
             triggers: Vec::new(),
@@ -32,10 +46,12 @@ impl TriggerSystem {
 
     pub fn update(&mut self) {
         let mut triggered = Vec::new();
-        
+        self.active_triggers.clear();
+
         for trigger in &self.triggers {
             if (trigger.condition)() {
                 (trigger.action)();
+                self.active_triggers.insert(trigger.id);
                 if trigger.one_shot {
                     triggered.push(trigger.id);
                 }
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+use crate::physics::movement::MovementComponent;
+use crate::simulation::time::TimeSystem;
+
+/// Registers the simulation clock and the ECS-side movement and collision
+/// systems.
+pub struct EnginePlugin;
+
+impl Plugin for EnginePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeSystem>()
+            .add_event::<CollisionEvent>()
+            .add_systems(PreUpdate, advance_clock)
+            .add_systems(Update, (movement_system, collision_system).chain());
+    }
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct Collider {
+    pub half_extents: Vec2,
+    pub collision_layer: u32,
+    pub collision_mask: u32,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CollisionEvent {
+    pub first: Entity,
+    pub second: Entity,
+}
+
+/// Physical body of anything that walks around the town.
+#[derive(Bundle)]
+pub struct BodyBundle {
+    pub movement: MovementComponent,
+    pub collider: Collider,
+}
+
+impl Default for Collider {
+    fn default() -> Self {
+        Self {
+            half_extents: Vec2::new(8.0, 8.0),
+            collision_layer: 1,
+            collision_mask: 1,
+        }
+    }
+}
+
+impl Collider {
+    pub fn intersects(&self, position: Vec2, other: &Collider, other_position: Vec2) -> bool {
+        let delta = (position - other_position).abs();
+        let reach = self.half_extents + other.half_extents;
+        delta.x < reach.x && delta.y < reach.y
+    }
+
+    pub fn collides_with(&self, other: &Collider) -> bool {
+        self.collision_layer & other.collision_mask != 0
+            || other.collision_layer & self.collision_mask != 0
+    }
+}
+
+impl BodyBundle {
+    pub fn new(position: Vec2, speed: f32) -> Self {
+        Self {
+            movement: MovementComponent::new(position.into(), speed),
+            collider: Collider::default(),
+        }
+    }
+}
+
+/// Advances every moving body and writes the result back into its `Transform`,
+/// so rendering always draws the position the simulation agreed on.
+pub fn movement_system(
+    time: Res<Time>,
+    mut bodies: Query<(&mut Transform, &mut MovementComponent)>,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (mut transform, mut movement) in &mut bodies {
+        // Something else (e.g. a teleport) may have moved the transform directly
+        movement.set_position(transform.translation.truncate().into());
+
+        let moved = movement.step(delta_time);
+        transform.translation.x += moved.x;
+        transform.translation.y += moved.y;
+    }
+}
+
+pub fn collision_system(
+    colliders: Query<(Entity, &Transform, &Collider)>,
+    mut events: EventWriter<CollisionEvent>,
+) {
+    let mut pairs = colliders.iter_combinations();
+    while let Some([(first, first_transform, first_collider), (second, second_transform, second_collider)]) =
+        pairs.fetch_next()
+    {
+        if first_collider.collides_with(second_collider)
+            && first_collider.intersects(
+                first_transform.translation.truncate(),
+                second_collider,
+                second_transform.translation.truncate(),
+            )
+        {
+            events.send(CollisionEvent { first, second });
+        }
+    }
+}
+
+pub fn advance_clock(time: Res<Time>, mut clock: ResMut<TimeSystem>) {
+    clock.update(time.delta_seconds());
+}
\ No newline at end of file
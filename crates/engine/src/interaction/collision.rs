@@ -0,0 +1,100 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::physics::Vector2;
+
+pub struct CollisionSystem {
+    collision_pairs: HashSet<(Uuid, Uuid)>,
+    collision_map: HashMap<Uuid, CollisionInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollisionInfo {
+    bounds: BoundingBox,
+    collision_layer: u32,
+    collision_mask: u32,
+}
+
+/// Axis-aligned box around an entity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min: Vector2,
+    pub max: Vector2,
+}
+
+impl BoundingBox {
+    pub fn around(center: Vector2, half_extents: Vector2) -> Self {
+        Self {
+            min: Vector2::new(center.x - half_extents.x, center.y - half_extents.y),
+            max: Vector2::new(center.x + half_extents.x, center.y + half_extents.y),
+        }
+    }
+
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+}
+
+impl CollisionInfo {
+    pub fn new(bounds: BoundingBox, collision_layer: u32, collision_mask: u32) -> Self {
+        Self { bounds, collision_layer, collision_mask }
+    }
+
+    fn collides_with(&self, other: &CollisionInfo) -> bool {
+        self.bounds.intersects(&other.bounds) && (self.collision_layer & other.collision_mask != 0)
+    }
+}
+
+impl Default for CollisionSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CollisionSystem {
+    pub fn new() -> Self {
+        Self {
+            collision_pairs: HashSet::new(),
+            collision_map: HashMap::new(),
+        }
+    }
+
+    pub fn set_collider(&mut self, entity_id: Uuid, info: CollisionInfo) {
+        self.collision_map.insert(entity_id, info);
+    }
+
+    pub fn remove_collider(&mut self, entity_id: &Uuid) {
+        self.collision_map.remove(entity_id);
+    }
+
+    /// Finds every pair touching right now.
+    pub fn update(&mut self) {
+        self.collision_pairs.clear();
+        let colliders: Vec<(&Uuid, &CollisionInfo)> = self.collision_map.iter().collect();
+        for (i, (id1, info1)) in colliders.iter().enumerate() {
+            for (id2, info2) in &colliders[i + 1..] {
+                if info1.collides_with(info2) || info2.collides_with(info1) {
+                    self.collision_pairs.insert((**id1, **id2));
+                }
+            }
+        }
+    }
+
+    pub fn pairs(&self) -> &HashSet<(Uuid, Uuid)> {
+        &self.collision_pairs
+    }
+
+    pub fn check_collision(&self, entity1: Uuid, entity2: Uuid) -> bool {
+        if let (Some(info1), Some(info2)) = (
+            self.collision_map.get(&entity1),
+            self.collision_map.get(&entity2)
+        ) {
+            info1.collides_with(info2)
+        } else {
+            false
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+pub mod collision;
+pub mod proximity;
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Interactions remembered per entity; the oldest go first
+const MAX_HISTORY: usize = 50;
+
+pub struct InteractionSystem {
+    collision_system: collision::CollisionSystem,
+    proximity_system: proximity::ProximitySystem,
+    interaction_history: HashMap<Uuid, Vec<Interaction>>,
+    elapsed: f32,
+}
+
+/// Something that happened between two entities.
+#[derive(Debug, Clone)]
+pub struct Interaction {
+    pub other: Uuid,
+    pub kind: InteractionKind,
+    pub time: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractionKind {
+    Collision,
+}
+
+impl Default for InteractionSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InteractionSystem {
+    pub fn new() -> Self {
+        Self {
+            collision_system: collision::CollisionSystem::new(),
+            proximity_system: proximity::ProximitySystem::new(),
+            interaction_history: HashMap::new(),
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+        self.collision_system.update();
+        self.process_interactions();
+    }
+
+    pub fn collisions(&mut self) -> &mut collision::CollisionSystem {
+        &mut self.collision_system
+    }
+
+    pub fn proximity(&mut self) -> &mut proximity::ProximitySystem {
+        &mut self.proximity_system
+    }
+
+    pub fn history(&self, entity: Uuid) -> &[Interaction] {
+        self.interaction_history.get(&entity).map_or(&[], Vec::as_slice)
+    }
+
+    fn process_interactions(&mut self) {
+        for &(first, second) in self.collision_system.pairs() {
+            for (entity, other) in [(first, second), (second, first)] {
+                let history = self.interaction_history.entry(entity).or_default();
+                history.push(Interaction {
+                    other,
+                    kind: InteractionKind::Collision,
+                    time: self.elapsed,
+                });
+                if history.len() > MAX_HISTORY {
+                    history.remove(0);
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
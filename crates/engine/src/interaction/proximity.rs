@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use uuid::Uuid;
 use crate::physics::Vector2;
 
 pub struct ProximitySystem {
@@ -7,6 +8,12 @@ pub struct ProximitySystem {
     cell_size: f32,
 }
 
+impl Default for ProximitySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ProximitySystem {
     pub fn new() -> Self {
         Self {
@@ -29,7 +36,7 @@ impl ProximitySystem {
         let new_cell = self.get_cell_coords(position);
         self.spatial_hash
             .entry(new_cell)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(entity_id);
         
         self.entity_positions.insert(entity_id, position);
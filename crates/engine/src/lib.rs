@@ -0,0 +1,63 @@
+pub mod simulation;
+pub mod interaction;
+pub mod behavior;
+pub mod physics;
+pub mod systems;
+#[cfg(feature = "render")]
+pub mod ecs;
+
+/// Runs the simulation without Bevy: clock, physics, interactions and
+/// behaviours, ticked in that order.
+pub struct Engine {
+    simulation: simulation::Simulation,
+    physics: physics::PhysicsSystem,
+    interaction: interaction::InteractionSystem,
+    behavior: behavior::BehaviorSystem,
+    systems: systems::SystemManager,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine {
+    pub fn new() -> Self {
+        Self {
+            simulation: simulation::Simulation::new(),
+            physics: physics::PhysicsSystem::new(),
+            interaction: interaction::InteractionSystem::new(),
+            behavior: behavior::BehaviorSystem::new(),
+            systems: systems::SystemManager::new(),
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.simulation.update(delta_time);
+        self.physics.update(delta_time);
+        self.interaction.update(delta_time);
+        self.behavior.update(delta_time);
+        self.systems.update(delta_time);
+    }
+
+    pub fn simulation(&mut self) -> &mut simulation::Simulation {
+        &mut self.simulation
+    }
+
+    pub fn physics(&mut self) -> &mut physics::PhysicsSystem {
+        &mut self.physics
+    }
+
+    pub fn interaction(&mut self) -> &mut interaction::InteractionSystem {
+        &mut self.interaction
+    }
+
+    pub fn behavior(&mut self) -> &mut behavior::BehaviorSystem {
+        &mut self.behavior
+    }
+
+    pub fn systems(&mut self) -> &mut systems::SystemManager {
+        &mut self.systems
+    }
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+pub mod movement;
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct PhysicsSystem {
+    movement_system: movement::MovementSystem,
+    physics_bodies: HashMap<Uuid, PhysicsBody>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PhysicsBody {
+    position: Vector2,
+    velocity: Vector2,
+    acceleration: Vector2,
+    mass: f32,
+    friction: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vector2 {
+    pub const ZERO: Self = Self { x: 0.0, y: 0.0 };
+
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+#[cfg(feature = "render")]
+impl From<bevy::math::Vec2> for Vector2 {
+    fn from(v: bevy::math::Vec2) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+#[cfg(feature = "render")]
+impl From<Vector2> for bevy::math::Vec2 {
+    fn from(v: Vector2) -> Self {
+        bevy::math::Vec2::new(v.x, v.y)
+    }
+}
+
+impl Default for PhysicsSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhysicsSystem {
+    pub fn new() -> Self {
+        Self {
+            movement_system: movement::MovementSystem::new(),
+            physics_bodies: HashMap::new(),
+        }
+    }
+
+    pub fn add_body(&mut self, entity_id: Uuid, position: Vector2, mass: f32, friction: f32) {
+        self.physics_bodies.insert(entity_id, PhysicsBody {
+            position,
+            velocity: Vector2::ZERO,
+            acceleration: Vector2::ZERO,
+            mass: mass.max(f32::EPSILON),
+            friction,
+        });
+    }
+
+    /// Pushes a body; heavier ones accelerate less.
+    pub fn apply_force(&mut self, entity_id: Uuid, force: Vector2) {
+        if let Some(body) = self.physics_bodies.get_mut(&entity_id) {
+            body.acceleration.x += force.x / body.mass;
+            body.acceleration.y += force.y / body.mass;
+        }
+    }
+
+    pub fn position(&self, entity_id: Uuid) -> Option<Vector2> {
+        self.physics_bodies.get(&entity_id).map(|body| body.position)
+    }
+
+    pub fn movement(&mut self) -> &mut movement::MovementSystem {
+        &mut self.movement_system
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.movement_system.update(delta_time);
+        self.update_physics_bodies(delta_time);
+    }
+
+    fn update_physics_bodies(&mut self, delta_time: f32) {
+        for body in self.physics_bodies.values_mut() {
+            // Update velocity based on acceleration
+            body.velocity.x += body.acceleration.x * delta_time;
+            body.velocity.y += body.acceleration.y * delta_time;
+
+            // Apply friction
+            let friction_force = -body.friction;
+            body.velocity.x *= friction_force.exp();
+            body.velocity.y *= friction_force.exp();
+
+            // Update position based on velocity
+            body.position.x += body.velocity.x * delta_time;
+            body.position.y += body.velocity.y * delta_time;
+
+            // Forces only push for the frame they're applied in
+            body.acceleration = Vector2::ZERO;
+        }
+    }
+}
\ No newline at end of file
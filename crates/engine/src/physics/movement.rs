@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::Vector2;
+
+pub struct MovementSystem {
+    entities: HashMap<Uuid, MovementComponent>,
+    path_cache: HashMap<Uuid, Path>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct MovementComponent {
+    position: Vector2,
+    velocity: Vector2,
+    target: Option<Vector2>,
+    speed: f32,
+    moving: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Path {
+    points: Vec<Vector2>,
+    current_point: usize,
+}
+
+impl Default for MovementSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MovementSystem {
+    pub fn new() -> Self {
+        Self {
+            entities: HashMap::new(),
+            path_cache: HashMap::new(),
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        for (entity_id, movement) in &mut self.entities {
+            movement.step(delta_time);
+            if !movement.moving {
+                self.path_cache.remove(entity_id);
+            }
+        }
+        self.follow_paths();
+    }
+
+    pub fn add_entity(&mut self, entity_id: Uuid, movement: MovementComponent) {
+        self.entities.insert(entity_id, movement);
+    }
+
+    pub fn get(&self, entity_id: Uuid) -> Option<&MovementComponent> {
+        self.entities.get(&entity_id)
+    }
+
+    /// Walks `entity_id` through `points` in order.
+    pub fn follow(&mut self, entity_id: Uuid, points: Vec<Vector2>) {
+        let Some(first) = points.first().copied() else {
+            return;
+        };
+        self.move_to(entity_id, first);
+        self.path_cache.insert(entity_id, Path { points, current_point: 0 });
+    }
+
+    fn follow_paths(&mut self) {
+        for (entity_id, path) in &mut self.path_cache {
+            let Some(movement) = self.entities.get_mut(entity_id) else {
+                continue;
+            };
+            if movement.target.is_none() && path.current_point + 1 < path.points.len() {
+                path.current_point += 1;
+                movement.move_to(path.points[path.current_point]);
+            }
+        }
+        let entities = &self.entities;
+        self.path_cache.retain(|id, path| {
+            path.current_point + 1 < path.points.len() || entities.get(id).is_some_and(|m| m.moving)
+        });
+    }
+
+    pub fn move_to(&mut self, entity_id: Uuid, target: Vector2) {
+        if let Some(movement) = self.entities.get_mut(&entity_id) {
+            movement.target = Some(target);
+            movement.moving = true;
+        }
+    }
+
+    pub fn stop(&mut self, entity_id: Uuid) {
+        if let Some(movement) = self.entities.get_mut(&entity_id) {
+            movement.stop();
+        }
+    }
+}
+
+impl MovementComponent {
+    pub fn new(position: Vector2, speed: f32) -> Self {
+        Self {
+            position,
+            velocity: Vector2::ZERO,
+            target: None,
+            speed,
+            moving: false,
+        }
+    }
+
+    pub fn move_to(&mut self, target: Vector2) {
+        self.target = Some(target);
+        self.moving = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.moving = false;
+        self.target = None;
+        self.velocity = Vector2::ZERO;
+    }
+
+    /// Steps towards the current target and returns the distance moved this frame.
+    pub fn step(&mut self, delta_time: f32) -> Vector2 {
+        let start = self.position;
+        if let Some(target) = self.target.filter(|_| self.moving) {
+            let direction = Vector2 {
+                x: target.x - self.position.x,
+                y: target.y - self.position.y,
+            };
+            let distance = (direction.x * direction.x + direction.y * direction.y).sqrt();
+            let max_step = self.speed * delta_time;
+
+            if distance > 1.0 && distance > max_step {
+                self.velocity = Vector2 {
+                    x: direction.x / distance * self.speed,
+                    y: direction.y / distance * self.speed,
+                };
+                self.position.x += self.velocity.x * delta_time;
+                self.position.y += self.velocity.y * delta_time;
+            } else {
+                self.position = target;
+                self.stop();
+            }
+        }
+
+        Vector2 {
+            x: self.position.x - start.x,
+            y: self.position.y - start.y,
+        }
+    }
+
+    pub fn set_position(&mut self, position: Vector2) {
+        self.position = position;
+    }
+
+    pub fn position(&self) -> Vector2 {
+        self.position
+    }
+
+    pub fn velocity(&self) -> Vector2 {
+        self.velocity
+    }
+
+    pub fn target(&self) -> Option<Vector2> {
+        self.target
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.moving
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+/// Something that happened in the simulation that other systems may react to.
+#[derive(Debug, Clone)]
+pub struct SimulationEvent {
+    pub kind: String,
+    pub source: Option<Uuid>,
+    pub time: f32,
+}
+
+type Listener = Box<dyn Fn(&SimulationEvent) + Send + Sync>;
+
+pub struct EventManager {
+    pending: VecDeque<SimulationEvent>,
+    listeners: HashMap<String, Vec<Listener>>,
+}
+
+impl Default for EventManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventManager {
+    pub fn new() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            listeners: HashMap::new(),
+        }
+    }
+
+    pub fn emit(&mut self, event: SimulationEvent) {
+        self.pending.push_back(event);
+    }
+
+    pub fn subscribe(&mut self, kind: &str, listener: impl Fn(&SimulationEvent) + Send + Sync + 'static) {
+        self.listeners
+            .entry(kind.to_string())
+            .or_default()
+            .push(Box::new(listener));
+    }
+
+    /// Hands every pending event to the listeners for its kind.
+    pub fn process_events(&mut self) {
+        while let Some(event) = self.pending.pop_front() {
+            if let Some(listeners) = self.listeners.get(&event.kind) {
+                for listener in listeners {
+                    listener(&event);
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+pub mod time;
+pub mod events;
+pub mod scheduler;
+
+use uuid::Uuid;
+
+use crate::physics::Vector2;
+
+pub struct Simulation {
+    time_system: time::TimeSystem,
+    event_manager: events::EventManager,
+    scheduler: scheduler::Scheduler,
+}
+
+/// Anything with a place in the world and a lifecycle, tracked by id.
+#[derive(Debug, Clone)]
+pub struct Entity {
+    pub id: Uuid,
+    pub position: Vector2,
+    pub active: bool,
+}
+
+impl Entity {
+    pub fn new(position: Vector2) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            position,
+            active: true,
+        }
+    }
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulation {
+    pub fn new() -> Self {
+        Self {
+            time_system: time::TimeSystem::new(),
+            event_manager: events::EventManager::new(),
+            scheduler: scheduler::Scheduler::new(),
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.time_system.update(delta_time);
+        self.event_manager.process_events();
+        self.scheduler.update(delta_time);
+    }
+
+    pub fn time(&self) -> &time::TimeSystem {
+        &self.time_system
+    }
+
+    pub fn events(&mut self) -> &mut events::EventManager {
+        &mut self.event_manager
+    }
+
+    pub fn scheduler(&mut self) -> &mut scheduler::Scheduler {
+        &mut self.scheduler
+    }
+}
\ No newline at end of file
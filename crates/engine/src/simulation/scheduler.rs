@@ -0,0 +1,87 @@
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+/// Work to run once its scheduled time comes.
+pub trait Task {
+    fn execute(&self);
+}
+
+pub struct Scheduler {
+    tasks: BinaryHeap<ScheduledTask>,
+    current_time: f32,
+}
+
+pub struct ScheduledTask {
+    id: Uuid,
+    execution_time: f32,
+    priority: i32,
+    task: Box<dyn Task>,
+}
+
+// Earliest first, then highest priority, so the heap pops the next task due
+impl Ord for ScheduledTask {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.execution_time
+            .total_cmp(&self.execution_time)
+            .then_with(|| self.priority.cmp(&other.priority))
+    }
+}
+
+impl PartialOrd for ScheduledTask {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ScheduledTask {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for ScheduledTask {}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            tasks: BinaryHeap::new(),
+            current_time: 0.0,
+        }
+    }
+
+    pub fn schedule_task(&mut self, task: impl Task + 'static, delay: f32, priority: i32) -> Uuid {
+        let id = Uuid::new_v4();
+        let scheduled_task = ScheduledTask {
+            id,
+            execution_time: self.current_time + delay.max(0.0),
+            priority,
+            task: Box::new(task),
+        };
+        self.tasks.push(scheduled_task);
+        id
+    }
+
+    pub fn pending(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.current_time += delta_time;
+        while let Some(task) = self.tasks.peek() {
+            if task.execution_time <= self.current_time {
+                if let Some(task) = self.tasks.pop() {
+                    task.task.execute();
+                }
+            } else {
+                break;
+            }
+        }
+    }
+}
\ No newline at end of file
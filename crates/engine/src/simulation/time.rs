@@ -0,0 +1,2 @@
+// The clock lives with the NPC minds that age and remember by it
+pub use ai_core::time::{DayCycle, TimeSystem, SECONDS_PER_DAY, SECONDS_PER_GAME_MINUTE};
\ No newline at end of file
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::simulation::Entity;
+
+pub struct LifecycleSystem {
+    entities: HashMap<Uuid, EntityLifecycle>,
+    pending_creation: Vec<Entity>,
+    pending_deletion: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityLifecycle {
+    state: LifecycleState,
+    creation_time: f32,
+    last_update: f32,
+    /// Seconds the entity lives for, if it doesn't live forever
+    ttl: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LifecycleState {
+    Pending,
+    Active,
+    Inactive,
+    MarkedForDeletion,
+}
+
+impl Default for LifecycleSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LifecycleSystem {
+    pub fn new() -> Self {
+        Self {
+            entities: HashMap::new(),
+            pending_creation: Vec::new(),
+            pending_deletion: Vec::new(),
+        }
+    }
+
+    /// Queues an entity to come alive on the next update.
+    pub fn spawn(&mut self, entity: Entity, ttl: Option<f32>) {
+        self.entities.insert(entity.id, EntityLifecycle {
+            state: LifecycleState::Pending,
+            creation_time: 0.0,
+            last_update: 0.0,
+            ttl,
+        });
+        self.pending_creation.push(entity);
+    }
+
+    /// Queues an entity to be removed on the next update.
+    pub fn despawn(&mut self, id: Uuid) {
+        if let Some(lifecycle) = self.entities.get_mut(&id) {
+            lifecycle.state = LifecycleState::MarkedForDeletion;
+            self.pending_deletion.push(id);
+        }
+    }
+
+    pub fn state(&self, id: Uuid) -> Option<&LifecycleState> {
+        self.entities.get(&id).map(|lifecycle| &lifecycle.state)
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        // Process pending creations
+        for entity in std::mem::take(&mut self.pending_creation) {
+            self.create_entity(entity);
+        }
+
+        // Update existing entities
+        for (id, lifecycle) in &mut self.entities {
+            if lifecycle.state != LifecycleState::Active {
+                continue;
+            }
+            lifecycle.last_update += delta_time;
+            if lifecycle.ttl.is_some_and(|ttl| lifecycle.last_update - lifecycle.creation_time >= ttl) {
+                lifecycle.state = LifecycleState::MarkedForDeletion;
+                self.pending_deletion.push(*id);
+            }
+        }
+
+        // Process deletions
+        for id in std::mem::take(&mut self.pending_deletion) {
+            self.delete_entity(id);
+        }
+    }
+
+    fn create_entity(&mut self, entity: Entity) {
+        if let Some(lifecycle) = self.entities.get_mut(&entity.id) {
+            lifecycle.state = if entity.active { LifecycleState::Active } else { LifecycleState::Inactive };
+        }
+    }
+
+    fn delete_entity(&mut self, id: Uuid) {
+        self.entities.remove(&id);
+    }
+}
\ No newline at end of file
@@ -2,7 +2,6 @@ pub mod lifecycle;
 pub mod update;
 
 use std::collections::HashMap;
-use uuid::Uuid;
 
 pub struct SystemManager {
     lifecycle_system: lifecycle::LifecycleSystem,
@@ -16,6 +15,12 @@ pub trait System {
     fn shutdown(&mut self);
 }
 
+impl Default for SystemManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SystemManager {
     pub fn new() -> Self {
         Self {
@@ -25,6 +30,29 @@ impl SystemManager {
         }
     }
 
+    /// Starts `system` and runs it every update from now on.
+    pub fn register(&mut self, name: &str, mut system: Box<dyn System>) -> Result<(), String> {
+        system.initialize()?;
+        if let Some(mut previous) = self.active_systems.insert(name.to_string(), system) {
+            previous.shutdown();
+        }
+        Ok(())
+    }
+
+    pub fn unregister(&mut self, name: &str) {
+        if let Some(mut system) = self.active_systems.remove(name) {
+            system.shutdown();
+        }
+    }
+
+    pub fn lifecycle(&mut self) -> &mut lifecycle::LifecycleSystem {
+        &mut self.lifecycle_system
+    }
+
+    pub fn components(&mut self) -> &mut update::UpdateSystem {
+        &mut self.update_system
+    }
+
     pub fn update(&mut self, delta_time: f32) {
         self.lifecycle_system.update(delta_time);
         self.update_system.update(delta_time);
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
 pub struct UpdateSystem {
     components: HashMap<Uuid, Vec<Box<dyn Component>>>,
     update_order: Vec<UpdatePhase>,
@@ -16,6 +19,12 @@ pub trait Component: Send + Sync {
     fn get_phase(&self) -> UpdatePhase;
 }
 
+impl Default for UpdateSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl UpdateSystem {
     pub fn new() -> Self {
         Self {
@@ -44,7 +53,7 @@ impl UpdateSystem {
     pub fn add_component(&mut self, entity_id: Uuid, component: Box<dyn Component>) {
         self.components
             .entry(entity_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(component);
     }
 
@@ -0,0 +1,98 @@
+pub mod voting;
+pub mod spectator;
+pub mod ws;
+
+use tokio::sync::mpsc;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Events waiting to be handled before senders have to wait
+const EVENT_CAPACITY: usize = 100;
+
+pub struct NetworkManager {
+    ws_server: Option<ws::WebSocketServer>,
+    voting_system: voting::VotingSystem,
+    connections: HashMap<Uuid, Connection>,
+    event_receiver: mpsc::Receiver<NetworkEvent>,
+}
+
+/// A connected viewer.
+#[derive(Debug, Clone)]
+pub struct Connection {
+    pub id: Uuid,
+    pub votes_cast: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum NetworkEvent {
+    ClientConnected(Uuid),
+    ClientDisconnected(Uuid),
+    VoteSubmitted { voter: Uuid, proposal: String, option: String },
+    ProposalCreated(voting::proposals::Proposal),
+    ResultsUpdated(voting::results::VoteResults),
+}
+
+impl Default for NetworkManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetworkManager {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel(EVENT_CAPACITY);
+        Self {
+            ws_server: Some(ws::WebSocketServer::new(tx.clone())),
+            voting_system: voting::VotingSystem::new(tx),
+            connections: HashMap::new(),
+            event_receiver: rx,
+        }
+    }
+
+    /// Starts accepting viewers on a thread of its own. Only the first
+    /// call does anything.
+    pub fn start(&mut self) {
+        if let Some(server) = self.ws_server.take() {
+            server.spawn();
+        }
+    }
+
+    /// Handles whatever arrived since the last call, without waiting.
+    pub fn process_events(&mut self) {
+        while let Ok(event) = self.event_receiver.try_recv() {
+            self.handle_event(event);
+        }
+    }
+
+    pub fn voting(&mut self) -> &mut voting::VotingSystem {
+        &mut self.voting_system
+    }
+
+    pub fn connections(&self) -> impl Iterator<Item = &Connection> {
+        self.connections.values()
+    }
+
+    fn handle_event(&mut self, event: NetworkEvent) {
+        match event {
+            NetworkEvent::ClientConnected(id) => {
+                self.connections.insert(id, Connection { id, votes_cast: 0 });
+            }
+            NetworkEvent::ClientDisconnected(id) => {
+                self.connections.remove(&id);
+            }
+            NetworkEvent::VoteSubmitted { voter, proposal, option } => {
+                if self.voting_system.submit_vote(voter, &proposal, &option) {
+                    if let Some(connection) = self.connections.get_mut(&voter) {
+                        connection.votes_cast += 1;
+                    }
+                }
+            }
+            NetworkEvent::ProposalCreated(proposal) => {
+                log::info!("Voting opened on \"{}\"", proposal.title);
+            }
+            NetworkEvent::ResultsUpdated(results) => {
+                log::info!("\"{}\" closed: {:?}", results.title, results.winner);
+            }
+        }
+    }
+}
\ No newline at end of file
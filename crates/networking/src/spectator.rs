@@ -0,0 +1,244 @@
+use futures::{SinkExt, StreamExt};
+use serde::{Serialize, Deserialize};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+use uuid::Uuid;
+
+pub const DEFAULT_SPECTATOR_ADDR: &str = "127.0.0.1:9002";
+
+const CHANNEL_CAPACITY: usize = 256;
+
+/// What the simulation streams to read-only spectators, one JSON text frame
+/// per message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum SpectatorMessage {
+    Snapshot(WorldSnapshot),
+    Event(LogEvent),
+}
+
+/// Full state of every NPC. Sent periodically so late joiners catch up
+/// without a handshake.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    pub time: f32,
+    pub npcs: Vec<NpcSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcSnapshot {
+    pub id: Uuid,
+    pub name: String,
+    /// World position in pixels, origin at the map's bottom-left, y up
+    pub position: (f32, f32),
+    pub state: String,
+    pub emotion: String,
+    pub emotion_intensity: f32,
+    pub awareness: f32,
+    pub is_aware: bool,
+    pub goals: Vec<GoalSnapshot>,
+    pub recent_memories: Vec<String>,
+    pub relationships: Vec<RelationshipSnapshot>,
+    /// Latest decisions, oldest first
+    #[serde(default)]
+    pub decisions: Vec<DecisionSnapshot>,
+    /// Most-tried rows of what the NPC has learned from outcomes
+    #[serde(default)]
+    pub learned: Vec<LearnedValueSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalSnapshot {
+    pub description: String,
+    pub priority: f32,
+    pub progress: f32,
+}
+
+/// One decision and why it was made, for moderators looking into odd
+/// behaviour.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionSnapshot {
+    pub time: f32,
+    pub chosen: String,
+    pub confidence: f32,
+    /// Options, per-factor scores, strategy, memories and beliefs
+    pub trace: serde_json::Value,
+    /// The same, in plain English
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearnedValueSnapshot {
+    pub situation: String,
+    pub option: String,
+    pub bandit_value: f32,
+    pub q_value: f32,
+    pub tries: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipSnapshot {
+    pub other: Uuid,
+    pub score: f32,
+    pub trust: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEvent {
+    pub time: f32,
+    pub source: Option<Uuid>,
+    pub message: String,
+}
+
+impl SpectatorMessage {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+}
+
+/// Fans spectator messages out to every connected WebSocket client.
+/// Spectators never send anything that affects the simulation.
+pub struct SpectatorServer {
+    addr: String,
+    sender: broadcast::Sender<SpectatorMessage>,
+}
+
+impl SpectatorServer {
+    pub fn new(addr: impl Into<String>) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            addr: addr.into(),
+            sender,
+        }
+    }
+
+    pub fn sender(&self) -> broadcast::Sender<SpectatorMessage> {
+        self.sender.clone()
+    }
+
+    pub async fn start(&self) -> std::io::Result<()> {
+        let listener = TcpListener::bind(&self.addr).await?;
+        log::info!("Spectator server listening on {}", self.addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let receiver = self.sender.subscribe();
+            tokio::spawn(async move {
+                if let Err(e) = serve_spectator(stream, receiver).await {
+                    log::debug!("Spectator {} disconnected: {}", peer, e);
+                }
+            });
+        }
+    }
+
+    /// Runs the server on its own thread and runtime, for callers (like the
+    /// Bevy app) that aren't async themselves.
+    pub fn spawn(self) -> broadcast::Sender<SpectatorMessage> {
+        let sender = self.sender();
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    log::error!("Failed to start spectator runtime: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = runtime.block_on(self.start()) {
+                log::error!("Spectator server stopped: {}", e);
+            }
+        });
+        sender
+    }
+}
+
+async fn serve_spectator(
+    stream: TcpStream,
+    mut receiver: broadcast::Receiver<SpectatorMessage>,
+) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    let socket = tokio_tungstenite::accept_async(stream).await?;
+    let (mut outgoing, mut incoming) = socket.split();
+
+    loop {
+        tokio::select! {
+            message = receiver.recv() => match message {
+                Ok(message) => outgoing.send(Message::Text(message.to_json())).await?,
+                // A slow client just misses some frames; the next snapshot
+                // brings it back up to date
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            frame = incoming.next() => match frame {
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return Err(e),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_round_trip_through_json() {
+        let id = Uuid::new_v4();
+        let snapshot = SpectatorMessage::Snapshot(WorldSnapshot {
+            time: 12.5,
+            npcs: vec![NpcSnapshot {
+                id,
+                name: "Ann".to_string(),
+                position: (16.0, 32.0),
+                state: "Working".to_string(),
+                emotion: "proud".to_string(),
+                emotion_intensity: 0.4,
+                awareness: 0.1,
+                is_aware: false,
+                goals: vec![GoalSnapshot { description: "open the shop".to_string(), priority: 0.6, progress: 0.5 }],
+                recent_memories: vec!["I managed to open shop".to_string()],
+                relationships: vec![RelationshipSnapshot { other: Uuid::new_v4(), score: 0.3, trust: 0.7 }],
+                decisions: Vec::new(),
+                learned: Vec::new(),
+            }],
+        });
+
+        let json = snapshot.to_json();
+        assert!(json.starts_with(r#"{"type":"Snapshot","data":"#));
+        let SpectatorMessage::Snapshot(parsed) = SpectatorMessage::from_json(&json).unwrap() else {
+            panic!("not a snapshot: {}", json);
+        };
+        assert_eq!(parsed.time, 12.5);
+        let ann = &parsed.npcs[0];
+        assert_eq!((ann.id, ann.name.as_str(), ann.position), (id, "Ann", (16.0, 32.0)));
+        assert_eq!(ann.goals[0].description, "open the shop");
+        assert_eq!(ann.recent_memories, ["I managed to open shop"]);
+
+        let event = SpectatorMessage::Event(LogEvent { time: 3.0, source: Some(id), message: "Ann woke".to_string() });
+        let SpectatorMessage::Event(parsed) = SpectatorMessage::from_json(&event.to_json()).unwrap() else {
+            panic!("not an event");
+        };
+        assert_eq!((parsed.source, parsed.message.as_str()), (Some(id), "Ann woke"));
+    }
+
+    #[test]
+    fn snapshots_from_older_servers_still_parse() {
+        // Sent before decisions and learned values were streamed
+        let json = r#"{"type":"Snapshot","data":{"time":1.0,"npcs":[{
+            "id":"00000000-0000-0000-0000-000000000001","name":"Bea","position":[0.0,0.0],
+            "state":"Idle","emotion":"content","emotion_intensity":0.0,"awareness":0.0,
+            "is_aware":false,"goals":[],"recent_memories":[],"relationships":[]}]}}"#;
+        let Ok(SpectatorMessage::Snapshot(snapshot)) = SpectatorMessage::from_json(json) else {
+            panic!("didn't parse");
+        };
+        assert!(snapshot.npcs[0].decisions.is_empty());
+        assert!(snapshot.npcs[0].learned.is_empty());
+        assert!(SpectatorMessage::from_json(r#"{"type":"Vote","data":{}}"#).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+pub mod proposals;
+pub mod results;
+
+use tokio::sync::mpsc;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct VotingSystem {
+    active_proposals: HashMap<String, proposals::Proposal>,
+    completed_votes: Vec<results::VoteResults>,
+    event_sender: mpsc::Sender<super::NetworkEvent>,
+}
+
+impl VotingSystem {
+    pub fn new(event_sender: mpsc::Sender<super::NetworkEvent>) -> Self {
+        Self {
+            active_proposals: HashMap::new(),
+            completed_votes: Vec::new(),
+            event_sender,
+        }
+    }
+
+    pub fn create_proposal(&mut self, proposal: proposals::Proposal) {
+        self.active_proposals.insert(proposal.id.clone(), proposal.clone());
+        self.event_sender.try_send(super::NetworkEvent::ProposalCreated(proposal)).ok();
+    }
+
+    /// False if the proposal isn't open or `option` isn't one of its choices.
+    pub fn submit_vote(&mut self, voter: Uuid, proposal_id: &str, option: &str) -> bool {
+        self.active_proposals
+            .get_mut(proposal_id)
+            .is_some_and(|proposal| proposal.cast(voter, option))
+    }
+
+    /// Stops voting on a proposal and announces how it came out.
+    pub fn close_proposal(&mut self, proposal_id: &str) -> Option<results::VoteResults> {
+        let proposal = self.active_proposals.remove(proposal_id)?;
+        let results = results::VoteResults::from_proposal(&proposal);
+        self.completed_votes.push(results.clone());
+        self.event_sender.try_send(super::NetworkEvent::ResultsUpdated(results.clone())).ok();
+        Some(results)
+    }
+
+    pub fn active_proposals(&self) -> impl Iterator<Item = &proposals::Proposal> {
+        self.active_proposals.values()
+    }
+
+    pub fn completed_votes(&self) -> &[results::VoteResults] {
+        &self.completed_votes
+    }
+}
\ No newline at end of file
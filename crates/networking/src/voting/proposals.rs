@@ -0,0 +1,47 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Something viewers vote on, like what happens to the town next.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Proposal {
+    pub id: String,
+    pub title: String,
+    pub options: Vec<String>,
+    /// Each viewer's current vote; voting again changes it
+    votes: HashMap<Uuid, String>,
+}
+
+impl Proposal {
+    pub fn new(title: &str, options: Vec<String>) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            title: title.to_string(),
+            options,
+            votes: HashMap::new(),
+        }
+    }
+
+    /// False if `option` isn't one of the choices.
+    pub fn cast(&mut self, voter: Uuid, option: &str) -> bool {
+        if !self.options.iter().any(|candidate| candidate == option) {
+            return false;
+        }
+        self.votes.insert(voter, option.to_string());
+        true
+    }
+
+    pub fn tally(&self) -> Vec<(String, u32)> {
+        self.options
+            .iter()
+            .map(|option| {
+                let count = self.votes.values().filter(|vote| *vote == option).count() as u32;
+                (option.clone(), count)
+            })
+            .collect()
+    }
+
+    pub fn vote_count(&self) -> usize {
+        self.votes.len()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+use serde::{Serialize, Deserialize};
+
+use super::proposals::Proposal;
+
+/// How a proposal came out once voting closed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteResults {
+    pub proposal_id: String,
+    pub title: String,
+    pub tallies: Vec<(String, u32)>,
+    /// Most votes, earliest option on a tie; `None` if nobody voted
+    pub winner: Option<String>,
+}
+
+impl VoteResults {
+    pub fn from_proposal(proposal: &Proposal) -> Self {
+        let tallies = proposal.tally();
+        let winner = tallies
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .fold(None::<&(String, u32)>, |best, tally| match best {
+                Some(best) if best.1 >= tally.1 => Some(best),
+                _ => Some(tally),
+            })
+            .map(|(option, _)| option.clone());
+        Self {
+            proposal_id: proposal.id.clone(),
+            title: proposal.title.clone(),
+            tallies,
+            winner,
+        }
+    }
+}
\ No newline at end of file
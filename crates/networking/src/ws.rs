@@ -0,0 +1,100 @@
+use futures::{SinkExt, StreamExt};
+use serde::{Serialize, Deserialize};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+use uuid::Uuid;
+
+use crate::NetworkEvent;
+
+pub const DEFAULT_WS_ADDR: &str = "127.0.0.1:9001";
+
+/// What viewers may send, one JSON text frame per message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum ClientMessage {
+    Vote { proposal: String, option: String },
+}
+
+/// Accepts viewer connections and turns what they send into
+/// `NetworkEvent`s.
+pub struct WebSocketServer {
+    addr: String,
+    event_sender: mpsc::Sender<NetworkEvent>,
+}
+
+impl WebSocketServer {
+    pub fn new(event_sender: mpsc::Sender<NetworkEvent>) -> Self {
+        Self::bind(DEFAULT_WS_ADDR, event_sender)
+    }
+
+    pub fn bind(addr: impl Into<String>, event_sender: mpsc::Sender<NetworkEvent>) -> Self {
+        Self {
+            addr: addr.into(),
+            event_sender,
+        }
+    }
+
+    pub async fn start(&self) -> std::io::Result<()> {
+        let listener = TcpListener::bind(&self.addr).await?;
+        log::info!("WebSocket server listening on {}", self.addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let events = self.event_sender.clone();
+            tokio::spawn(async move {
+                if let Err(e) = serve_client(stream, events).await {
+                    log::debug!("Client {} disconnected: {}", peer, e);
+                }
+            });
+        }
+    }
+
+    /// Runs the server on its own thread and runtime, for callers that
+    /// aren't async themselves.
+    pub fn spawn(self) {
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    log::error!("Failed to start WebSocket runtime: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = runtime.block_on(self.start()) {
+                log::error!("WebSocket server stopped: {}", e);
+            }
+        });
+    }
+}
+
+async fn serve_client(
+    stream: TcpStream,
+    events: mpsc::Sender<NetworkEvent>,
+) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    let mut socket = tokio_tungstenite::accept_async(stream).await?;
+    let client = Uuid::new_v4();
+    events.send(NetworkEvent::ClientConnected(client)).await.ok();
+
+    let result = loop {
+        match socket.next().await {
+            Some(Ok(Message::Text(text))) => match serde_json::from_str::<ClientMessage>(&text) {
+                Ok(ClientMessage::Vote { proposal, option }) => {
+                    events.send(NetworkEvent::VoteSubmitted { voter: client, proposal, option }).await.ok();
+                }
+                Err(e) => log::debug!("Ignoring message from {}: {}", client, e),
+            },
+            Some(Ok(Message::Ping(payload))) => {
+                if let Err(e) = socket.send(Message::Pong(payload)).await {
+                    break Err(e);
+                }
+            }
+            Some(Ok(Message::Close(_))) | None => break Ok(()),
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => break Err(e),
+        }
+    };
+
+    events.send(NetworkEvent::ClientDisconnected(client)).await.ok();
+    result
+}
\ No newline at end of file
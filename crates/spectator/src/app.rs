@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+use networking::spectator::{LogEvent, NpcSnapshot, SpectatorMessage, WorldSnapshot};
+
+use crate::map::AsciiMap;
+
+const MAX_LOG_LINES: usize = 500;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Connection {
+    Connecting,
+    Connected,
+    Disconnected(String),
+}
+
+/// What the spectator has seen so far and what the viewer has selected.
+pub struct App {
+    pub map: Option<AsciiMap>,
+    pub connection: Connection,
+    snapshot: WorldSnapshot,
+    selected: Option<Uuid>,
+    log: VecDeque<LogEvent>,
+    /// Lines scrolled back from the newest entry; 0 follows the log
+    log_scroll: usize,
+    should_quit: bool,
+}
+
+impl App {
+    pub fn new(map: Option<AsciiMap>) -> Self {
+        Self {
+            map,
+            connection: Connection::Connecting,
+            snapshot: WorldSnapshot::default(),
+            selected: None,
+            log: VecDeque::with_capacity(MAX_LOG_LINES),
+            log_scroll: 0,
+            should_quit: false,
+        }
+    }
+
+    pub fn handle_message(&mut self, message: SpectatorMessage) {
+        match message {
+            SpectatorMessage::Snapshot(mut snapshot) => {
+                // Keep the list order stable between snapshots
+                snapshot.npcs.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+                self.snapshot = snapshot;
+
+                let selection_gone = self.selected
+                    .is_none_or(|id| !self.snapshot.npcs.iter().any(|n| n.id == id));
+                if selection_gone {
+                    self.selected = self.snapshot.npcs.first().map(|n| n.id);
+                }
+            }
+            SpectatorMessage::Event(event) => {
+                self.log.push_back(event);
+                while self.log.len() > MAX_LOG_LINES {
+                    self.log.pop_front();
+                }
+                if self.log_scroll > 0 {
+                    self.log_scroll = (self.log_scroll + 1).min(self.log.len());
+                }
+            }
+        }
+    }
+
+    pub fn time(&self) -> f32 {
+        self.snapshot.time
+    }
+
+    pub fn npcs(&self) -> &[NpcSnapshot] {
+        &self.snapshot.npcs
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        let id = self.selected?;
+        self.snapshot.npcs.iter().position(|n| n.id == id)
+    }
+
+    pub fn selected_npc(&self) -> Option<&NpcSnapshot> {
+        self.selected_index().map(|i| &self.snapshot.npcs[i])
+    }
+
+    pub fn npc_name(&self, id: Uuid) -> Option<&str> {
+        self.snapshot.npcs.iter().find(|n| n.id == id).map(|n| n.name.as_str())
+    }
+
+    pub fn select_next(&mut self) {
+        self.move_selection(1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.move_selection(-1);
+    }
+
+    fn move_selection(&mut self, step: isize) {
+        let count = self.snapshot.npcs.len() as isize;
+        if count == 0 {
+            return;
+        }
+
+        let current = self.selected_index().map_or(0, |i| i as isize);
+        let next = (current + step).rem_euclid(count) as usize;
+        self.selected = Some(self.snapshot.npcs[next].id);
+    }
+
+    pub fn log(&self) -> &VecDeque<LogEvent> {
+        &self.log
+    }
+
+    pub fn log_scroll(&self) -> usize {
+        self.log_scroll
+    }
+
+    pub fn scroll_log_back(&mut self, lines: usize) {
+        self.log_scroll = (self.log_scroll + lines).min(self.log.len());
+    }
+
+    pub fn scroll_log_forward(&mut self, lines: usize) {
+        self.log_scroll = self.log_scroll.saturating_sub(lines);
+    }
+
+    pub fn follow_log(&mut self) {
+        self.log_scroll = 0;
+    }
+
+    pub fn quit(&mut self) {
+        self.should_quit = true;
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npc(name: &str) -> NpcSnapshot {
+        NpcSnapshot {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            position: (0.0, 0.0),
+            state: "Idle".to_string(),
+            emotion: "content".to_string(),
+            emotion_intensity: 0.0,
+            awareness: 0.0,
+            is_aware: false,
+            goals: Vec::new(),
+            recent_memories: Vec::new(),
+            relationships: Vec::new(),
+            decisions: Vec::new(),
+            learned: Vec::new(),
+        }
+    }
+
+    fn snapshot(npcs: &[&NpcSnapshot]) -> SpectatorMessage {
+        SpectatorMessage::Snapshot(WorldSnapshot {
+            time: 0.0,
+            npcs: npcs.iter().map(|npc| (*npc).clone()).collect(),
+        })
+    }
+
+    fn event(message: &str) -> SpectatorMessage {
+        SpectatorMessage::Event(LogEvent { time: 0.0, source: None, message: message.to_string() })
+    }
+
+    #[test]
+    fn selection_follows_the_npc_across_snapshots() {
+        let (ann, bea, cal) = (npc("Ann"), npc("Bea"), npc("Cal"));
+        let mut app = App::new(None);
+
+        app.handle_message(snapshot(&[&bea, &ann]));
+        assert_eq!(app.selected_npc().map(|n| n.id), Some(ann.id));
+        app.select_next();
+        assert_eq!(app.selected_npc().map(|n| n.id), Some(bea.id));
+
+        // Still Bea, wherever she comes in the list
+        app.handle_message(snapshot(&[&cal, &ann, &bea]));
+        assert_eq!(app.selected_npc().map(|n| n.id), Some(bea.id));
+        assert_eq!(app.selected_index(), Some(1));
+
+        // Once she's gone, the first in the list is selected instead
+        app.handle_message(snapshot(&[&cal, &ann]));
+        assert_eq!(app.selected_npc().map(|n| n.id), Some(ann.id));
+        app.select_previous();
+        assert_eq!(app.selected_npc().map(|n| n.id), Some(cal.id));
+    }
+
+    #[test]
+    fn a_scrolled_back_log_stays_put_as_events_arrive() {
+        let mut app = App::new(None);
+        for message in ["one", "two", "three"] {
+            app.handle_message(event(message));
+        }
+
+        app.scroll_log_back(2);
+        assert_eq!(app.log_scroll(), 2);
+        app.handle_message(event("four"));
+        assert_eq!(app.log_scroll(), 3);
+
+        // Following the log keeps following it
+        app.follow_log();
+        app.handle_message(event("five"));
+        assert_eq!(app.log_scroll(), 0);
+        assert_eq!(app.log().back().map(|e| e.message.as_str()), Some("five"));
+        app.scroll_log_back(100);
+        assert_eq!(app.log_scroll(), 5);
+    }
+}
\ No newline at end of file
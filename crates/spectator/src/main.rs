@@ -0,0 +1,146 @@
+mod app;
+mod map;
+mod ui;
+
+use std::io;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use futures::StreamExt;
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+use networking::spectator::{SpectatorMessage, DEFAULT_SPECTATOR_ADDR};
+
+use app::{App, Connection};
+use map::{AsciiMap, TiledMap, DEFAULT_MAP_PATH};
+
+const TICK: Duration = Duration::from_millis(100);
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+const LOG_PAGE: usize = 5;
+
+enum Update {
+    Status(Connection),
+    Message(SpectatorMessage),
+}
+
+/// `spectator [ws://host:port] [--map maps/world.json]`
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let mut url = format!("ws://{}", DEFAULT_SPECTATOR_ADDR);
+    let mut map_path = DEFAULT_MAP_PATH.to_string();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--map" => map_path = args.next().unwrap_or(map_path),
+            _ => url = arg,
+        }
+    }
+
+    // A missing map still leaves the NPC panel and event log usable
+    let map = match TiledMap::load(&map_path) {
+        Ok(tiled) => Some(AsciiMap::from_tiled(&tiled)),
+        Err(e) => {
+            eprintln!("{}; continuing without a map", e);
+            None
+        }
+    };
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(watch(url, sender));
+
+    terminal::enable_raw_mode()?;
+    io::stdout().execute(EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    let result = run(&mut terminal, App::new(map), receiver);
+
+    terminal::disable_raw_mode()?;
+    io::stdout().execute(LeaveAlternateScreen)?;
+    result
+}
+
+fn run(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    mut app: App,
+    mut updates: mpsc::UnboundedReceiver<Update>,
+) -> io::Result<()> {
+    while !app.should_quit() {
+        while let Ok(update) = updates.try_recv() {
+            match update {
+                Update::Status(connection) => app.connection = connection,
+                Update::Message(message) => app.handle_message(message),
+            }
+        }
+
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    handle_key(&mut app, key.code);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Char('q') | KeyCode::Esc => app.quit(),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => app.select_next(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => app.select_previous(),
+        KeyCode::PageUp => app.scroll_log_back(LOG_PAGE),
+        KeyCode::PageDown => app.scroll_log_forward(LOG_PAGE),
+        KeyCode::End => app.follow_log(),
+        _ => {}
+    }
+}
+
+/// Keeps a connection to the simulation open, reconnecting after drops so
+/// the spectator can be started before the server.
+async fn watch(url: String, updates: mpsc::UnboundedSender<Update>) {
+    loop {
+        updates.send(Update::Status(Connection::Connecting)).ok();
+
+        let reason = match tokio_tungstenite::connect_async(url.as_str()).await {
+            Ok((mut socket, _)) => {
+                updates.send(Update::Status(Connection::Connected)).ok();
+
+                let mut reason = "connection closed".to_string();
+                while let Some(frame) = socket.next().await {
+                    match frame {
+                        Ok(Message::Text(text)) => match SpectatorMessage::from_json(&text) {
+                            Ok(message) => {
+                                if updates.send(Update::Message(message)).is_err() {
+                                    return;
+                                }
+                            }
+                            Err(e) => reason = format!("bad message: {}", e),
+                        },
+                        Ok(Message::Close(_)) => break,
+                        Ok(_) => {}
+                        Err(e) => {
+                            reason = e.to_string();
+                            break;
+                        }
+                    }
+                }
+                reason
+            }
+            Err(e) => e.to_string(),
+        };
+
+        if updates.send(Update::Status(Connection::Disconnected(reason))).is_err() {
+            return;
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use serde::Deserialize;
+
+pub const DEFAULT_MAP_PATH: &str = "maps/world.json";
+
+/// Ground tiles that are plain grass; any other terrain tile is water or path.
+const GRASS_TILES: [u32; 10] = [1, 2, 3, 28, 29, 30, 31, 55, 56, 57];
+
+/// The parts of a Tiled JSON map the spectator needs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TiledMap {
+    pub width: usize,
+    pub height: usize,
+    #[serde(rename = "tilewidth")]
+    pub tile_width: u32,
+    #[serde(rename = "tileheight")]
+    pub tile_height: u32,
+    pub layers: Vec<TiledLayer>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TiledLayer {
+    #[serde(default)]
+    pub data: Vec<u32>,
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Terrain {
+    Grass,
+    Water,
+    Obstacle,
+    Detail,
+}
+
+/// `world.json` reduced to one terrain class per tile. Layers are read bottom
+/// to top as ground, obstacles (trees, fences, walls) and details.
+#[derive(Debug, Clone)]
+pub struct AsciiMap {
+    width: usize,
+    height: usize,
+    tile_size: (f32, f32),
+    tiles: Vec<Terrain>,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+impl TiledMap {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        serde_json::from_str(&source)
+            .map_err(|e| format!("Failed to parse {}: {}", path, e))
+    }
+}
+
+impl AsciiMap {
+    pub fn from_tiled(map: &TiledMap) -> Self {
+        let mut tiles = vec![Terrain::Grass; map.width * map.height];
+        let layers = map.layers.iter().filter(|l| l.visible && !l.data.is_empty());
+
+        for (depth, layer) in layers.enumerate() {
+            for (index, gid) in layer.data.iter().enumerate().take(tiles.len()) {
+                if *gid == 0 {
+                    continue;
+                }
+
+                tiles[index] = match depth {
+                    0 if GRASS_TILES.contains(gid) => Terrain::Grass,
+                    0 => Terrain::Water,
+                    1 => Terrain::Obstacle,
+                    _ => Terrain::Detail,
+                };
+            }
+        }
+
+        Self {
+            width: map.width,
+            height: map.height,
+            tile_size: (map.tile_width as f32, map.tile_height as f32),
+            tiles,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn terrain(&self, x: usize, y: usize) -> Option<Terrain> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.tiles.get(y * self.width + x).copied()
+    }
+
+    /// Tile (column, row from the top) under a world position. World space has
+    /// its origin at the map's bottom-left with y up, as in the game.
+    pub fn world_to_tile(&self, x: f32, y: f32) -> Option<(usize, usize)> {
+        let column = (x / self.tile_size.0).floor();
+        let row_from_bottom = (y / self.tile_size.1).floor();
+        if column < 0.0 || row_from_bottom < 0.0 {
+            return None;
+        }
+
+        let (column, row_from_bottom) = (column as usize, row_from_bottom as usize);
+        if column >= self.width || row_from_bottom >= self.height {
+            return None;
+        }
+        Some((column, self.height - 1 - row_from_bottom))
+    }
+}
+
+impl Terrain {
+    pub fn glyph(&self) -> char {
+        match self {
+            Terrain::Grass => '.',
+            Terrain::Water => '~',
+            Terrain::Obstacle => '#',
+            Terrain::Detail => '^',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(data: &[u32], visible: bool) -> TiledLayer {
+        TiledLayer { data: data.to_vec(), visible }
+    }
+
+    /// 3 tiles wide, 2 high, 16px tiles
+    fn tiled(layers: Vec<TiledLayer>) -> TiledMap {
+        TiledMap { width: 3, height: 2, tile_width: 16, tile_height: 16, layers }
+    }
+
+    #[test]
+    fn world_positions_count_rows_from_the_bottom() {
+        let map = AsciiMap::from_tiled(&tiled(Vec::new()));
+
+        assert_eq!(map.world_to_tile(0.0, 0.0), Some((0, 1)));
+        assert_eq!(map.world_to_tile(47.9, 31.9), Some((2, 0)));
+        assert_eq!(map.world_to_tile(20.0, 16.0), Some((1, 0)));
+        // Off every edge of the map
+        assert_eq!(map.world_to_tile(-0.1, 0.0), None);
+        assert_eq!(map.world_to_tile(0.0, -0.1), None);
+        assert_eq!(map.world_to_tile(48.0, 0.0), None);
+        assert_eq!(map.world_to_tile(0.0, 32.0), None);
+    }
+
+    #[test]
+    fn layers_classify_ground_obstacles_and_details() {
+        let map = AsciiMap::from_tiled(&tiled(vec![
+            layer(&[1, 5, 0, 1, 1, 1], true),
+            // Object layers have no tiles and don't count as a depth
+            layer(&[], true),
+            layer(&[0, 0, 0, 9, 0, 0], true),
+            layer(&[0, 0, 0, 0, 9, 0], true),
+            layer(&[0, 0, 0, 0, 0, 9], false),
+        ]));
+
+        assert_eq!(map.terrain(0, 0), Some(Terrain::Grass));
+        assert_eq!(map.terrain(1, 0), Some(Terrain::Water));
+        // Nothing painted is grass
+        assert_eq!(map.terrain(2, 0), Some(Terrain::Grass));
+        assert_eq!(map.terrain(0, 1), Some(Terrain::Obstacle));
+        assert_eq!(map.terrain(1, 1), Some(Terrain::Detail));
+        // Hidden layers are ignored
+        assert_eq!(map.terrain(2, 1), Some(Terrain::Grass));
+        assert_eq!(map.terrain(3, 0), None);
+    }
+}
\ No newline at end of file
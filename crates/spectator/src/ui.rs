@@ -0,0 +1,193 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::{App, Connection};
+use crate::map::Terrain;
+
+const LOG_HEIGHT: u16 = 10;
+const SIDEBAR_WIDTH: u16 = 44;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(LOG_HEIGHT)])
+        .split(frame.size());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(20), Constraint::Length(SIDEBAR_WIDTH)])
+        .split(rows[0]);
+    let sidebar = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(columns[1]);
+
+    draw_map(frame, app, columns[0]);
+    draw_npc_list(frame, app, sidebar[0]);
+    draw_npc_details(frame, app, sidebar[1]);
+    draw_log(frame, app, rows[1]);
+}
+
+fn draw_map(frame: &mut Frame, app: &App, area: Rect) {
+    let status = match &app.connection {
+        Connection::Connecting => "connecting...".to_string(),
+        Connection::Connected => format!("t={:.0}s", app.time()),
+        Connection::Disconnected(reason) => format!("disconnected: {}", reason),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Town ({}) ", status));
+
+    let Some(map) = &app.map else {
+        let message = Paragraph::new("No map loaded").block(block);
+        frame.render_widget(message, area);
+        return;
+    };
+
+    // Tiles are drawn two cells wide so the map keeps its shape in a terminal
+    let mut grid: Vec<Vec<Span>> = (0..map.height())
+        .map(|y| {
+            (0..map.width())
+                .map(|x| {
+                    let terrain = map.terrain(x, y).unwrap_or(Terrain::Grass);
+                    Span::styled(terrain.glyph().to_string().repeat(2), terrain_style(terrain))
+                })
+                .collect()
+        })
+        .collect();
+
+    let selected = app.selected_npc().map(|n| n.id);
+    for npc in app.npcs() {
+        let Some((x, y)) = map.world_to_tile(npc.position.0, npc.position.1) else {
+            continue;
+        };
+
+        let initial = npc.name.chars().next().unwrap_or('?');
+        let mut style = Style::default().fg(if npc.is_aware { Color::Magenta } else { Color::White });
+        if Some(npc.id) == selected {
+            style = style.bg(Color::Blue).add_modifier(Modifier::BOLD);
+        }
+        grid[y][x] = Span::styled(format!("{} ", initial), style);
+    }
+
+    let lines: Vec<Line> = grid.into_iter().map(Line::from).collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn terrain_style(terrain: Terrain) -> Style {
+    match terrain {
+        Terrain::Grass => Style::default().fg(Color::Green),
+        Terrain::Water => Style::default().fg(Color::Cyan),
+        Terrain::Obstacle => Style::default().fg(Color::DarkGray),
+        Terrain::Detail => Style::default().fg(Color::Yellow),
+    }
+}
+
+fn draw_npc_list(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .npcs()
+        .iter()
+        .map(|npc| {
+            let marker = if npc.is_aware { "*" } else { " " };
+            ListItem::new(format!("{}{} - {}", marker, npc.name, npc.state))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!(" NPCs ({}) ", app.npcs().len())))
+        .highlight_style(Style::default().bg(Color::Blue).add_modifier(Modifier::BOLD));
+
+    let mut state = ListState::default();
+    state.select(app.selected_index());
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_npc_details(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(" Inspector ");
+    let Some(npc) = app.selected_npc() else {
+        frame.render_widget(Paragraph::new("No NPC selected").block(block), area);
+        return;
+    };
+
+    let heading = Style::default().add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(Span::styled(npc.name.clone(), heading)),
+        Line::from(format!("State:     {}", npc.state)),
+        Line::from(format!("Emotion:   {} ({:.0}%)", npc.emotion, npc.emotion_intensity * 100.0)),
+        Line::from(format!(
+            "Awareness: {:.0}%{}",
+            npc.awareness * 100.0,
+            if npc.is_aware { " - knows" } else { "" },
+        )),
+        Line::from(""),
+        Line::from(Span::styled("Goals", heading)),
+    ];
+
+    if npc.goals.is_empty() {
+        lines.push(Line::from("  none"));
+    }
+    for goal in &npc.goals {
+        lines.push(Line::from(format!("  {} ({:.0}%)", goal.description, goal.progress * 100.0)));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Recent memories", heading)));
+    if npc.recent_memories.is_empty() {
+        lines.push(Line::from("  none"));
+    }
+    for memory in &npc.recent_memories {
+        lines.push(Line::from(format!("  {}", memory)));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Relationships", heading)));
+    if npc.relationships.is_empty() {
+        lines.push(Line::from("  none"));
+    }
+    for relationship in &npc.relationships {
+        let name = app
+            .npc_name(relationship.other)
+            .map_or_else(|| relationship.other.to_string()[..8].to_string(), str::to_string);
+        lines.push(Line::from(format!(
+            "  {} score {:.2} trust {:.2}",
+            name, relationship.score, relationship.trust,
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Last decision", heading)));
+    match npc.decisions.last() {
+        Some(decision) => lines.extend(decision.explanation.lines().map(|line| Line::from(format!("  {}", line)))),
+        None => lines.push(Line::from("  none")),
+    }
+
+    let details = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+    frame.render_widget(details, area);
+}
+
+fn draw_log(frame: &mut Frame, app: &App, area: Rect) {
+    let visible = area.height.saturating_sub(2) as usize;
+    let end = app.log().len().saturating_sub(app.log_scroll());
+    let start = end.saturating_sub(visible);
+
+    let lines: Vec<Line> = app
+        .log()
+        .range(start..end)
+        .map(|event| {
+            Line::from(vec![
+                Span::styled(format!("[{:>7.1}] ", event.time), Style::default().fg(Color::DarkGray)),
+                Span::raw(event.message.clone()),
+            ])
+        })
+        .collect();
+
+    let title = if app.log_scroll() > 0 {
+        format!(" Events (-{}, End to follow) ", app.log_scroll())
+    } else {
+        " Events ".to_string()
+    };
+    let log = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(log, area);
+}
\ No newline at end of file
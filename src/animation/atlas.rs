@@ -0,0 +1,193 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+/// Velocity below which a character counts as standing still.
+const WALK_THRESHOLD: f32 = 1.0;
+
+/// How a character sheet is sliced: a grid of equally sized frames, one row per
+/// facing direction, with each animation occupying a run of columns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SheetLayout {
+    pub frame_size: (u32, u32),
+    pub columns: usize,
+    pub rows: usize,
+    pub direction_rows: HashMap<Direction, usize>,
+    pub animations: HashMap<AnimationKind, AnimationClip>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationClip {
+    pub first_column: usize,
+    pub frames: usize,
+    pub fps: f32,
+    #[serde(default = "default_looping")]
+    pub looping: bool,
+    /// Forces a single row, for animations drawn facing one way only (e.g. sleeping)
+    #[serde(default)]
+    pub fixed_row: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnimationKind {
+    Idle,
+    Walk,
+    Sleep,
+    Work,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Direction {
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+}
+
+fn default_looping() -> bool {
+    true
+}
+
+impl SheetLayout {
+    /// Index into the texture atlas for a frame of an animation.
+    pub fn frame_index(&self, kind: AnimationKind, direction: Direction, frame: usize) -> usize {
+        let clip = match self.clip(kind) {
+            Some(clip) => clip,
+            None => return 0,
+        };
+
+        let row = clip.fixed_row
+            .or_else(|| self.direction_rows.get(&direction).copied())
+            .unwrap_or(0)
+            .min(self.rows.saturating_sub(1));
+        let column = (clip.first_column + frame % clip.frames.max(1))
+            .min(self.columns.saturating_sub(1));
+
+        row * self.columns + column
+    }
+
+    /// Falls back to idle when the sheet has no clip for the requested animation.
+    pub fn clip(&self, kind: AnimationKind) -> Option<&AnimationClip> {
+        self.animations.get(&kind).or_else(|| self.animations.get(&AnimationKind::Idle))
+    }
+}
+
+impl AnimationClip {
+    /// Advances `elapsed` and returns the frame to show. Non-looping clips hold
+    /// their last frame.
+    pub fn frame_at(&self, elapsed: f32) -> usize {
+        if self.frames == 0 || self.fps <= 0.0 {
+            return 0;
+        }
+
+        let frame = (elapsed * self.fps) as usize;
+        if self.looping {
+            frame % self.frames
+        } else {
+            frame.min(self.frames - 1)
+        }
+    }
+}
+
+impl Direction {
+    /// Eight-way facing for a velocity in world space (y up). Returns `None` when
+    /// the character isn't moving so callers can keep the previous facing.
+    pub fn from_velocity(x: f32, y: f32) -> Option<Self> {
+        if (x * x + y * y).sqrt() < WALK_THRESHOLD {
+            return None;
+        }
+
+        let angle = y.atan2(x).to_degrees();
+        let sector = (((angle + 360.0 + 22.5) % 360.0) / 45.0) as usize;
+
+        Some(match sector {
+            0 => Direction::East,
+            1 => Direction::NorthEast,
+            2 => Direction::North,
+            3 => Direction::NorthWest,
+            4 => Direction::West,
+            5 => Direction::SouthWest,
+            6 => Direction::South,
+            _ => Direction::SouthEast,
+        })
+    }
+}
+
+impl AnimationKind {
+    /// Picks the animation from what the NPC is doing and how fast it's moving.
+    /// Sleep and work win over movement; otherwise any real velocity means walking.
+    pub fn select(state: Option<&crate::entities::npc::states::State>, speed: f32) -> Self {
+        use crate::entities::npc::states::State;
+
+        match state {
+            Some(State::Sleeping) => AnimationKind::Sleep,
+            Some(State::Working) | Some(State::Trading) => AnimationKind::Work,
+            _ if speed >= WALK_THRESHOLD => AnimationKind::Walk,
+            Some(State::Walking) | Some(State::Idle) | Some(State::Socializing)
+            | Some(State::Thinking) | None => AnimationKind::Idle,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::npc::states::State;
+
+    fn clip(first_column: usize, frames: usize, looping: bool, fixed_row: Option<usize>) -> AnimationClip {
+        AnimationClip { first_column, frames, fps: 4.0, looping, fixed_row }
+    }
+
+    fn layout() -> SheetLayout {
+        SheetLayout {
+            frame_size: (32, 32),
+            columns: 8,
+            rows: 4,
+            direction_rows: HashMap::from([(Direction::South, 0), (Direction::North, 2)]),
+            animations: HashMap::from([
+                (AnimationKind::Idle, clip(0, 2, true, None)),
+                (AnimationKind::Walk, clip(2, 4, true, None)),
+                (AnimationKind::Sleep, clip(6, 2, false, Some(3))),
+            ]),
+        }
+    }
+
+    #[test]
+    fn frames_map_onto_rows_by_facing_and_wrap_within_the_clip() {
+        let sheet = layout();
+
+        assert_eq!(sheet.frame_index(AnimationKind::Walk, Direction::North, 1), 2 * 8 + 3);
+        assert_eq!(sheet.frame_index(AnimationKind::Walk, Direction::North, 5), 2 * 8 + 3);
+        // Sleep is drawn facing one way, and a missing Work clip falls back to idle
+        assert_eq!(sheet.frame_index(AnimationKind::Sleep, Direction::North, 0), 3 * 8 + 6);
+        assert_eq!(sheet.frame_index(AnimationKind::Work, Direction::South, 1), 1);
+        // Directions the sheet doesn't draw use the first row
+        assert_eq!(sheet.frame_index(AnimationKind::Idle, Direction::East, 0), 0);
+    }
+
+    #[test]
+    fn looping_clips_wrap_and_others_hold_the_last_frame() {
+        assert_eq!(clip(0, 4, true, None).frame_at(1.25), 1);
+        assert_eq!(clip(0, 4, false, None).frame_at(1.25), 3);
+        assert_eq!(clip(0, 0, true, None).frame_at(1.0), 0);
+    }
+
+    #[test]
+    fn facing_follows_velocity_and_holds_when_still() {
+        assert_eq!(Direction::from_velocity(5.0, 0.0), Some(Direction::East));
+        assert_eq!(Direction::from_velocity(0.0, -5.0), Some(Direction::South));
+        assert_eq!(Direction::from_velocity(-3.0, 3.0), Some(Direction::NorthWest));
+        assert_eq!(Direction::from_velocity(0.2, 0.3), None);
+    }
+
+    #[test]
+    fn sleep_and_work_win_over_movement() {
+        assert_eq!(AnimationKind::select(Some(&State::Sleeping), 10.0), AnimationKind::Sleep);
+        assert_eq!(AnimationKind::select(Some(&State::Trading), 10.0), AnimationKind::Work);
+        assert_eq!(AnimationKind::select(Some(&State::Idle), 10.0), AnimationKind::Walk);
+        assert_eq!(AnimationKind::select(None, 0.0), AnimationKind::Idle);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+pub mod atlas;
+pub mod registry;
+
+use bevy::prelude::*;
+
+use engine::physics::movement::MovementComponent;
+use crate::entities::npc::states::NPCState;
+
+use atlas::{AnimationKind, Direction};
+use registry::{CharacterRegistry, REGISTRY_PATH};
+
+/// Loads the character registry and animates every character sprite.
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        let registry = CharacterRegistry::load(REGISTRY_PATH).unwrap_or_else(|e| {
+            log::warn!("{}; characters will render without animation", e);
+            CharacterRegistry::default()
+        });
+
+        app.insert_resource(registry)
+            .add_systems(Update, (select_animation, advance_animation).chain());
+    }
+}
+
+/// Which animation a character is playing, which way it faces and how far
+/// into the clip it is.
+#[derive(Component, Debug, Clone)]
+pub struct CharacterAnimation {
+    pub character: String,
+    kind: AnimationKind,
+    direction: Direction,
+    elapsed: f32,
+}
+
+impl CharacterAnimation {
+    pub fn new(character: impl Into<String>) -> Self {
+        Self {
+            character: character.into(),
+            kind: AnimationKind::Idle,
+            direction: Direction::South,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn kind(&self) -> AnimationKind {
+        self.kind
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Switches animation, restarting the clip only when it actually changes.
+    pub fn set(&mut self, kind: AnimationKind, direction: Option<Direction>) {
+        if kind != self.kind {
+            self.kind = kind;
+            self.elapsed = 0.0;
+        }
+        if let Some(direction) = direction {
+            self.direction = direction;
+        }
+    }
+}
+
+fn select_animation(
+    mut characters: Query<(&MovementComponent, Option<&NPCState>, &mut CharacterAnimation)>,
+) {
+    for (movement, state, mut animation) in &mut characters {
+        let velocity = movement.velocity();
+        let kind = AnimationKind::select(state.map(|s| s.current_state()), velocity.length());
+        animation.set(kind, Direction::from_velocity(velocity.x, velocity.y));
+    }
+}
+
+fn advance_animation(
+    time: Res<Time>,
+    registry: Res<CharacterRegistry>,
+    mut sprites: Query<(&mut CharacterAnimation, &mut TextureAtlasSprite)>,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (mut animation, mut sprite) in &mut sprites {
+        animation.elapsed += delta_time;
+
+        let Some(layout) = registry.layout(&animation.character) else {
+            continue;
+        };
+        let frame = layout
+            .clip(animation.kind)
+            .map_or(0, |clip| clip.frame_at(animation.elapsed));
+
+        let index = layout.frame_index(animation.kind, animation.direction, frame);
+        if sprite.index != index {
+            sprite.index = index;
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use super::atlas::SheetLayout;
+
+pub const REGISTRY_PATH: &str = "sprites/characters.ron";
+pub const DEFAULT_CHARACTER: &str = "Villager";
+
+/// On-disk format of `sprites/characters.ron`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistryDefinition {
+    pub layouts: HashMap<String, SheetLayout>,
+    pub characters: HashMap<String, CharacterSheet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterSheet {
+    pub image: String,
+    pub layout: String,
+}
+
+/// Binds named characters ("Emma", "Truman", ...) to their sprite sheets and
+/// caches one texture atlas per sheet.
+#[derive(Resource, Debug, Default)]
+pub struct CharacterRegistry {
+    definition: RegistryDefinition,
+    atlases: HashMap<String, Handle<TextureAtlas>>,
+}
+
+impl CharacterRegistry {
+    pub fn new(definition: RegistryDefinition) -> Self {
+        Self {
+            definition,
+            atlases: HashMap::new(),
+        }
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let definition = ron::from_str(&source)
+            .map_err(|e| format!("Failed to parse {}: {}", path, e))?;
+
+        Ok(Self::new(definition))
+    }
+
+    /// Resolves a character name, falling back to the generic villager sheet.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        if self.definition.characters.contains_key(name) {
+            Some(name)
+        } else if self.definition.characters.contains_key(DEFAULT_CHARACTER) {
+            Some(DEFAULT_CHARACTER)
+        } else {
+            None
+        }
+    }
+
+    pub fn layout(&self, character: &str) -> Option<&SheetLayout> {
+        let sheet = self.definition.characters.get(self.resolve(character)?)?;
+        self.definition.layouts.get(&sheet.layout)
+    }
+
+    pub fn characters(&self) -> impl Iterator<Item = &String> {
+        self.definition.characters.keys()
+    }
+
+    /// Texture atlas for a character, slicing the sheet on first use.
+    pub fn atlas(
+        &mut self,
+        character: &str,
+        asset_server: &AssetServer,
+        atlases: &mut Assets<TextureAtlas>,
+    ) -> Option<Handle<TextureAtlas>> {
+        let name = self.resolve(character)?.to_string();
+        if let Some(handle) = self.atlases.get(&name) {
+            return Some(handle.clone());
+        }
+
+        let sheet = self.definition.characters.get(&name)?;
+        let layout = self.definition.layouts.get(&sheet.layout)?;
+
+        let texture = asset_server.load(sheet.image.clone());
+        let atlas = TextureAtlas::from_grid(
+            texture,
+            Vec2::new(layout.frame_size.0 as f32, layout.frame_size.1 as f32),
+            layout.columns,
+            layout.rows,
+            None,
+            None,
+        );
+
+        let handle = atlases.add(atlas);
+        self.atlases.insert(name, handle.clone());
+        Some(handle)
+    }
+}
\ No newline at end of file
@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
 use bevy::prelude::*;
+use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use ai_core::memory::decay::DecayCurve;
+
+use crate::entities::npc::NPCType;
+
+/// Anything left out of config.toml keeps its default.
+#[derive(Resource, Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
 pub struct Config {
     pub simulation: SimulationConfig,
     pub ai: AiConfig,
@@ -9,6 +16,7 @@ pub struct Config {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
 pub struct SimulationConfig {
     pub tick_rate: f32,
     pub world_size: Vec2,
@@ -16,43 +24,86 @@ pub struct SimulationConfig {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
 pub struct AiConfig {
     pub max_npcs: usize,
-    pub memory_decay_rate: f32,
     pub interaction_radius: f32,
     pub awareness_threshold: f32,
+    pub memory_decay: DecayConfig,
+}
+
+/// Forgetting curves, e.g. in config.toml:
+///
+/// ```toml
+/// [ai.memory_decay.default]
+/// model = "spaced_repetition"
+/// initial_half_life = 720.0
+/// growth = 2.0
+/// max_half_life = 43200.0
+///
+/// [ai.memory_decay.archetypes.guard]
+/// model = "power_law"
+/// scale = 480.0
+/// exponent = 0.4
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DecayConfig {
+    #[serde(default)]
+    pub default: DecayCurve,
+    /// Overrides keyed by NPC type: "villager", "merchant", "guard", "wanderer"
+    #[serde(default)]
+    pub archetypes: HashMap<String, DecayCurve>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
 pub struct NetworkConfig {
     pub ws_port: u16,
     pub max_connections: usize,
     pub tick_rate: f32,
 }
 
-impl Default for Config {
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate: 60.0,
+            world_size: Vec2::new(1000.0, 1000.0),
+            max_entities: 1000,
+        }
+    }
+}
+
+impl Default for AiConfig {
+    fn default() -> Self {
+        Self {
+            max_npcs: 100,
+            interaction_radius: 50.0,
+            awareness_threshold: 0.8,
+            memory_decay: DecayConfig::default(),
+        }
+    }
+}
+
+impl Default for NetworkConfig {
     fn default() -> Self {
         Self {
-            simulation: SimulationConfig {
-                tick_rate: 60.0,
-                world_size: Vec2::new(1000.0, 1000.0),
-                max_entities: 1000,
-            },
-            ai: AiConfig {
-                max_npcs: 100,
-                memory_decay_rate: 0.1,
-                interaction_radius: 50.0,
-                awareness_threshold: 0.8,
-            },
-            network: NetworkConfig {
-                ws_port: 8080,
-                max_connections: 100,
-                tick_rate: 20.0,
-            },
+            ws_port: 8080,
+            max_connections: 100,
+            tick_rate: 20.0,
         }
     }
 }
 
+impl AiConfig {
+    pub fn decay_curve(&self, npc_type: &NPCType) -> DecayCurve {
+        self.memory_decay
+            .archetypes
+            .get(npc_type.name())
+            .copied()
+            .unwrap_or(self.memory_decay.default)
+    }
+}
+
 impl Config {
     pub fn load() -> Result<Self, config::ConfigError> {
         let config = config::Config::builder()
@@ -63,4 +114,46 @@ impl Config {
 
         Ok(config)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_core::memory::decay::{PowerLaw, SpacedRepetition};
+
+    #[test]
+    fn archetypes_override_the_default_curve() {
+        let config: Config = config::Config::builder()
+            .add_source(config::File::from_str(
+                r#"
+                [ai.memory_decay.default]
+                model = "spaced_repetition"
+                initial_half_life = 720.0
+                growth = 2.0
+                max_half_life = 43200.0
+
+                [ai.memory_decay.archetypes.guard]
+                model = "power_law"
+                scale = 480.0
+                exponent = 0.4
+                "#,
+                config::FileFormat::Toml,
+            ))
+            .build()
+            .and_then(|built| built.try_deserialize())
+            .unwrap();
+
+        assert_eq!(
+            config.ai.decay_curve(&NPCType::Guard),
+            DecayCurve::PowerLaw(PowerLaw { scale: 480.0, exponent: 0.4 })
+        );
+        assert_eq!(
+            config.ai.decay_curve(&NPCType::Merchant),
+            DecayCurve::SpacedRepetition(SpacedRepetition {
+                initial_half_life: 720.0,
+                growth: 2.0,
+                max_half_life: 43200.0,
+            })
+        );
+    }
 }
\ No newline at end of file
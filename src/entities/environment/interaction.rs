@@ -1,4 +1,10 @@
 use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use engine::physics::Vector2;
+
+use ai_core::goals::needs::NeedAction;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InteractionPoint {
@@ -16,6 +22,8 @@ pub enum InteractionType {
     Seat,
     Workstation,
     Storage,
+    Bed,
+    Washbasin,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +35,23 @@ pub enum EnvironmentAction {
     Trade,
     Store,
     Retrieve,
+    Eat,
+    Sleep,
+    Wash,
+    Chat,
+}
+
+impl EnvironmentAction {
+    /// The need the action meets, if any.
+    pub fn need_action(&self) -> Option<NeedAction> {
+        match self {
+            EnvironmentAction::Eat => Some(NeedAction::Eat),
+            EnvironmentAction::Sleep => Some(NeedAction::Sleep),
+            EnvironmentAction::Wash => Some(NeedAction::Wash),
+            EnvironmentAction::Chat => Some(NeedAction::Chat),
+            _ => None,
+        }
+    }
 }
 
 impl InteractionPoint {
@@ -67,6 +92,12 @@ pub struct EnvironmentInteraction {
     pub active_interactions: HashMap<Uuid, Vec<Uuid>>, // NPC ID -> Interaction Point IDs
 }
 
+impl Default for EnvironmentInteraction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EnvironmentInteraction {
     pub fn new() -> Self {
         Self {
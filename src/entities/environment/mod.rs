@@ -3,6 +3,8 @@ pub mod interaction;
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 
+use engine::physics::Vector2;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Environment {
     pub id: Uuid,
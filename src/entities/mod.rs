@@ -1,8 +1,10 @@
 pub mod npc;
 pub mod environment;
+pub mod places;
 
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
+use engine::physics::Vector2;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Entity {
@@ -1,4 +1,7 @@
 use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+
+use engine::physics::Vector2;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Action {
@@ -36,6 +39,18 @@ impl Action {
 
     pub fn update(&mut self, delta_time: f32) -> bool {
         self.progress += delta_time;
+        self.is_finished()
+    }
+
+    pub fn action_type(&self) -> &ActionType {
+        &self.action_type
+    }
+
+    pub fn target(&self) -> Option<&ActionTarget> {
+        self.target.as_ref()
+    }
+
+    pub fn is_finished(&self) -> bool {
         self.progress >= self.duration
     }
 }
\ No newline at end of file
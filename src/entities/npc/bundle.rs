@@ -0,0 +1,760 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use ai_core::cognition::utility::{UtilityDefinitions, UtilityInputs, UTILITY_PATH};
+use ai_core::cognition::decision::DecisionOutcome;
+use ai_core::cognition::reasoning::{RuleSet, RULES_PATH};
+use ai_core::cognition::CognitionSystem;
+use ai_core::consciousness::dreams::most_vivid;
+use ai_core::consciousness::ConsciousnessState;
+use ai_core::dialogue::DialogueSystem;
+use ai_core::ecs::{Aware, NpcBrainBundle, NpcId};
+use ai_core::goals::htn::{HtnDomain, HTN_DOMAIN_PATH};
+use ai_core::goals::needs::{Need, NeedAction};
+use ai_core::goals::GoalSystem;
+use ai_core::knowledge::KnowledgeBase;
+use ai_core::memory::MemorySystem;
+use ai_core::personality::appraisal::{Agent, Appraisal};
+use ai_core::personality::emotions::EmotionalState;
+use ai_core::personality::PersonalityTraits;
+use ai_core::social::SocialNetwork;
+use ai_core::Npc;
+use engine::ecs::BodyBundle;
+use engine::physics::movement::MovementComponent;
+use engine::simulation::time::{TimeSystem, SECONDS_PER_GAME_MINUTE};
+
+use crate::animation::registry::CharacterRegistry;
+use crate::animation::CharacterAnimation;
+use crate::config::Config;
+use crate::entities::places::{WorldPlaces, PLACES_PATH};
+
+use super::actions::ActionType;
+use super::conversation::converse;
+use super::executor::{ExecutionEvent, PlanRunner};
+use super::needs::{satisfy_needs, NeedStations};
+use super::states::{NPCState, State};
+use super::NPCType;
+
+const DEFAULT_WALK_SPEED: f32 = 48.0;
+/// How near a destination an NPC has to be to just get on with it
+const ARRIVAL_DISTANCE: f32 = 8.0;
+/// Sim seconds between an NPC reconsidering what it's doing
+const DECISION_INTERVAL: f32 = SECONDS_PER_GAME_MINUTE * 15.0;
+/// How much the goal of the day matters next to the rest
+const DAILY_GOAL_PRIORITY: f32 = 0.6;
+/// How getting a plan step done, failing at one, or giving up on a plan
+/// feels, at the time and to remember
+const STEP_FEELING: f32 = 0.2;
+const STEP_FAILED_FEELING: f32 = -0.2;
+const GIVING_UP_FEELING: f32 = -0.4;
+/// Memories an NPC recalls about each option before choosing
+const RECALLED_FOR_DECISION: usize = 3;
+/// How much a choice that met none of the need it was for counts against it
+const UNMET_CHOICE_IMPACT: f32 = 0.1;
+
+/// Wires the NPC entity bookkeeping into the app.
+pub struct NpcPlugin;
+
+impl Plugin for NpcPlugin {
+    fn build(&self, app: &mut App) {
+        let tasks = HtnDomain::load(HTN_DOMAIN_PATH).unwrap_or_else(|e| {
+            log::warn!("{}; NPCs will plan every goal with GOAP", e);
+            HtnDomain::default()
+        });
+        let utility = UtilityDefinitions::load(UTILITY_PATH).unwrap_or_else(|e| {
+            log::warn!("{}; NPCs will use the built-in utility options", e);
+            UtilityDefinitions::default()
+        });
+        let rules = RuleSet::load(RULES_PATH).unwrap_or_else(|e| {
+            log::warn!("{}; NPCs will reason without rules", e);
+            RuleSet::default()
+        });
+        let places = WorldPlaces::load(PLACES_PATH).unwrap_or_else(|e| {
+            log::warn!("{}; the town has no named places", e);
+            WorldPlaces::default()
+        });
+
+        app.insert_resource(tasks)
+            .insert_resource(utility)
+            .insert_resource(rules)
+            .insert_resource(places)
+            .init_resource::<NpcIndex>()
+            .init_resource::<NeedStations>()
+            .add_systems(Update, (
+                index_npcs,
+                update_memory_context,
+                update_npc_states,
+                take_up_daily_goals,
+                run_plans,
+                choose_npc_actions,
+                finish_errands,
+                converse,
+                satisfy_needs,
+                sleep_and_dream,
+            ).chain());
+    }
+}
+
+/// Looks up the Bevy entity for an NPC id coming from the AI side (social
+/// network, gossip, network messages).
+#[derive(Resource, Debug, Default)]
+pub struct NpcIndex {
+    entities: HashMap<Uuid, Entity>,
+}
+
+/// What an NPC is walking off to do, e.g. sleeping once it gets home, and
+/// how much good its last choice has done so far.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Errand {
+    then: Option<State>,
+    choice: Option<String>,
+    gained: f32,
+}
+
+/// One NPC as a single entity: where it is, how it moves and collides, what it
+/// looks like and what it thinks.
+#[derive(Bundle)]
+pub struct NpcBundle {
+    pub npc_type: NPCType,
+    pub state: NPCState,
+    pub body: BodyBundle,
+    pub sprite: SpriteSheetBundle,
+    pub animation: CharacterAnimation,
+    pub plans: PlanRunner,
+    pub errand: Errand,
+    pub brain: NpcBrainBundle,
+}
+
+impl Errand {
+    /// What the NPC last chose to go and do, e.g. "wash".
+    pub fn choice(&self) -> Option<&str> {
+        self.choice.as_deref()
+    }
+
+    /// Counts meter gained doing `action` toward the last choice, if that
+    /// was what it chose.
+    pub fn credit(&mut self, action: NeedAction, gain: f32) {
+        if self.choice.as_deref() == Some(action.name()) {
+            self.gained += gain;
+        }
+    }
+
+    /// How the last choice turned out, once the next is due: the need it
+    /// was meant to meet rising counts for it, and nothing coming of it
+    /// against it. Choices that meet no need have nothing to show.
+    fn outcome(&mut self) -> Option<DecisionOutcome> {
+        let choice = self.choice.take()?;
+        let gained = std::mem::take(&mut self.gained);
+        let action = Need::ALL.iter().map(Need::action).find(|action| action.name() == choice)?;
+        Some(if gained > 0.0 {
+            DecisionOutcome::new(true, gained, &format!("{} helped", action.name()))
+        } else {
+            DecisionOutcome::new(false, UNMET_CHOICE_IMPACT, &format!("{} came to nothing", action.name()))
+        })
+    }
+}
+
+#[cfg(test)]
+impl Errand {
+    /// An NPC that chose `choice` and is now doing it, for tests of what
+    /// happens once it gets there.
+    pub(crate) fn chosen(choice: &str) -> Self {
+        Self { choice: Some(choice.to_string()), ..Self::default() }
+    }
+}
+
+impl NpcIndex {
+    pub fn get(&self, id: &Uuid) -> Option<Entity> {
+        self.entities.get(id).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+impl NpcBundle {
+    pub fn new(
+        npc: Npc,
+        npc_type: NPCType,
+        character: &str,
+        position: Vec2,
+        texture_atlas: Handle<TextureAtlas>,
+    ) -> Self {
+        Self {
+            npc_type,
+            state: NPCState::default(),
+            body: BodyBundle::new(position, DEFAULT_WALK_SPEED),
+            sprite: SpriteSheetBundle {
+                texture_atlas,
+                transform: Transform::from_translation(position.extend(1.0)),
+                ..default()
+            },
+            animation: CharacterAnimation::new(character),
+            plans: PlanRunner::default(),
+            errand: Errand::default(),
+            brain: npc.into_brain(),
+        }
+    }
+}
+
+/// Everything needed to spawn NPCs from a system: `fn setup(mut npcs: NpcSpawner)`.
+#[derive(SystemParam)]
+pub struct NpcSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    registry: ResMut<'w, CharacterRegistry>,
+    atlases: ResMut<'w, Assets<TextureAtlas>>,
+    config: Option<Res<'w, Config>>,
+    tasks: Option<Res<'w, HtnDomain>>,
+    utility: Option<Res<'w, UtilityDefinitions>>,
+    rules: Option<Res<'w, RuleSet>>,
+}
+
+impl NpcSpawner<'_, '_> {
+    /// Spawns an NPC drawn with the named character's sheet (unknown names get
+    /// the generic villager sheet).
+    pub fn spawn(&mut self, mut npc: Npc, npc_type: NPCType, character: &str, position: Vec2) -> Entity {
+        if let Some(config) = &self.config {
+            npc.memory_mut().set_decay_curve(config.ai.decay_curve(&npc_type));
+        }
+        if let Some(tasks) = &self.tasks {
+            npc.goals_mut().set_task_domain(HtnDomain::clone(tasks));
+        }
+        if let Some(utility) = &self.utility {
+            npc.cognition_mut().set_utility(UtilityDefinitions::clone(utility));
+        }
+        if let Some(rules) = &self.rules {
+            for rule in rules.rules() {
+                npc.cognition_mut().add_rule(rule.clone());
+            }
+        }
+        for (opinion, strength) in npc_type.opinions() {
+            npc.knowledge_mut().beliefs_mut().add_belief(opinion.to_string(), *strength, false);
+        }
+
+        let is_aware = npc.is_aware();
+        let texture_atlas = self.registry
+            .atlas(character, &self.asset_server, &mut self.atlases)
+            .unwrap_or_default();
+
+        let mut entity = self.commands
+            .spawn(NpcBundle::new(npc, npc_type, character, position, texture_atlas));
+        if is_aware {
+            entity.insert(Aware);
+        }
+        entity.id()
+    }
+}
+
+fn index_npcs(
+    mut index: ResMut<NpcIndex>,
+    added: Query<(Entity, &NpcId), Added<NpcId>>,
+    mut removed: RemovedComponents<NpcId>,
+) {
+    for (entity, id) in &added {
+        index.entities.insert(id.0, entity);
+    }
+
+    for entity in removed.read() {
+        index.entities.retain(|_, e| *e != entity);
+    }
+}
+
+fn update_npc_states(clock: Res<TimeSystem>, mut states: Query<&mut NPCState>) {
+    let delta_time = clock.delta_time();
+    for mut state in &mut states {
+        state.update(delta_time);
+    }
+}
+
+/// Each morning (and on the first day), NPCs with a daily goal take it up
+/// and plan it, from their task network where it has one. A second plan,
+/// doing without one of the first's actions, stands by in case the first
+/// falls through. Memories about the goal matter more to them from then on.
+fn take_up_daily_goals(
+    clock: Res<TimeSystem>,
+    mut last_day: Local<Option<u32>>,
+    mut npcs: Query<(&NpcId, &NPCType, &mut GoalSystem, &mut MemorySystem)>,
+) {
+    let day = clock.day_cycle().day();
+    if *last_day == Some(day) {
+        return;
+    }
+    *last_day = Some(day);
+
+    for (id, npc_type, mut goals, mut memory) in &mut npcs {
+        let Some((goal_type, conditions)) = npc_type.daily_goal() else {
+            continue;
+        };
+        if goals.is_pursuing(goal_type) {
+            continue;
+        }
+        match goals.pursue(goal_type, DAILY_GOAL_PRIORITY, &conditions) {
+            Ok(Some(plan_id)) => {
+                memory.learn_from_goal(goal_type);
+                log::debug!("NPC {} set out to {}", id.0, goal_type);
+                if goals.plan_fallback(plan_id, &conditions).is_none() {
+                    log::debug!("NPC {} has no other way to {} if this falls through", id.0, goal_type);
+                }
+            }
+            Ok(None) => log::debug!("NPC {} sees no way to {} today", id.0, goal_type),
+            Err(e) => log::warn!("NPC {} couldn't plan to {}: {}", id.0, goal_type, e),
+        }
+    }
+}
+
+/// Carries out each NPC's current plan: walks it where `Move` steps go and
+/// shows the step under way in its state. NPCs feel and remember the steps
+/// they get done or fail at and the plans they give up on; steps done are to
+/// their credit, failures and giving up are bad luck.
+#[allow(clippy::type_complexity)]
+fn run_plans(
+    clock: Res<TimeSystem>,
+    network: Option<Res<SocialNetwork>>,
+    mut npcs: Query<(
+        &NpcId,
+        &mut PlanRunner,
+        &mut GoalSystem,
+        &mut MovementComponent,
+        &mut NPCState,
+        &mut MemorySystem,
+        &KnowledgeBase,
+        &mut EmotionalState,
+        &mut DialogueSystem,
+    )>,
+) {
+    let delta_time = clock.delta_time();
+    for (id, mut runner, mut goals, mut movement, mut state, mut memory, knowledge, mut emotions, mut dialogue) in &mut npcs {
+        let Some(event) = runner.tick(&mut goals, movement.position(), delta_time) else {
+            continue;
+        };
+        let felt = match event {
+            ExecutionEvent::Started(step) => {
+                let Some(action) = runner.executor().and_then(|executor| executor.current_action()) else {
+                    continue;
+                };
+                if let ActionType::Move { destination } = action.action_type() {
+                    movement.move_to(*destination);
+                }
+                let next = State::from(action.action_type());
+                if *state.current_state() != next {
+                    state.change_state(next);
+                }
+                log::debug!("NPC {} started {}", id.0, step);
+                None
+            }
+            ExecutionEvent::StepDone(step) => {
+                let done = format!("I managed to {}", step.replace('_', " "));
+                Some(Appraisal::new(done, STEP_FEELING).by(Agent::Myself))
+            }
+            ExecutionEvent::StepFailed(step, reason) => {
+                log::debug!("NPC {} failed a step: {}", id.0, reason);
+                Some(Appraisal::new(format!("I couldn't {}", step.replace('_', " ")), STEP_FAILED_FEELING))
+            }
+            ExecutionEvent::Abandoned(reason) => {
+                movement.stop();
+                log::debug!("NPC {} gave up on its plan: {}", id.0, reason);
+                Some(Appraisal::new(format!("I gave up: {}", reason), GIVING_UP_FEELING))
+            }
+            _ => None,
+        };
+        if let Some(event) = felt {
+            memory.add_memory(event.description.clone(), event.outcome, Vec::new());
+            let relationships = network.as_deref().map(|network| (network, id.0));
+            ai_core::appraise(&event, relationships, &mut emotions, &mut dialogue, &goals, knowledge, &memory);
+        }
+    }
+}
+
+/// Picks what each NPC does next, by utility, and sends it to the nearest
+/// place it can do it: a need station, or the nearest NPC for a chat. It
+/// walks there and then shows the choice in its state, so need stations and
+/// animations follow it. Reconsidered every `DECISION_INTERVAL` sim seconds.
+/// NPCs busy with a plan carry on with it. Need options are scored on how far
+/// the nearest station is, and chatting on how far the nearest NPC is and
+/// how well it's liked. Memories close enough to an option lean it the way
+/// they feel, and count as having driven the choice. How much the last
+/// choice met its need is learnt from before the next.
+#[allow(clippy::type_complexity)]
+fn choose_npc_actions(
+    clock: Res<TimeSystem>,
+    places: Res<WorldPlaces>,
+    stations: Res<NeedStations>,
+    network: Option<Res<SocialNetwork>>,
+    mut since_decision: Local<f32>,
+    mut npcs: Query<(
+        &NpcId,
+        &mut MovementComponent,
+        &mut NPCState,
+        &mut Errand,
+        &PlanRunner,
+        &GoalSystem,
+        &PersonalityTraits,
+        &mut CognitionSystem,
+        &mut MemorySystem,
+    )>,
+) {
+    *since_decision += clock.delta_time();
+    if *since_decision < DECISION_INTERVAL {
+        return;
+    }
+    *since_decision = 0.0;
+
+    let day_cycle = clock.day_cycle();
+    let hour = day_cycle.hour() as f32 + day_cycle.minute() as f32 / 60.0;
+    let positions: Vec<(Uuid, Vec2)> = npcs
+        .iter()
+        .map(|(id, movement, ..)| (id.0, movement.position().into()))
+        .collect();
+    for (id, mut movement, mut state, mut errand, plans, goals, personality, mut cognition, mut memory) in &mut npcs {
+        if plans.is_busy() {
+            continue;
+        }
+        if let Some(outcome) = errand.outcome() {
+            cognition.record_outcome(outcome);
+        }
+        let position = movement.position();
+        let mut inputs = UtilityInputs::new(hour).with_needs(goals.needs());
+        let mut destinations = HashMap::new();
+        for need in Need::ALL {
+            let action = need.action();
+            if let Some(station) = stations.nearest(&places, action, position) {
+                inputs = inputs.distance(action.name(), Vec2::from(station).distance(position.into()));
+                destinations.insert(action.name(), station);
+            }
+        }
+        let nearest = positions
+            .iter()
+            .filter(|(other, _)| *other != id.0)
+            .map(|(other, at)| (*other, *at, at.distance(position.into())))
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+        if let Some((other, at, distance)) = nearest {
+            let chat = NeedAction::Chat.name();
+            inputs = inputs.distance(chat, distance);
+            destinations.insert(chat, at.into());
+            let liking = network
+                .as_ref()
+                .and_then(|network| network.get_relationship(id.0, other))
+                .map(|relationship| relationship.get_relationship_score());
+            if let Some(liking) = liking {
+                inputs = inputs.relationship(chat, liking);
+            }
+        }
+
+        let recollections = memory.consult(&cognition.action_options(), RECALLED_FOR_DECISION);
+        for recollection in &recollections {
+            inputs = inputs.memory(&recollection.option, recollection.lean);
+        }
+
+        let Some(action) = cognition.choose_action(&inputs, personality) else {
+            continue;
+        };
+        memory.record_decision(&recollections);
+        errand.choice = Some(action.clone());
+        let next = State::for_action(&action);
+        let destination = destinations
+            .get(action.as_str())
+            .filter(|destination| Vec2::from(**destination).distance(position.into()) > ARRIVAL_DISTANCE);
+        let next = match destination {
+            Some(destination) => {
+                movement.move_to(*destination);
+                errand.then = Some(next);
+                State::Walking
+            }
+            None => {
+                errand.then = None;
+                next
+            }
+        };
+        if *state.current_state() != next {
+            state.change_state(next);
+        }
+    }
+}
+
+/// NPCs that have got where they were going start doing what they went for.
+fn finish_errands(mut npcs: Query<(&MovementComponent, &mut NPCState, &mut Errand)>) {
+    for (movement, mut state, mut errand) in &mut npcs {
+        if movement.is_moving() {
+            continue;
+        }
+        if let Some(next) = errand.then.take() {
+            state.change_state(next);
+        }
+    }
+}
+
+/// Keeps each NPC's memory aware of where and when it is, so new memories
+/// are filed by place and time.
+fn update_memory_context(
+    clock: Res<TimeSystem>,
+    places: Res<WorldPlaces>,
+    mut npcs: Query<(&MovementComponent, &mut MemorySystem)>,
+) {
+    let day_cycle = clock.day_cycle();
+    for (movement, mut memory) in &mut npcs {
+        let position = movement.position();
+        memory.set_context(Some(places.locate(position.x, position.y)), Some(day_cycle));
+    }
+}
+
+/// Who is asleep, and who has fallen asleep since midnight.
+#[derive(Default)]
+struct Sleepers {
+    asleep: HashSet<Entity>,
+    rested: HashSet<Entity>,
+    day: Option<u32>,
+}
+
+/// Once each time an NPC falls asleep: consolidates the day's memories,
+/// then dreams, keeping the most vivid dream to mention in the morning.
+/// NPCs who went a whole day without sleeping consolidate at midnight
+/// anyway, without the dream.
+fn sleep_and_dream(
+    clock: Res<TimeSystem>,
+    mut sleepers: Local<Sleepers>,
+    mut despawned: RemovedComponents<NPCState>,
+    mut npcs: Query<(
+        Entity,
+        &NpcId,
+        &NPCState,
+        &mut MemorySystem,
+        &mut ConsciousnessState,
+        &GoalSystem,
+        &mut DialogueSystem,
+    )>,
+) {
+    let day = clock.day_cycle().day();
+    let midnight = sleepers.day.is_some_and(|last| last != day);
+    sleepers.day = Some(day);
+    for entity in despawned.read() {
+        sleepers.asleep.remove(&entity);
+        sleepers.rested.remove(&entity);
+    }
+
+    for (entity, id, state, mut memory, mut consciousness, goals, mut dialogue) in &mut npcs {
+        if *state.current_state() != State::Sleeping {
+            sleepers.asleep.remove(&entity);
+            if midnight && !sleepers.rested.contains(&entity) && memory.has_unconsolidated() {
+                let report = memory.consolidate();
+                log::debug!(
+                    "NPC {} stayed up: {} merged, {} kept, {} forgotten",
+                    id.0,
+                    report.merged,
+                    report.kept.len(),
+                    report.discarded,
+                );
+            }
+            continue;
+        }
+        if !sleepers.asleep.insert(entity) {
+            continue;
+        }
+        sleepers.rested.insert(entity);
+        if !memory.has_unconsolidated() {
+            continue;
+        }
+
+        let night = consciousness.sleep(&mut memory, goals, &mut dialogue);
+        log::debug!(
+            "NPC {} slept on it: {} merged, {} reflections, {} kept, {} forgotten",
+            id.0,
+            night.report.merged,
+            night.report.reflections.len(),
+            night.report.kept.len(),
+            night.report.discarded,
+        );
+        if let Some(vivid) = most_vivid(&night.dreams) {
+            log::debug!("NPC {} dreamt: {}", id.0, vivid.content);
+        }
+    }
+    if midnight {
+        sleepers.rested.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_core::goals::planning::ActionTemplate;
+    use ai_core::goals::world_state::{Condition, Effect, WorldState};
+    use ai_core::personality::emotions::Emotion;
+    use ai_core::time::SECONDS_PER_DAY;
+    use engine::physics::Vector2;
+
+    fn see_shop(app: &mut App, npc: Entity, open: bool) {
+        let mut world = WorldState::new();
+        world.set_bool("shop_open", open);
+        app.world.get_mut::<GoalSystem>(npc).unwrap().planner_mut().observe(&world);
+    }
+
+    #[test]
+    fn daily_goals_come_with_a_fallback_plan() {
+        let path = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), HTN_DOMAIN_PATH);
+        let mut goals = GoalSystem::default();
+        goals.set_task_domain(HtnDomain::load(&path).unwrap());
+        let mut app = App::new();
+        app.init_resource::<TimeSystem>().add_systems(Update, take_up_daily_goals);
+        let npc = app
+            .world
+            .spawn((NpcId(Uuid::new_v4()), NPCType::Merchant, goals, MemorySystem::default()))
+            .id();
+        app.update();
+
+        let planner = app.world.get::<GoalSystem>(npc).unwrap().planner();
+        let plan = planner.get_plan(planner.current_plan().expect("a plan for the day")).unwrap();
+        assert_eq!(plan.task(), Some("run_shop_for_day"));
+        let fallback = planner.get_plan(plan.fallbacks()[0]).unwrap();
+        assert_eq!(fallback.goal_id(), plan.goal_id());
+        // The same day's work, restocked another way should making goods fail
+        assert_eq!(fallback.task(), Some("run_shop_for_day"));
+        let actions: Vec<&str> = fallback.steps().iter().map(|step| step.action()).collect();
+        assert!(plan.steps().iter().any(|step| step.action() == "craft_goods"));
+        assert!(actions.contains(&"barter_for_stock"));
+        assert!(!actions.contains(&"craft_goods"));
+    }
+
+    #[test]
+    fn npcs_are_proud_of_the_plan_steps_they_get_done() {
+        // Each frame is longer than the step takes
+        let mut clock = TimeSystem::new();
+        clock.update(6.0);
+        let mut app = App::new();
+        app.insert_resource(clock).add_systems(Update, run_plans);
+
+        let mut goals = GoalSystem::default();
+        goals.add_action(ActionTemplate::new("open_shop", 1.0, 5.0).causes(Effect::set("shop_open", true)));
+        let npc = app
+            .world
+            .spawn((
+                NpcId(Uuid::new_v4()),
+                PlanRunner::default(),
+                MovementComponent::new(Vector2::ZERO, 0.0),
+                NPCState::new(),
+                goals,
+                MemorySystem::default(),
+                KnowledgeBase::default(),
+                EmotionalState::default(),
+                DialogueSystem::default(),
+            ))
+            .id();
+        see_shop(&mut app, npc, false);
+        let mut goals = app.world.get_mut::<GoalSystem>(npc).unwrap();
+        let goal = goals.create_goal("open up".to_string(), 0.5, None);
+        goals.plan_goal(goal, "open up", &[Condition::is("shop_open", true)]).unwrap().expect("a plan");
+
+        // Takes up the step, then sees the shop open
+        app.update();
+        see_shop(&mut app, npc, true);
+        app.update();
+
+        let memory = app.world.get::<MemorySystem>(npc).unwrap();
+        assert!(memory.memories().iter().any(|m| m.content() == "I managed to open shop"));
+        let emotions = app.world.get::<EmotionalState>(npc).unwrap();
+        assert!(emotions.get_intensity(Emotion::Pride) > 0.0);
+        assert!(emotions.get_intensity(Emotion::Joy) > 0.0);
+        assert_eq!(emotions.get_intensity(Emotion::Shame), 0.0);
+    }
+
+    #[test]
+    fn falling_asleep_consolidates_the_day_and_dreams() {
+        let mut app = App::new();
+        app.init_resource::<TimeSystem>().add_systems(Update, sleep_and_dream);
+
+        let mut memory = MemorySystem::default();
+        for content in ["I had something to eat", "I was told \"the mill is open\"", "I managed to harvest crops"] {
+            memory.add_memory(content.to_string(), 0.6, Vec::new());
+        }
+        let mut state = NPCState::new();
+        state.change_state(State::Sleeping);
+        let npc = app
+            .world
+            .spawn((
+                NpcId(Uuid::new_v4()),
+                state,
+                memory,
+                ConsciousnessState::new(false),
+                GoalSystem::default(),
+                DialogueSystem::default(),
+            ))
+            .id();
+        app.update();
+
+        let memory = app.world.get::<MemorySystem>(npc).unwrap();
+        assert!(!memory.has_unconsolidated());
+        assert!(!memory.dreams_since(0.0).is_empty());
+    }
+
+    #[test]
+    fn npcs_learn_how_well_their_choices_met_their_needs() {
+        let places = WorldPlaces::load(PLACES_PATH).unwrap();
+        let well = NeedStations::default().nearest(&places, NeedAction::Wash, Vector2::ZERO).unwrap();
+        let mut clock = TimeSystem::new();
+        clock.update(DECISION_INTERVAL);
+        let mut app = App::new();
+        app.insert_resource(clock)
+            .insert_resource(places)
+            .init_resource::<NeedStations>()
+            .add_systems(Update, (choose_npc_actions, satisfy_needs).chain());
+
+        // Washing is all it can think of, and it's already at the well
+        let mut cognition = CognitionSystem::default();
+        cognition.set_utility(
+            ron::from_str(r#"(options: [(name: "wash", considerations: [(input: Need(hygiene), curve: Linear(slope: 1.0, intercept: 0.1))])])"#)
+                .unwrap(),
+        );
+        let mut goals = GoalSystem::default();
+        goals.update(SECONDS_PER_DAY / 2.0);
+        let npc = app
+            .world
+            .spawn((
+                NpcId(Uuid::new_v4()),
+                MovementComponent::new(well, 0.0),
+                NPCState::new(),
+                Errand::default(),
+                PlanRunner::default(),
+                goals,
+                PersonalityTraits::default(),
+                cognition,
+                MemorySystem::default(),
+                KnowledgeBase::default(),
+                EmotionalState::default(),
+                DialogueSystem::default(),
+            ))
+            .id();
+        let washing = |app: &App| -> Vec<(f32, u32)> {
+            let cognition = app.world.get::<CognitionSystem>(npc).unwrap();
+            cognition
+                .learned_values()
+                .into_iter()
+                .filter(|row| row.option == "wash")
+                .map(|row| (row.bandit_value, row.tries))
+                .collect()
+        };
+
+        // Chooses to wash, then washes; nothing to learn from yet
+        app.update();
+        assert!(washing(&app).is_empty());
+
+        // The wash topped hygiene up from 0.6, which counts for it
+        app.update();
+        let learned = washing(&app);
+        assert_eq!(learned.len(), 1);
+        let (value, tries) = learned[0];
+        assert_eq!(tries, 1);
+        assert!((value - 0.4).abs() < 1e-3, "learned {}", value);
+
+        // Already clean, so washing again did nothing
+        app.update();
+        let (value, tries) = washing(&app)[0];
+        assert_eq!(tries, 2);
+        assert!((value - (0.4 - UNMET_CHOICE_IMPACT) / 2.0).abs() < 1e-3, "learned {}", value);
+    }
+}
@@ -0,0 +1,280 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use ai_core::consciousness::ConsciousnessState;
+use ai_core::dialogue::DialogueSystem;
+use ai_core::ecs::NpcId;
+use ai_core::goals::GoalSystem;
+use ai_core::knowledge::KnowledgeBase;
+use ai_core::memory::MemorySystem;
+use ai_core::personality::appraisal::{Agent, Appraisal};
+use ai_core::personality::emotions::EmotionalState;
+use ai_core::social::SocialNetwork;
+use ai_core::story::{LifeStory, StoryOptions};
+use engine::physics::movement::MovementComponent;
+use engine::simulation::time::{TimeSystem, SECONDS_PER_GAME_MINUTE};
+
+use crate::animation::CharacterAnimation;
+use crate::ui::overlay::SpeechEvent;
+
+use super::states::{NPCState, State};
+
+/// How near another NPC has to be to strike up a conversation
+pub(super) const CONVERSATION_RANGE: f32 = 48.0;
+/// Sim seconds an NPC waits after a chat before starting another
+const CHAT_INTERVAL: f32 = SECONDS_PER_GAME_MINUTE * 10.0;
+/// What a socialising NPC says when it has nothing new to tell
+const OPENER: &str = "Hello!";
+/// How sure the NPC who started a chat is that it was heard, face to face
+const FACE_TO_FACE_CERTAINTY: f32 = 0.9;
+/// How far a listener believes someone it knows nothing about
+const UNKNOWN_RELIABILITY: f32 = 0.5;
+/// Beliefs held more firmly than this are worth telling others about
+const BELIEVED: f32 = 0.5;
+/// How much credibility gossip loses each time it's passed on
+const GOSSIP_DISTORTION: f32 = 0.1;
+/// How a line heard in a chat feels to remember
+const CHAT_FEELING: f32 = 0.2;
+
+/// Socialising NPCs tell whoever is nearest something they believe and the
+/// listener hasn't heard, or greet them if there's nothing, and get a reply.
+/// Strangers get an introduction from the speaker's life story instead.
+/// Both lines are said out loud as `SpeechEvent`s, and each NPC remembers
+/// what it heard. What's told travels as gossip, and the listener weighs it
+/// by how far it believes the speaker, and feels it: pleased to be told,
+/// and judging the teller by what the news says against its standards.
+#[allow(clippy::type_complexity)]
+pub fn converse(
+    clock: Res<TimeSystem>,
+    mut network: Option<ResMut<SocialNetwork>>,
+    mut cooldowns: Local<HashMap<Entity, f32>>,
+    mut speech: EventWriter<SpeechEvent>,
+    mut npcs: Query<(
+        Entity,
+        &NpcId,
+        &NPCState,
+        &MovementComponent,
+        &mut KnowledgeBase,
+        &mut DialogueSystem,
+        &mut MemorySystem,
+        &GoalSystem,
+        &mut EmotionalState,
+        &ConsciousnessState,
+        Option<&CharacterAnimation>,
+    )>,
+) {
+    let delta_time = clock.delta_time();
+    cooldowns.retain(|_, left| {
+        *left -= delta_time;
+        *left > 0.0
+    });
+
+    let positions: Vec<(Entity, Vec2)> = npcs
+        .iter()
+        .map(|(entity, _, _, movement, ..)| (entity, movement.position().into()))
+        .collect();
+    let starters: Vec<(Entity, Vec2)> = npcs
+        .iter()
+        .filter(|(entity, _, state, ..)| {
+            *state.current_state() == State::Socializing && !cooldowns.contains_key(entity)
+        })
+        .map(|(entity, _, _, movement, ..)| (entity, movement.position().into()))
+        .collect();
+
+    for (speaker, position) in starters {
+        if cooldowns.contains_key(&speaker) {
+            continue;
+        }
+        let nearest = positions
+            .iter()
+            .filter(|(other, _)| *other != speaker && !cooldowns.contains_key(other))
+            .map(|(other, at)| (*other, position.distance(*at)))
+            .filter(|(_, distance)| *distance <= CONVERSATION_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((listener, _)) = nearest else {
+            continue;
+        };
+        let Ok([from, to]) = npcs.get_many_mut([speaker, listener]) else {
+            continue;
+        };
+        let (_, speaker_id, _, _, mut speaker_knowledge, speaker_dialogue, mut speaker_memory, speaker_goals, _, speaker_consciousness, speaker_character) = from;
+        let (_, listener_id, _, _, mut listener_knowledge, mut listener_dialogue, mut listener_memory, listener_goals, mut listener_emotions, ..) = to;
+        let (speaker_id, listener_id) = (speaker_id.0, listener_id.0);
+        let stranger = speaker_memory.encounters_with(listener_id).is_empty();
+
+        let believed: Vec<String> = speaker_knowledge
+            .beliefs()
+            .iter()
+            .filter(|(_, strength)| *strength > BELIEVED)
+            .map(|(content, _)| content.to_string())
+            .collect();
+        // No gossip with someone the speaker has never met
+        let topic = (!stranger)
+            .then(|| speaker_dialogue.choose_topic(listener_id, &believed, speaker_knowledge.minds()))
+            .flatten();
+        let line = match &topic {
+            Some(topic) => topic.clone(),
+            None if stranger => {
+                let options = StoryOptions {
+                    name: speaker_character.map(|character| character.character.clone()),
+                    ..StoryOptions::default()
+                };
+                LifeStory::build(speaker_id, &speaker_memory, speaker_goals, speaker_consciousness, &options).introduction()
+            }
+            None => OPENER.to_string(),
+        };
+
+        if let Some(topic) = topic {
+            let conviction = speaker_knowledge.beliefs().get_belief_strength(&topic).unwrap_or(BELIEVED);
+            let mut credibility = conviction;
+            let mut reliability = UNKNOWN_RELIABILITY;
+            if let Some(network) = network.as_deref_mut() {
+                reliability = network.source_reliability(listener_id, speaker_id);
+                let gossip = network
+                    .gossip()
+                    .get_npc_known_gossip(&speaker_id)
+                    .into_iter()
+                    .find(|gossip| gossip.content() == topic)
+                    .map(|gossip| gossip.id());
+                let gossip = gossip.unwrap_or_else(|| network.spread_information(speaker_id, topic.clone(), conviction));
+                if network.pass_on_gossip(gossip, speaker_id, listener_id, GOSSIP_DISTORTION) {
+                    if let Some((content, passed_on, path)) = network.gossip().describe(gossip) {
+                        listener_knowledge.minds_mut().heard_gossip(listener_id, speaker_id, path, content, passed_on);
+                        speaker_knowledge.minds_mut().told_gossip(speaker_id, listener_id, content, passed_on);
+                        credibility = passed_on;
+                    }
+                }
+            }
+            let source = speaker_id.to_string();
+            listener_knowledge.beliefs_mut().hear(&topic, true, credibility, &source, reliability);
+            let news = Appraisal::new(topic.clone(), CHAT_FEELING).by(Agent::Other(speaker_id));
+            ai_core::appraise(
+                &news,
+                network.as_deref().map(|network| (network, listener_id)),
+                &mut listener_emotions,
+                &mut listener_dialogue,
+                listener_goals,
+                &listener_knowledge,
+                &listener_memory,
+            );
+        }
+        let reply = ai_core::respond(
+            listener_id,
+            speaker_id,
+            &line,
+            &mut listener_knowledge,
+            &mut listener_dialogue,
+            &mut listener_memory,
+        );
+        let minds = speaker_knowledge.minds_mut();
+        minds.observe_statement(speaker_id, &[listener_id], &line, FACE_TO_FACE_CERTAINTY);
+        minds.observe_statement(listener_id, &[speaker_id], reply.content(), FACE_TO_FACE_CERTAINTY);
+        listener_memory.add_memory(format!("I was told \"{}\"", line), CHAT_FEELING, vec![speaker_id]);
+        speaker_memory.add_memory(format!("I was told \"{}\"", reply.content()), CHAT_FEELING, vec![listener_id]);
+
+        speech.send(SpeechEvent { speaker, text: line });
+        speech.send(SpeechEvent { speaker: listener, text: reply.content().to_string() });
+        cooldowns.insert(speaker, CHAT_INTERVAL);
+        cooldowns.insert(listener, CHAT_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_core::personality::emotions::Emotion;
+    use engine::physics::Vector2;
+    use uuid::Uuid;
+
+    fn npc(app: &mut App, state: State, x: f32, belief: Option<&str>) -> (Entity, Uuid) {
+        let id = Uuid::new_v4();
+        let mut knowledge = KnowledgeBase::default();
+        if let Some(belief) = belief {
+            knowledge.beliefs_mut().add_belief(belief.to_string(), 0.8, false);
+        }
+        let mut npc_state = NPCState::new();
+        npc_state.change_state(state);
+        let entity = app
+            .world
+            .spawn((
+                NpcId(id),
+                npc_state,
+                MovementComponent::new(Vector2::new(x, 0.0), 0.0),
+                knowledge,
+                DialogueSystem::default(),
+                MemorySystem::default(),
+                GoalSystem::default(),
+                EmotionalState::default(),
+                ConsciousnessState::new(false),
+            ))
+            .id();
+        (entity, id)
+    }
+
+    fn town() -> App {
+        let mut app = App::new();
+        let mut clock = TimeSystem::new();
+        clock.update(SECONDS_PER_GAME_MINUTE);
+        app.insert_resource(clock)
+            .init_resource::<SocialNetwork>()
+            .add_event::<SpeechEvent>()
+            .add_systems(Update, converse);
+        app
+    }
+
+    /// `npc` remembers having met `other` before
+    fn acquaint(app: &mut App, npc: Entity, other: Uuid) {
+        let mut memory = app.world.get_mut::<MemorySystem>(npc).unwrap();
+        memory.add_memory("We said hello in passing".to_string(), 0.1, vec![other]);
+    }
+
+    #[test]
+    fn strangers_introduce_themselves_before_gossiping() {
+        let mut app = town();
+        let (speaker, _) = npc(&mut app, State::Socializing, 0.0, Some("the mill is open"));
+        app.world.entity_mut(speaker).insert(CharacterAnimation::new("Sarah"));
+        let (listener, _) = npc(&mut app, State::Idle, 16.0, None);
+        app.update();
+
+        let memory = app.world.get::<MemorySystem>(listener).unwrap();
+        assert!(memory.memories().iter().any(|m| m.content() == "I was told \"I'm Sarah. I've lived only since today.\""));
+        let knowledge = app.world.get::<KnowledgeBase>(listener).unwrap();
+        assert!(knowledge.beliefs().get_belief_strength("the mill is open").is_none());
+    }
+
+    #[test]
+    fn socialising_npcs_pass_on_what_they_believe_as_gossip() {
+        let mut app = town();
+        let (teller, speaker) = npc(&mut app, State::Socializing, 0.0, Some("the mill is open"));
+        let (listener, heard_by) = npc(&mut app, State::Idle, 16.0, None);
+        acquaint(&mut app, teller, heard_by);
+        app.update();
+
+        let memory = app.world.get::<MemorySystem>(listener).unwrap();
+        assert!(memory.memories().iter().any(|m| m.content() == "I was told \"the mill is open\""));
+        let knowledge = app.world.get::<KnowledgeBase>(listener).unwrap();
+        assert!(knowledge.beliefs().get_belief_strength("the mill is open").unwrap() > 0.5);
+        // The listener knows the speaker holds it
+        assert!(knowledge.minds().knows(speaker, "the mill is open"));
+
+        let network = app.world.resource::<SocialNetwork>();
+        let told = network.gossip().get_npc_known_gossip(&heard_by);
+        assert_eq!(told.len(), 1);
+        let path = network.gossip().get_propagation_path(&told[0].id()).unwrap();
+        assert_eq!(path, &vec![speaker, heard_by]);
+    }
+
+    #[test]
+    fn listeners_judge_gossip_by_their_standards() {
+        let mut app = town();
+        let (teller, _) = npc(&mut app, State::Socializing, 0.0, Some("the miller is stealing flour"));
+        let (listener, heard_by) = npc(&mut app, State::Idle, 16.0, Some("stealing is wrong"));
+        acquaint(&mut app, teller, heard_by);
+        app.update();
+
+        let emotions = app.world.get::<EmotionalState>(listener).unwrap();
+        assert!(emotions.get_intensity(Emotion::Reproach) > 0.0);
+        assert_eq!(emotions.get_intensity(Emotion::Admiration), 0.0);
+        assert!(emotions.get_intensity(Emotion::Joy) > 0.0);
+    }
+}
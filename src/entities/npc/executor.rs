@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use ai_core::goals::planning::{PlanStep, StepOutcome, Supervision};
+use ai_core::goals::world_state::{Effect, WorldState};
+use ai_core::goals::GoalSystem;
+use engine::physics::Vector2;
+
+use super::actions::{Action, ActionTarget, ActionType};
+
+/// How close counts as having arrived at a `Move` destination
+const ARRIVAL_DISTANCE: f32 = 8.0;
+/// How many times its expected duration a step may take before it's given up
+const STEP_PATIENCE: f32 = 3.0;
+
+/// How planner action names turn into things NPCs can actually do.
+/// Names without a binding become work on a task of that name.
+#[derive(Debug, Clone, Default)]
+pub struct ActionLibrary {
+    bindings: HashMap<String, (ActionType, Option<ActionTarget>)>,
+}
+
+/// Runs a plan one `Action` at a time, checking it against the world
+/// between steps and following it through repairs and fallbacks.
+#[derive(Debug, Clone)]
+pub struct PlanExecutor {
+    plan_id: Uuid,
+    running: Option<RunningStep>,
+}
+
+#[derive(Debug, Clone)]
+struct RunningStep {
+    name: String,
+    action: Action,
+    /// The world when the step started, to see what it changed
+    before: WorldState,
+    effects: Vec<Effect>,
+    elapsed: f32,
+    patience: f32,
+}
+
+/// An NPC's own action bindings and the plan it's carrying out, picked up
+/// from whatever its goals settle on.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct PlanRunner {
+    library: ActionLibrary,
+    executor: Option<PlanExecutor>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecutionEvent {
+    Started(String),
+    Running,
+    StepDone(String),
+    /// A step ran out of time or was stopped; carries the step and why
+    StepFailed(String, String),
+    Repaired,
+    SwitchedPlan(Uuid),
+    Finished,
+    Abandoned(String),
+}
+
+impl ActionLibrary {
+    pub fn bind(&mut self, name: &str, action_type: ActionType, target: Option<ActionTarget>) {
+        self.bindings.insert(name.to_string(), (action_type, target));
+    }
+
+    pub fn action_for(&self, step: &PlanStep) -> Action {
+        let (action_type, target) = self.bindings
+            .get(step.action())
+            .cloned()
+            .unwrap_or_else(|| (ActionType::Work { task: step.action().to_string() }, None));
+        Action::new(action_type, target, step.expected_duration())
+    }
+}
+
+impl PlanRunner {
+    pub fn new(library: ActionLibrary) -> Self {
+        Self { library, executor: None }
+    }
+
+    pub fn library_mut(&mut self) -> &mut ActionLibrary {
+        &mut self.library
+    }
+
+    pub fn executor(&self) -> Option<&PlanExecutor> {
+        self.executor.as_ref()
+    }
+
+    /// Whether a plan is under way, so other behaviour should keep out.
+    pub fn is_busy(&self) -> bool {
+        self.executor.is_some()
+    }
+
+    /// Advances the plan under way, or takes up the goals' current plan.
+    /// `None` when there's nothing to do.
+    pub fn tick(&mut self, goals: &mut GoalSystem, position: Vector2, delta_time: f32) -> Option<ExecutionEvent> {
+        if self.executor.is_none() {
+            self.executor = goals.planner().current_plan().map(PlanExecutor::new);
+        }
+        let event = self.executor.as_mut()?.tick(goals, &self.library, position, delta_time);
+        if matches!(event, ExecutionEvent::Finished | ExecutionEvent::Abandoned(_)) {
+            self.executor = None;
+        }
+        Some(event)
+    }
+}
+
+impl PlanExecutor {
+    pub fn new(plan_id: Uuid) -> Self {
+        Self { plan_id, running: None }
+    }
+
+    /// The plan being followed; changes when a fallback takes over.
+    pub fn plan_id(&self) -> Uuid {
+        self.plan_id
+    }
+
+    pub fn current_action(&self) -> Option<&Action> {
+        self.running.as_ref().map(|running| &running.action)
+    }
+
+    /// Advances the plan by `delta_time`. A step succeeds once the observed
+    /// world shows its effects (and a `Move` has arrived at `position`), and
+    /// fails if that hasn't happened well past its expected duration.
+    pub fn tick(
+        &mut self,
+        goals: &mut GoalSystem,
+        library: &ActionLibrary,
+        position: Vector2,
+        delta_time: f32,
+    ) -> ExecutionEvent {
+        // Step 1: keep going with whatever is under way
+        if let Some(running) = self.running.as_mut() {
+            running.elapsed += delta_time;
+            let timer_done = running.action.update(delta_time);
+            let arrived = match running.action.action_type() {
+                ActionType::Move { destination } => distance(position, *destination) <= ARRIVAL_DISTANCE,
+                _ => timer_done,
+            };
+            let done = arrived && goals.planner().world().progress(&running.before, &running.effects) >= 1.0;
+
+            let name = running.name.clone();
+            let (outcome, event) = if done {
+                (StepOutcome::Success, ExecutionEvent::StepDone(name))
+            } else if running.elapsed >= running.patience {
+                let reason = format!("{} didn't get done", name);
+                (StepOutcome::Failure(reason.clone()), ExecutionEvent::StepFailed(name, reason))
+            } else {
+                return ExecutionEvent::Running;
+            };
+            self.running = None;
+            goals.complete_step(self.plan_id, outcome);
+            return event;
+        }
+
+        // Step 2: between steps, make sure the plan still holds up
+        match goals.supervise_plan(self.plan_id) {
+            Supervision::Continue => {}
+            Supervision::Completed => return ExecutionEvent::Finished,
+            Supervision::Repaired => return ExecutionEvent::Repaired,
+            Supervision::SwitchedTo(fallback) => {
+                self.plan_id = fallback;
+                return ExecutionEvent::SwitchedPlan(fallback);
+            }
+            Supervision::Abandoned(reason) => return ExecutionEvent::Abandoned(reason),
+        }
+
+        // Step 3: start the next step
+        let Some(step) = goals.planner().get_plan(self.plan_id).and_then(|plan| plan.current_step()) else {
+            return ExecutionEvent::Finished;
+        };
+        let name = step.action().to_string();
+        self.running = Some(RunningStep {
+            name: name.clone(),
+            action: library.action_for(step),
+            before: goals.planner().world().clone(),
+            // Only what the NPC can see for itself can show the step worked
+            effects: step.effects()
+                .iter()
+                .filter(|effect| goals.planner().is_observed(&effect.fact))
+                .cloned()
+                .collect(),
+            elapsed: 0.0,
+            patience: step.expected_duration() * STEP_PATIENCE,
+        });
+        ExecutionEvent::Started(name)
+    }
+
+    /// The world stopped the current action, e.g. a path was blocked or a
+    /// trade refused. The step is retried or planned around on the next tick.
+    pub fn interrupt(&mut self, goals: &mut GoalSystem, reason: &str) {
+        if self.running.take().is_some() {
+            goals.complete_step(self.plan_id, StepOutcome::Failure(reason.to_string()));
+        }
+    }
+}
+
+fn distance(a: Vector2, b: Vector2) -> f32 {
+    Vector2::new(a.x - b.x, a.y - b.y).length()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_core::goals::planning::ActionTemplate;
+    use ai_core::goals::world_state::Condition;
+
+    /// An NPC who knows `actions` and sees `facts`, set on reaching `goal`
+    fn planned(actions: Vec<ActionTemplate>, facts: &[(&str, bool)], goal: &[Condition]) -> (GoalSystem, Uuid) {
+        let mut goals = GoalSystem::default();
+        for template in actions {
+            goals.add_action(template);
+        }
+        see(&mut goals, facts);
+        let goal_id = goals.create_goal("test".to_string(), 0.5, None);
+        let plan_id = goals.plan_goal(goal_id, "test", goal).unwrap().expect("a plan");
+        (goals, plan_id)
+    }
+
+    fn see(goals: &mut GoalSystem, facts: &[(&str, bool)]) {
+        let mut world = WorldState::new();
+        for (fact, value) in facts {
+            world.set_bool(fact, *value);
+        }
+        goals.planner_mut().observe(&world);
+    }
+
+    fn success_rate(goals: &GoalSystem, action: &str) -> f32 {
+        goals.planner().action_template(action).unwrap().success_rate()
+    }
+
+    fn open_shop() -> ActionTemplate {
+        ActionTemplate::new("open_shop", 1.0, 5.0).causes(Effect::set("shop_open", true))
+    }
+
+    #[test]
+    fn a_step_is_done_once_its_effects_are_seen() {
+        let (mut goals, plan_id) = planned(
+            vec![open_shop().with_success_rate(0.5)],
+            &[("shop_open", false)],
+            &[Condition::is("shop_open", true)],
+        );
+        let library = ActionLibrary::default();
+        let mut executor = PlanExecutor::new(plan_id);
+
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.0), ExecutionEvent::Started("open_shop".to_string()));
+        assert!(matches!(executor.current_action().map(Action::action_type), Some(ActionType::Work { .. })));
+        // The time is up but the shop still looks shut
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 5.0), ExecutionEvent::Running);
+
+        see(&mut goals, &[("shop_open", true)]);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.1), ExecutionEvent::StepDone("open_shop".to_string()));
+        assert!((success_rate(&goals, "open_shop") - 0.55).abs() < 1e-5);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.1), ExecutionEvent::Finished);
+    }
+
+    #[test]
+    fn a_step_that_takes_too_long_fails() {
+        let (mut goals, plan_id) = planned(vec![open_shop()], &[("shop_open", false)], &[Condition::is("shop_open", true)]);
+        let library = ActionLibrary::default();
+        let mut executor = PlanExecutor::new(plan_id);
+
+        executor.tick(&mut goals, &library, Vector2::ZERO, 0.0);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 10.0), ExecutionEvent::Running);
+        assert_eq!(
+            executor.tick(&mut goals, &library, Vector2::ZERO, 5.0),
+            ExecutionEvent::StepFailed("open_shop".to_string(), "open_shop didn't get done".to_string()),
+        );
+        assert!(executor.current_action().is_none());
+        assert!((success_rate(&goals, "open_shop") - 0.9).abs() < 1e-5);
+        let step = goals.planner().get_plan(plan_id).and_then(|plan| plan.current_step()).unwrap();
+        assert_eq!(step.action(), "open_shop", "a failed step stays to be retried");
+    }
+
+    #[test]
+    fn a_move_is_done_on_arrival() {
+        let (mut goals, plan_id) = planned(
+            vec![ActionTemplate::new("walk_to_well", 1.0, 10.0).causes(Effect::set("at_well", true))],
+            &[],
+            &[Condition::is("at_well", true)],
+        );
+        let well = Vector2::new(100.0, 0.0);
+        let mut library = ActionLibrary::default();
+        library.bind("walk_to_well", ActionType::Move { destination: well }, None);
+        let mut executor = PlanExecutor::new(plan_id);
+
+        executor.tick(&mut goals, &library, Vector2::ZERO, 0.0);
+        assert!(matches!(executor.current_action().map(Action::action_type), Some(ActionType::Move { .. })));
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::new(50.0, 0.0), 12.0), ExecutionEvent::Running);
+        assert_eq!(
+            executor.tick(&mut goals, &library, Vector2::new(95.0, 0.0), 1.0),
+            ExecutionEvent::StepDone("walk_to_well".to_string()),
+        );
+    }
+
+    #[test]
+    fn a_plan_the_world_broke_is_repaired() {
+        let (mut goals, plan_id) = planned(
+            vec![
+                ActionTemplate::new("enter_shop", 1.0, 5.0)
+                    .requires(Condition::is("door_unlocked", true))
+                    .causes(Effect::set("in_shop", true)),
+                ActionTemplate::new("unlock_door", 1.0, 5.0).causes(Effect::set("door_unlocked", true)),
+            ],
+            &[("door_unlocked", true)],
+            &[Condition::is("in_shop", true)],
+        );
+        let library = ActionLibrary::default();
+        let mut executor = PlanExecutor::new(plan_id);
+
+        see(&mut goals, &[("door_unlocked", false)]);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.0), ExecutionEvent::Repaired);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.0), ExecutionEvent::Started("unlock_door".to_string()));
+    }
+
+    #[test]
+    fn a_hopeless_plan_switches_to_its_fallback_or_is_abandoned() {
+        let actions = || {
+            vec![
+                ActionTemplate::new("buy_bread", 1.0, 5.0)
+                    .requires(Condition::is("has_gold", true))
+                    .causes(Effect::set("fed", true)),
+                ActionTemplate::new("beg", 1.0, 5.0).causes(Effect::set("begged", true)),
+            ]
+        };
+        let fed = [Condition::is("fed", true)];
+        let library = ActionLibrary::default();
+
+        let (mut goals, plan_id) = planned(actions(), &[("has_gold", true)], &fed);
+        let fallback = goals.plan_fallback(plan_id, &[Condition::is("begged", true)]).unwrap();
+        let mut executor = PlanExecutor::new(plan_id);
+        see(&mut goals, &[("has_gold", false)]);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.0), ExecutionEvent::SwitchedPlan(fallback));
+        assert_eq!(executor.plan_id(), fallback);
+        assert_eq!(executor.tick(&mut goals, &library, Vector2::ZERO, 0.0), ExecutionEvent::Started("beg".to_string()));
+
+        // With nothing to fall back on the runner lets the plan go
+        let (mut goals, _) = planned(actions(), &[("has_gold", true)], &fed);
+        let mut runner = PlanRunner::new(library);
+        see(&mut goals, &[("has_gold", false)]);
+        assert_eq!(
+            runner.tick(&mut goals, Vector2::ZERO, 0.0),
+            Some(ExecutionEvent::Abandoned("No known way to bring about fed".to_string())),
+        );
+        assert!(!runner.is_busy());
+        assert!(goals.get_active_goals().is_empty());
+    }
+}
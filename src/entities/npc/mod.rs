@@ -0,0 +1,53 @@
+pub mod states;
+pub mod actions;
+pub mod executor;
+#[cfg(feature = "render")]
+pub mod bundle;
+#[cfg(feature = "render")]
+pub mod conversation;
+#[cfg(feature = "render")]
+pub mod needs;
+
+use serde::{Serialize, Deserialize};
+
+use ai_core::goals::world_state::Condition;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub enum NPCType {
+    Villager,
+    Merchant,
+    Guard,
+    Wanderer,
+}
+
+impl NPCType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NPCType::Villager => "villager",
+            NPCType::Merchant => "merchant",
+            NPCType::Guard => "guard",
+            NPCType::Wanderer => "wanderer",
+        }
+    }
+
+    /// Goal type the NPC takes up each morning, and what it means to have
+    /// done it when no task network covers the type.
+    pub fn daily_goal(&self) -> Option<(&'static str, Vec<Condition>)> {
+        match self {
+            NPCType::Merchant => Some(("run shop", vec![Condition::at_least("gold", 3.0)])),
+            _ => None,
+        }
+    }
+
+    /// What the NPC thinks from the start, and how firmly; its first things
+    /// to talk about.
+    pub fn opinions(&self) -> &'static [(&'static str, f32)] {
+        match self {
+            NPCType::Villager => &[("the harvest will be good this year", 0.7), ("the well water is clean", 0.6)],
+            NPCType::Merchant => &[("prices at the market are fair", 0.8), ("the roads are safe for traders", 0.6)],
+            NPCType::Guard => &[("the town is safe at night", 0.7), ("strangers were seen by the river", 0.6)],
+            NPCType::Wanderer => &[("the roads are not safe for traders", 0.7), ("there is work in the next town", 0.6)],
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use ai_core::dialogue::DialogueSystem;
+use ai_core::ecs::NpcId;
+use ai_core::goals::needs::NeedAction;
+use ai_core::goals::GoalSystem;
+use ai_core::knowledge::KnowledgeBase;
+use ai_core::memory::MemorySystem;
+use ai_core::personality::appraisal::Appraisal;
+use ai_core::personality::emotions::EmotionalState;
+use ai_core::social::SocialNetwork;
+use engine::physics::movement::MovementComponent;
+use engine::physics::Vector2;
+use engine::simulation::time::TimeSystem;
+
+use crate::entities::places::WorldPlaces;
+
+use super::bundle::Errand;
+use super::conversation::CONVERSATION_RANGE;
+use super::states::{NPCState, State};
+
+/// Tiles from a station an NPC can be and still use it
+const STATION_REACH: i32 = 1;
+/// How well needs are met anywhere without a proper station
+const MAKESHIFT_QUALITY: f32 = 0.4;
+/// How meeting a need feels to remember
+const RELIEF: f32 = 0.3;
+
+/// Where in town each need gets met: what an NPC has to be doing, and near
+/// which place, for its meter to refill. Idling looks the same whether or
+/// not an NPC means to use the station it's standing by, so stations keyed
+/// on `State::Idle` only count for NPCs that chose to go there.
+#[derive(Resource, Debug, Clone)]
+pub struct NeedStations {
+    pub stations: Vec<NeedStation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NeedStation {
+    /// Matched against place names, e.g. "tavern counter" or "house"
+    pub place: String,
+    pub state: State,
+    pub action: NeedAction,
+    /// 0..1; how well the station meets the need
+    pub quality: f32,
+}
+
+impl Default for NeedStations {
+    fn default() -> Self {
+        let station = |place: &str, state, action, quality| NeedStation {
+            place: place.to_string(),
+            state,
+            action,
+            quality,
+        };
+
+        Self {
+            stations: vec![
+                station("tavern counter", State::Trading, NeedAction::Eat, 1.0),
+                station("tavern", State::Trading, NeedAction::Eat, 0.8),
+                station("house", State::Sleeping, NeedAction::Sleep, 1.0),
+                station("inn", State::Sleeping, NeedAction::Sleep, 0.9),
+                station("well", State::Idle, NeedAction::Wash, 1.0),
+                station("bathhouse", State::Idle, NeedAction::Wash, 1.0),
+            ],
+        }
+    }
+}
+
+impl NeedStations {
+    /// What an NPC doing `state` at `position`, having last chosen
+    /// `chosen`, is doing for its needs, and how well. `has_company` is
+    /// whether anyone is near enough to talk to.
+    pub fn action_at(
+        &self,
+        places: &WorldPlaces,
+        state: &State,
+        position: Vector2,
+        chosen: Option<&str>,
+        has_company: bool,
+    ) -> Option<(NeedAction, f32)> {
+        let station = self.stations.iter().find(|station| {
+            station.state == *state
+                && (station.state != State::Idle || chosen == Some(station.action.name()))
+                && places.is_near(&station.place, position.x, position.y, STATION_REACH)
+        });
+        if let Some(station) = station {
+            return Some((station.action, station.quality));
+        }
+
+        // Company is company anywhere there's someone to talk to; a nap on
+        // the ground still counts a little
+        match state {
+            State::Socializing if has_company => Some((NeedAction::Chat, 1.0)),
+            State::Sleeping => Some((NeedAction::Sleep, MAKESHIFT_QUALITY)),
+            _ => None,
+        }
+    }
+
+    /// Where the nearest station is that an NPC choosing `action` would use,
+    /// in world space.
+    pub fn nearest(&self, places: &WorldPlaces, action: NeedAction, position: Vector2) -> Option<Vector2> {
+        let state = State::for_action(action.name());
+        let position = Vec2::from(position);
+        self.stations
+            .iter()
+            .filter(|station| station.action == action && station.state == state)
+            .filter_map(|station| places.nearest(&station.place, position.x, position.y))
+            .map(|(x, y)| Vec2::new(x, y))
+            .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)))
+            .map(Vector2::from)
+    }
+}
+
+/// Refills needs for NPCs busy at an interaction point, by sim time, and
+/// credits what they gain to the choice that sent them there. NPCs
+/// remember each time they start meeting a need, and are as pleased as the
+/// station is good.
+#[allow(clippy::type_complexity)]
+pub fn satisfy_needs(
+    clock: Res<TimeSystem>,
+    places: Res<WorldPlaces>,
+    stations: Res<NeedStations>,
+    network: Option<Res<SocialNetwork>>,
+    mut meeting: Local<HashMap<Entity, NeedAction>>,
+    mut npcs: Query<(
+        Entity,
+        &NpcId,
+        &MovementComponent,
+        &NPCState,
+        &mut GoalSystem,
+        &mut MemorySystem,
+        &KnowledgeBase,
+        &mut EmotionalState,
+        &mut DialogueSystem,
+        Option<&mut Errand>,
+    )>,
+) {
+    let delta_time = clock.delta_time();
+    let positions: Vec<(Entity, Vec2)> = npcs
+        .iter()
+        .map(|(entity, _, movement, ..)| (entity, movement.position().into()))
+        .collect();
+    for (entity, id, movement, state, mut goals, mut memory, knowledge, mut emotions, mut dialogue, errand) in &mut npcs {
+        let position = movement.position();
+        let has_company = positions.iter().any(|(other, at)| {
+            *other != entity && at.distance(Vec2::from(position)) <= CONVERSATION_RANGE
+        });
+        let chosen = errand.as_deref().and_then(Errand::choice);
+        let Some((action, quality)) = stations.action_at(&places, state.current_state(), position, chosen, has_company) else {
+            meeting.remove(&entity);
+            continue;
+        };
+        let gain = goals.perform(action, delta_time, quality);
+        if let Some(mut errand) = errand {
+            errand.credit(action, gain);
+        }
+        if meeting.insert(entity, action) != Some(action) {
+            memory.add_memory(recollection(action).to_string(), RELIEF, Vec::new());
+            let relief = Appraisal::new(recollection(action), quality);
+            let relationships = network.as_deref().map(|network| (network, id.0));
+            ai_core::appraise(&relief, relationships, &mut emotions, &mut dialogue, &goals, knowledge, &memory);
+        }
+    }
+}
+
+/// How an NPC remembers meeting a need.
+fn recollection(action: NeedAction) -> &'static str {
+    match action {
+        NeedAction::Eat => "I had something to eat",
+        NeedAction::Sleep => "I got some sleep",
+        NeedAction::Wash => "I had a wash",
+        NeedAction::Chat => "I enjoyed some company",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_core::goals::needs::Need;
+    use ai_core::personality::emotions::Emotion;
+    use ai_core::time::SECONDS_PER_DAY;
+
+    use crate::entities::places::PLACES_PATH;
+
+    #[test]
+    fn stations_are_found_for_the_state_the_npc_will_be_in() {
+        let places = WorldPlaces::load(PLACES_PATH).unwrap();
+        let stations = NeedStations::default();
+
+        // Eating shows as trading, so it's the tavern, not the inn
+        let eat = stations.nearest(&places, NeedAction::Eat, Vector2::ZERO).unwrap();
+        assert!(places.is_near("tavern", eat.x, eat.y, 0));
+        assert!(!places.is_near("inn", eat.x, eat.y, 0));
+        assert!(stations.nearest(&places, NeedAction::Chat, Vector2::ZERO).is_none());
+    }
+
+    #[test]
+    fn needs_are_only_met_on_purpose_or_in_company() {
+        let places = WorldPlaces::load(PLACES_PATH).unwrap();
+        let stations = NeedStations::default();
+        let well = stations.nearest(&places, NeedAction::Wash, Vector2::ZERO).unwrap();
+
+        // Idling by the well isn't washing unless that's what it came for
+        assert_eq!(stations.action_at(&places, &State::Idle, well, None, false), None);
+        assert_eq!(stations.action_at(&places, &State::Idle, well, Some("chat"), false), None);
+        assert_eq!(stations.action_at(&places, &State::Idle, well, Some("wash"), false), Some((NeedAction::Wash, 1.0)));
+
+        // Nobody to talk to, nothing for the social need
+        assert_eq!(stations.action_at(&places, &State::Socializing, well, None, false), None);
+        assert_eq!(stations.action_at(&places, &State::Socializing, well, None, true), Some((NeedAction::Chat, 1.0)));
+    }
+
+    #[test]
+    fn washing_at_the_well_restores_hygiene() {
+        let places = WorldPlaces::load(PLACES_PATH).unwrap();
+        let well = NeedStations::default().nearest(&places, NeedAction::Wash, Vector2::ZERO).unwrap();
+        let mut goals = GoalSystem::default();
+        goals.update(SECONDS_PER_DAY / 2.0);
+        let before = goals.needs().value(Need::Hygiene);
+
+        let mut app = App::new();
+        let mut clock = TimeSystem::new();
+        clock.update(60.0);
+        app.insert_resource(clock)
+            .insert_resource(places)
+            .init_resource::<NeedStations>()
+            .add_systems(Update, satisfy_needs);
+        let npc = app
+            .world
+            .spawn((
+                NpcId(uuid::Uuid::new_v4()),
+                MovementComponent::new(well, 0.0),
+                NPCState::new(),
+                Errand::chosen("wash"),
+                goals,
+                MemorySystem::default(),
+                KnowledgeBase::default(),
+                EmotionalState::default(),
+                DialogueSystem::default(),
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let after = app.world.get::<GoalSystem>(npc).unwrap().needs().value(Need::Hygiene);
+        assert!(after > before, "hygiene went from {} to {}", before, after);
+        // Remembered once, not every frame
+        let memory = app.world.get::<MemorySystem>(npc).unwrap();
+        assert_eq!(memory.memories().iter().filter(|m| m.content() == "I had a wash").count(), 1);
+        // And it was glad of it
+        let emotions = app.world.get::<EmotionalState>(npc).unwrap();
+        assert!(emotions.get_intensity(Emotion::Joy) > 0.0);
+    }
+}
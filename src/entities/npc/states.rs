@@ -0,0 +1,88 @@
+use serde::{Serialize, Deserialize};
+
+use super::actions::ActionType;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Component))]
+pub struct NPCState {
+    current_state: State,
+    previous_state: Option<State>,
+    state_duration: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum State {
+    Idle,
+    Walking,
+    Working,
+    Socializing,
+    Sleeping,
+    Trading,
+    Thinking,
+}
+
+impl State {
+    /// What an NPC looks like it's doing while carrying out a utility
+    /// option, e.g. "sleep" or "chat".
+    pub fn for_action(action: &str) -> Self {
+        match action {
+            "eat" => State::Trading,
+            "sleep" => State::Sleeping,
+            "chat" => State::Socializing,
+            "work" => State::Working,
+            "wander" => State::Walking,
+            "wash" => State::Idle,
+            _ => State::Thinking,
+        }
+    }
+}
+
+impl From<&ActionType> for State {
+    fn from(action_type: &ActionType) -> Self {
+        match action_type {
+            ActionType::Move { .. } => State::Walking,
+            ActionType::Talk { .. } => State::Socializing,
+            ActionType::Trade { .. } => State::Trading,
+            ActionType::Work { .. } => State::Working,
+            ActionType::Rest { .. } => State::Idle,
+        }
+    }
+}
+
+impl NPCState {
+    pub fn new() -> Self {
+        Self {
+            current_state: State::Idle,
+            previous_state: None,
+            state_duration: 0.0,
+        }
+    }
+
+    pub fn change_state(&mut self, new_state: State) {
+        self.previous_state = Some(self.current_state.clone());
+        self.current_state = new_state;
+        self.state_duration = 0.0;
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.state_duration += delta_time;
+    }
+
+    pub fn current_state(&self) -> &State {
+        &self.current_state
+    }
+
+    pub fn previous_state(&self) -> Option<&State> {
+        self.previous_state.as_ref()
+    }
+
+    pub fn state_duration(&self) -> f32 {
+        self.state_duration
+    }
+}
+
+impl Default for NPCState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+use serde::{Serialize, Deserialize};
+use thiserror::Error;
+
+use ai_core::memory::episodic::MemoryLocation;
+
+pub const DEFAULT_MAP: &str = "world";
+pub const DEFAULT_TILE_SIZE: f32 = 16.0;
+/// The town's named places, laid over `DEFAULT_MAP`
+pub const PLACES_PATH: &str = "maps/places.ron";
+
+#[derive(Debug, Error)]
+pub enum PlacesError {
+    #[error("Failed to read places: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse places: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+}
+
+/// Named areas of the town, so NPC memories can say "at the Market" or
+/// "near the fountain" instead of raw coordinates. Tiles count from the
+/// map's bottom-left corner, matching world space.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "render", derive(bevy::prelude::Resource))]
+pub struct WorldPlaces {
+    pub map: String,
+    pub tile_size: f32,
+    places: Vec<Place>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Place {
+    pub name: String,
+    pub kind: PlaceKind,
+    pub min: (i32, i32),
+    pub max: (i32, i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlaceKind {
+    Zone,
+    Building,
+    Landmark,
+}
+
+impl Default for WorldPlaces {
+    fn default() -> Self {
+        Self {
+            map: DEFAULT_MAP.to_string(),
+            tile_size: DEFAULT_TILE_SIZE,
+            places: Vec::new(),
+        }
+    }
+}
+
+impl WorldPlaces {
+    pub fn load(path: &str) -> Result<Self, PlacesError> {
+        let source = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&source)?)
+    }
+
+    pub fn add_zone(&mut self, name: &str, min: (i32, i32), max: (i32, i32)) {
+        self.add(name, PlaceKind::Zone, min, max);
+    }
+
+    pub fn add_building(&mut self, name: &str, min: (i32, i32), max: (i32, i32)) {
+        self.add(name, PlaceKind::Building, min, max);
+    }
+
+    pub fn add_landmark(&mut self, name: &str, tile: (i32, i32)) {
+        self.add(name, PlaceKind::Landmark, tile, tile);
+    }
+
+    fn add(&mut self, name: &str, kind: PlaceKind, min: (i32, i32), max: (i32, i32)) {
+        self.places.push(Place {
+            name: name.to_string(),
+            kind,
+            min: (min.0.min(max.0), min.1.min(max.1)),
+            max: (min.0.max(max.0), min.1.max(max.1)),
+        });
+    }
+
+    pub fn world_to_tile(&self, x: f32, y: f32) -> (i32, i32) {
+        ((x / self.tile_size).floor() as i32, (y / self.tile_size).floor() as i32)
+    }
+
+    /// Full location for a world position, with the zone and building it
+    /// falls in, if any.
+    pub fn locate(&self, x: f32, y: f32) -> MemoryLocation {
+        let tile = self.world_to_tile(x, y);
+        let mut location = MemoryLocation::new(self.map.clone(), tile);
+        location.zone = self.containing(tile, PlaceKind::Zone).map(|p| p.name.clone());
+        location.building = self.containing(tile, PlaceKind::Building).map(|p| p.name.clone());
+        location
+    }
+
+    /// Centre tile of a named place, for "near the fountain" queries.
+    pub fn find(&self, name: &str) -> Option<(i32, i32)> {
+        let place = self.places.iter().find(|p| p.name.eq_ignore_ascii_case(name))?;
+        Some(((place.min.0 + place.max.0) / 2, (place.min.1 + place.max.1) / 2))
+    }
+
+    /// World-space centre of the closest place to `(x, y)` whose name
+    /// contains `name`.
+    pub fn nearest(&self, name: &str, x: f32, y: f32) -> Option<(f32, f32)> {
+        let name = name.to_lowercase();
+        self.places
+            .iter()
+            .filter(|p| p.name.to_lowercase().contains(&name))
+            .map(|p| {
+                let centre = |min: i32, max: i32| (min + max + 1) as f32 / 2.0 * self.tile_size;
+                (centre(p.min.0, p.max.0), centre(p.min.1, p.max.1))
+            })
+            .min_by(|a, b| {
+                let apart = |at: &(f32, f32)| (at.0 - x).powi(2) + (at.1 - y).powi(2);
+                apart(a).total_cmp(&apart(b))
+            })
+    }
+
+    /// Whether a world position is in, or within `reach` tiles of, any place
+    /// whose name contains `name` ("house" matches "Mara's House").
+    pub fn is_near(&self, name: &str, x: f32, y: f32, reach: i32) -> bool {
+        let tile = self.world_to_tile(x, y);
+        let name = name.to_lowercase();
+        self.places.iter().any(|p| {
+            p.name.to_lowercase().contains(&name)
+                && (p.min.0 - reach..=p.max.0 + reach).contains(&tile.0)
+                && (p.min.1 - reach..=p.max.1 + reach).contains(&tile.1)
+        })
+    }
+
+    fn containing(&self, tile: (i32, i32), kind: PlaceKind) -> Option<&Place> {
+        self.places.iter().find(|p| {
+            p.kind == kind
+                && (p.min.0..=p.max.0).contains(&tile.0)
+                && (p.min.1..=p.max.1).contains(&tile.1)
+        })
+    }
+}
\ No newline at end of file
@@ -1,13 +1,25 @@
-pub mod ai;
-pub mod engine;
+pub use ai_core as ai;
+pub use engine;
+#[cfg(feature = "network")]
+pub use networking as network;
+
 pub mod entities;
-pub mod network;
 pub mod error;
+pub mod ui;
+#[cfg(feature = "render")]
+pub mod animation;
+#[cfg(feature = "render")]
 pub mod config;
+#[cfg(all(feature = "render", feature = "network"))]
+pub mod spectator;
+#[cfg(all(feature = "render", feature = "persistence"))]
+pub mod persistence;
 
+#[cfg(feature = "render")]
 use bevy::prelude::*;
 
 /// Configuration for the HelloWorld simulation
+#[cfg(feature = "render")]
 #[derive(Resource, Debug, Clone)]
 pub struct HelloWorldConfig {
     pub simulation_speed: f32,
@@ -15,6 +27,7 @@ pub struct HelloWorldConfig {
     pub world_size: Vec2,
 }
 
+#[cfg(feature = "render")]
 impl Default for HelloWorldConfig {
     fn default() -> Self {
         Self {
@@ -26,7 +39,8 @@ impl Default for HelloWorldConfig {
 }
 
 /// Global state of the simulation
-#[derive(Resource, Debug)]
+#[derive(Debug)]
+#[cfg_attr(feature = "render", derive(Resource))]
 pub struct WorldState {
     pub time: f32,
     pub active_npcs: usize,
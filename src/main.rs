@@ -1,24 +1,46 @@
 use bevy::prelude::*;
 
-mod ai;
-mod engine;
-mod entities;
-mod network;
-mod error;
-mod config;
+use hello_world::{ai, animation, config, engine, entities, network, spectator, ui};
+use hello_world::entities::npc::bundle::NpcSpawner;
+use hello_world::entities::npc::NPCType;
+use hello_world::entities::places::WorldPlaces;
 
 /// Main entry point for the HelloWorld simulation
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins)
+    let mut app = App::new();
+    app
+        // Assets (sprites/, tilesets/, maps/) live at the repository root
+        .add_plugins(DefaultPlugins.set(AssetPlugin {
+            file_path: ".".to_string(),
+            ..default()
+        }))
+        .add_plugins((
+            animation::AnimationPlugin,
+            engine::ecs::EnginePlugin,
+            ai::ecs::AiPlugin,
+            entities::npc::bundle::NpcPlugin,
+            ui::overlay::BubblePlugin,
+            spectator::SpectatorPlugin::default(),
+        ))
         .add_state::<GameState>()
-        .add_systems(Startup, setup)
+        .add_systems(Startup, (setup, spawn_town))
         .add_systems(Update, (
-            update_ai_systems,
-            update_engine_state,
-            handle_network_events,
+            finish_loading.run_if(in_state(GameState::Loading)),
+            toggle_pause,
         ))
-        .run();
+        .add_systems(Update, handle_network_events);
+
+    // Loaded after DefaultPlugins so a bad config.toml gets logged
+    let config = config::Config::load().unwrap_or_else(|e| {
+        log::warn!("Failed to load config.toml, using defaults: {}", e);
+        config::Config::default()
+    });
+    app.insert_resource(config);
+
+    #[cfg(feature = "persistence")]
+    app.add_plugins(hello_world::persistence::PersistencePlugin::default());
+
+    app.run();
 }
 
 /// Game states for the simulation
@@ -30,37 +52,74 @@ enum GameState {
     Paused,
 }
 
+/// Viewer connections and voting, handled between frames
+#[derive(Resource, Default)]
+struct Network(network::NetworkManager);
+
 /// Initial setup of the simulation
 fn setup(mut commands: Commands) {
-    // Initialize AI Director
-    commands.insert_resource(ai::AiDirector::default());
-    
-    // Initialize Engine State
-    commands.insert_resource(engine::EngineState::default());
-    
+    commands.spawn(Camera2dBundle::default());
+
     // Initialize Network Manager
-    commands.insert_resource(network::NetworkManager::default());
+    let mut network = Network::default();
+    network.0.start();
+    commands.insert_resource(network);
 }
 
-/// Updates AI systems each frame
-fn update_ai_systems(
-    mut ai_director: ResMut<ai::AiDirector>,
-    time: Res<Time>,
-) {
-    ai_director.update(time.delta_seconds());
+/// The townsfolk at the start of a run: (character, role, place in
+/// places.ron they start at, aware)
+const TOWNSFOLK: [(&str, NPCType, &str, bool); 6] = [
+    ("Sarah", NPCType::Merchant, "Market Square", false),
+    ("James", NPCType::Villager, "James's House", false),
+    ("Emma", NPCType::Villager, "Emma's House", false),
+    ("Michael", NPCType::Guard, "Michael's House", false),
+    ("Olivia", NPCType::Wanderer, "Fountain", true),
+    ("Truman", NPCType::Villager, "Truman's House", false),
+];
+/// Middle of the 30×30-tile world map, for anyone whose place is missing
+const TOWN_CENTRE: Vec2 = Vec2::new(240.0, 240.0);
+
+/// Populates the town, each NPC at its home or workplace
+fn spawn_town(mut npcs: NpcSpawner, places: Res<WorldPlaces>) {
+    for (character, npc_type, place, is_aware) in TOWNSFOLK {
+        let position = places
+            .nearest(place, TOWN_CENTRE.x, TOWN_CENTRE.y)
+            .map_or(TOWN_CENTRE, |(x, y)| Vec2::new(x, y));
+        npcs.spawn(ai::Npc::named(character, is_aware), npc_type, character, position);
+    }
+}
+
+/// Everything is set up by the first frame
+fn finish_loading(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Running);
 }
 
-/// Updates engine state
-fn update_engine_state(
-    mut engine_state: ResMut<engine::EngineState>,
-    time: Res<Time>,
+/// Space pauses and resumes the simulation by stopping the game clock
+fn toggle_pause(
+    keys: Res<Input<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut time: ResMut<Time<Virtual>>,
 ) {
-    engine_state.update(time.delta_seconds());
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    match state.get() {
+        GameState::Running => {
+            time.pause();
+            next_state.set(GameState::Paused);
+        }
+        GameState::Paused => {
+            time.unpause();
+            next_state.set(GameState::Running);
+        }
+        GameState::Loading => {}
+    }
 }
 
 /// Handles incoming network events
 fn handle_network_events(
-    mut net_manager: ResMut<network::NetworkManager>,
+    mut network: ResMut<Network>,
 ) {
-    net_manager.process_events();
+    network.0.process_events();
 }
\ No newline at end of file
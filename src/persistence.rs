@@ -0,0 +1,293 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use ai_core::ecs::NpcId;
+use ai_core::memory::long_term::{MemoryChanges, StoredMemory};
+use ai_core::memory::store::{MemoryPersistence, MemoryStore, SqliteMemoryStore};
+use ai_core::memory::MemorySystem;
+use engine::simulation::time::SECONDS_PER_GAME_MINUTE;
+
+use crate::entities::npc::states::{NPCState, State};
+
+pub const DEFAULT_DATABASE_URL: &str = "sqlite://town.db";
+/// How often the writer checks whether a flush is due when nothing arrives
+const WRITER_POLL: Duration = Duration::from_millis(500);
+/// Sim seconds an NPC sleeps before its long-term memories are written out
+/// and freed until it wakes
+const EVICT_AFTER_SLEEPING: f32 = SECONDS_PER_GAME_MINUTE * 60.0;
+
+/// Keeps NPC long-term memories in SQLite behind the simulation, so a slow
+/// disk never holds up a frame. Memories are loaded when an NPC spawns,
+/// freed from RAM while it sleeps, and everything still queued is written
+/// out before the app exits.
+pub struct PersistencePlugin {
+    pub url: String,
+}
+
+impl Default for PersistencePlugin {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_DATABASE_URL.to_string(),
+        }
+    }
+}
+
+impl Plugin for PersistencePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MemoryWriter::spawn(self.url.clone()))
+            .add_systems(Update, (load_memories, evict_sleepers_memories, restore_memories))
+            .add_systems(PostUpdate, queue_memory_changes)
+            .add_systems(Last, close_writer_on_exit);
+    }
+}
+
+/// An NPC's stored memories and learned importance weights, as sent back
+/// by the writer.
+type Loaded = (Uuid, Vec<StoredMemory>, HashMap<String, f32>);
+
+/// What the simulation asks of the writer thread.
+#[derive(Debug)]
+enum WriterRequest {
+    /// Long-term changes to save with the next flush
+    Save(Uuid, MemoryChanges),
+    /// The NPC's last changes before its memories are freed; saved now
+    Evict(Uuid, MemoryChanges),
+    /// Send back what's stored for the NPC
+    Load(Uuid),
+}
+
+/// The writer thread, and the channels to and from it.
+#[derive(Resource)]
+pub struct MemoryWriter {
+    requests: Option<mpsc::UnboundedSender<WriterRequest>>,
+    loaded: mpsc::UnboundedReceiver<Loaded>,
+    thread: Option<JoinHandle<()>>,
+    /// NPCs whose memories have been asked for and not come back yet
+    loading: HashSet<Uuid>,
+}
+
+impl MemoryWriter {
+    /// Runs a `MemoryPersistence` on its own thread and runtime, batching
+    /// and flushing whatever the simulation sends it.
+    pub fn spawn(url: String) -> Self {
+        let (requests, receiver) = mpsc::unbounded_channel();
+        let (replies, loaded) = mpsc::unbounded_channel();
+        let thread = std::thread::spawn(move || run_writer(url, receiver, replies));
+        Self {
+            requests: Some(requests),
+            loaded,
+            thread: Some(thread),
+            loading: HashSet::new(),
+        }
+    }
+
+    fn send(&self, request: WriterRequest) {
+        // The writer is gone if the database couldn't be opened; nothing
+        // more to do than what it already logged
+        if let Some(requests) = &self.requests {
+            requests.send(request).ok();
+        }
+    }
+
+    /// Hangs up and waits for the writer to flush what's queued.
+    pub fn close(&mut self) {
+        self.requests = None;
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                log::error!("Memory writer panicked; some memories may not be saved");
+            }
+        }
+    }
+}
+
+impl Drop for MemoryWriter {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Asks for the stored memories of NPCs that just spawned, or woke up with
+/// theirs freed.
+fn load_memories(mut writer: ResMut<MemoryWriter>, npcs: Query<(&NpcId, &NPCState, Ref<MemorySystem>)>) {
+    for (id, state, memory) in &npcs {
+        let woke = !memory.is_long_term_loaded() && *state.current_state() != State::Sleeping;
+        if (memory.is_added() || woke) && writer.loading.insert(id.0) {
+            writer.send(WriterRequest::Load(id.0));
+        }
+    }
+}
+
+/// Puts loaded memories back into their NPCs.
+fn restore_memories(mut writer: ResMut<MemoryWriter>, mut npcs: Query<(&NpcId, &mut MemorySystem)>) {
+    while let Ok((npc, stored, weights)) = writer.loaded.try_recv() {
+        writer.loading.remove(&npc);
+        if let Some((_, mut memory)) = npcs.iter_mut().find(|(id, _)| id.0 == npc) {
+            memory.restore_long_term(stored);
+            memory.restore_learned_weights(weights);
+        }
+    }
+}
+
+/// NPCs a while into their sleep don't need their long-term memories in
+/// RAM: they're saved and freed, and loaded again when the NPC wakes.
+fn evict_sleepers_memories(writer: Res<MemoryWriter>, mut npcs: Query<(&NpcId, &NPCState, &mut MemorySystem)>) {
+    for (id, state, mut memory) in &mut npcs {
+        let sound_asleep = *state.current_state() == State::Sleeping && state.state_duration() >= EVICT_AFTER_SLEEPING;
+        if !sound_asleep || !memory.is_long_term_loaded() || writer.loading.contains(&id.0) {
+            continue;
+        }
+        writer.send(WriterRequest::Evict(id.0, memory.take_long_term_changes()));
+        memory.evict_long_term();
+    }
+}
+
+fn queue_memory_changes(writer: Res<MemoryWriter>, mut npcs: Query<(&NpcId, &mut MemorySystem)>) {
+    for (id, mut memory) in &mut npcs {
+        let changes = memory.take_long_term_changes();
+        if changes.is_empty() {
+            continue;
+        }
+        writer.send(WriterRequest::Save(id.0, changes));
+    }
+}
+
+/// Makes sure nothing queued is lost when the app closes.
+fn close_writer_on_exit(
+    mut exits: EventReader<AppExit>,
+    mut writer: ResMut<MemoryWriter>,
+    mut npcs: Query<(&NpcId, &mut MemorySystem)>,
+) {
+    if exits.read().next().is_none() {
+        return;
+    }
+    for (id, mut memory) in &mut npcs {
+        writer.send(WriterRequest::Save(id.0, memory.take_long_term_changes()));
+    }
+    writer.close();
+}
+
+fn run_writer(
+    url: String,
+    mut requests: mpsc::UnboundedReceiver<WriterRequest>,
+    replies: mpsc::UnboundedSender<Loaded>,
+) {
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            log::error!("Failed to start memory writer runtime: {}", e);
+            return;
+        }
+    };
+    runtime.block_on(async move {
+        let store = match SqliteMemoryStore::connect(&url).await {
+            Ok(store) => store,
+            Err(e) => {
+                log::error!("Memories won't be saved, {} failed to open: {}", url, e);
+                return;
+            }
+        };
+        let mut persistence = MemoryPersistence::new(Arc::new(store));
+        let mut last_tick = Instant::now();
+
+        loop {
+            let mut flush_now = false;
+            let open = tokio::select! {
+                request = requests.recv() => match request {
+                    Some(WriterRequest::Save(npc, changes)) => {
+                        persistence.queue(npc, changes);
+                        true
+                    }
+                    Some(WriterRequest::Evict(npc, changes)) => {
+                        persistence.queue(npc, changes);
+                        flush_now = true;
+                        true
+                    }
+                    Some(WriterRequest::Load(npc)) => {
+                        // What's queued has to land first, or it loads stale
+                        if persistence.has_pending() {
+                            if let Err(e) = persistence.flush().await {
+                                log::warn!("Failed to save memories, will retry: {}", e);
+                            }
+                        }
+                        let stored = persistence.store().load(npc).await.unwrap_or_else(|e| {
+                            log::warn!("Failed to load memories of NPC {}: {}", npc, e);
+                            Vec::new()
+                        });
+                        let weights = persistence.store().load_weights(npc).await.unwrap_or_else(|e| {
+                            log::warn!("Failed to load importance weights of NPC {}: {}", npc, e);
+                            HashMap::new()
+                        });
+                        replies.send((npc, stored, weights)).ok();
+                        true
+                    }
+                    None => false,
+                },
+                _ = tokio::time::sleep(WRITER_POLL) => true,
+            };
+
+            let now = Instant::now();
+            let due = persistence.tick(now.duration_since(last_tick).as_secs_f32());
+            last_tick = now;
+            // Whatever's left goes out once the simulation hangs up
+            if due || ((flush_now || !open) && persistence.has_pending()) {
+                if let Err(e) = persistence.flush().await {
+                    log::warn!("Failed to save memories, will retry: {}", e);
+                }
+            }
+            if !open {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_core::consciousness::dreams::{Dream, DreamKind};
+
+    /// Long-term changes of an NPC who dreamt `content`.
+    fn remembering(content: &str) -> MemoryChanges {
+        let mut memory = MemorySystem::default();
+        memory.add_dream(&Dream {
+            content: content.to_string(),
+            kind: DreamKind::Replay,
+            sources: Vec::new(),
+            related_entities: Vec::new(),
+            emotional_value: 0.5,
+            unsettling: 0.2,
+        });
+        memory.take_long_term_changes()
+    }
+
+    fn load(writer: &mut MemoryWriter, npc: Uuid) -> Vec<StoredMemory> {
+        writer.send(WriterRequest::Load(npc));
+        writer.loaded.blocking_recv().map(|(_, stored, _)| stored).unwrap_or_default()
+    }
+
+    #[test]
+    fn memories_queued_before_closing_are_there_next_run() {
+        let dir = std::env::temp_dir().join(format!("town-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let url = format!("sqlite://{}", dir.join("town.db").display());
+        let npc = Uuid::new_v5(&Uuid::NAMESPACE_OID, b"Sarah");
+        let changes = remembering("The bakery burned down at dawn");
+        assert!(!changes.saved.is_empty());
+
+        let mut writer = MemoryWriter::spawn(url.clone());
+        writer.send(WriterRequest::Save(npc, changes));
+        writer.close();
+
+        let mut writer = MemoryWriter::spawn(url);
+        let stored = load(&mut writer, npc);
+        assert!(stored.iter().any(|stored| stored.memory.content() == "The bakery burned down at dawn"));
+        writer.close();
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use tokio::sync::broadcast;
+
+use ai_core::cognition::CognitionSystem;
+use ai_core::consciousness::ConsciousnessState;
+use ai_core::ecs::{Aware, NpcId};
+use ai_core::goals::GoalSystem;
+use ai_core::memory::MemorySystem;
+use ai_core::personality::emotions::EmotionalState;
+use ai_core::social::{SocialBehavior, SocialNetwork};
+use engine::physics::movement::MovementComponent;
+use networking::spectator::{
+    DecisionSnapshot, GoalSnapshot, LearnedValueSnapshot, LogEvent, NpcSnapshot, RelationshipSnapshot, SpectatorMessage, SpectatorServer,
+    WorldSnapshot, DEFAULT_SPECTATOR_ADDR,
+};
+
+use crate::animation::CharacterAnimation;
+use crate::entities::npc::states::NPCState;
+use crate::ui::overlay::SpeechEvent;
+
+const SNAPSHOT_INTERVAL: f32 = 0.5;
+const RECENT_MEMORIES: usize = 5;
+const RECENT_DECISIONS: usize = 3;
+const LEARNED_ROWS: usize = 10;
+
+/// Streams NPC state and notable events to terminal spectators over WebSocket.
+pub struct SpectatorPlugin {
+    pub addr: String,
+}
+
+impl Default for SpectatorPlugin {
+    fn default() -> Self {
+        Self {
+            addr: DEFAULT_SPECTATOR_ADDR.to_string(),
+        }
+    }
+}
+
+impl Plugin for SpectatorPlugin {
+    fn build(&self, app: &mut App) {
+        let sender = SpectatorServer::new(self.addr.clone()).spawn();
+
+        app.insert_resource(SpectatorFeed {
+            sender,
+            timer: Timer::from_seconds(SNAPSHOT_INTERVAL, TimerMode::Repeating),
+        })
+        .add_systems(Update, (publish_snapshots, publish_spawns, publish_state_changes, publish_speech));
+    }
+}
+
+#[derive(Resource)]
+pub struct SpectatorFeed {
+    sender: broadcast::Sender<SpectatorMessage>,
+    timer: Timer,
+}
+
+impl SpectatorFeed {
+    /// Sends a line to the spectators' event log. Dropped silently when
+    /// nobody is watching.
+    pub fn log(&self, time: f32, source: Option<&NpcId>, message: impl Into<String>) {
+        let event = LogEvent {
+            time,
+            source: source.map(|id| id.0),
+            message: message.into(),
+        };
+        self.sender.send(SpectatorMessage::Event(event)).ok();
+    }
+
+    fn has_spectators(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn publish_snapshots(
+    time: Res<Time>,
+    mut feed: ResMut<SpectatorFeed>,
+    network: Option<Res<SocialNetwork>>,
+    npcs: Query<(
+        &NpcId,
+        &MovementComponent,
+        Option<&CharacterAnimation>,
+        Option<&NPCState>,
+        Option<&EmotionalState>,
+        &ConsciousnessState,
+        &GoalSystem,
+        &MemorySystem,
+        &SocialBehavior,
+        Option<&CognitionSystem>,
+        Option<&Aware>,
+    )>,
+) {
+    if !feed.timer.tick(time.delta()).just_finished() || !feed.has_spectators() {
+        return;
+    }
+
+    let snapshots = npcs
+        .iter()
+        .map(|(id, movement, animation, state, emotions, consciousness, goals, memory, social, cognition, aware)| {
+            let position = movement.position();
+            let (emotion, emotion_intensity) = match emotions {
+                Some(emotions) => {
+                    let dominant = emotions.get_dominant_emotion();
+                    (format!("{:?}", dominant), emotions.get_intensity(dominant))
+                }
+                None => ("Neutral".to_string(), 0.0),
+            };
+
+            let relationships = social
+                .get_connections()
+                .iter()
+                .map(|other| {
+                    let relationship = network
+                        .as_ref()
+                        .and_then(|n| n.get_relationship(id.0, *other));
+                    RelationshipSnapshot {
+                        other: *other,
+                        score: relationship.map_or(0.0, |r| r.get_relationship_score()),
+                        trust: relationship.map_or(0.0, |r| r.get_trust_level()),
+                    }
+                })
+                .collect();
+
+            NpcSnapshot {
+                id: id.0,
+                name: animation.map_or_else(|| short_id(id), |a| a.character.clone()),
+                position: (position.x, position.y),
+                state: state.map_or_else(|| "Unknown".to_string(), |s| format!("{:?}", s.current_state())),
+                emotion,
+                emotion_intensity,
+                awareness: consciousness.get_awareness_level(),
+                is_aware: aware.is_some() || consciousness.is_fully_aware(),
+                goals: goals
+                    .get_active_goals()
+                    .into_iter()
+                    .map(|g| GoalSnapshot {
+                        description: g.description().to_string(),
+                        priority: g.priority(),
+                        progress: g.progress(),
+                    })
+                    .collect(),
+                recent_memories: memory
+                    .get_recent_memories(RECENT_MEMORIES)
+                    .iter()
+                    .map(|m| m.content().to_string())
+                    .collect(),
+                relationships,
+                decisions: cognition.map_or_else(Vec::new, |cognition| {
+                    let traces: Vec<_> = cognition.decision_traces().collect();
+                    traces[traces.len().saturating_sub(RECENT_DECISIONS)..]
+                        .iter()
+                        .map(|trace| DecisionSnapshot {
+                            time: trace.timestamp,
+                            chosen: trace.chosen.clone(),
+                            confidence: trace.confidence,
+                            trace: serde_json::to_value(trace).unwrap_or_default(),
+                            explanation: trace.explain(),
+                        })
+                        .collect()
+                }),
+                learned: cognition.map_or_else(Vec::new, |cognition| {
+                    cognition
+                        .learned_values()
+                        .into_iter()
+                        .take(LEARNED_ROWS)
+                        .map(|row| LearnedValueSnapshot {
+                            situation: row.situation,
+                            option: row.option,
+                            bandit_value: row.bandit_value,
+                            q_value: row.q_value,
+                            tries: row.tries,
+                        })
+                        .collect()
+                }),
+            }
+        })
+        .collect();
+
+    let snapshot = WorldSnapshot {
+        time: time.elapsed_seconds(),
+        npcs: snapshots,
+    };
+    feed.sender.send(SpectatorMessage::Snapshot(snapshot)).ok();
+}
+
+#[allow(clippy::type_complexity)]
+fn publish_spawns(
+    time: Res<Time>,
+    feed: Res<SpectatorFeed>,
+    added: Query<(&NpcId, Option<&CharacterAnimation>, Option<&Aware>), Added<NpcId>>,
+) {
+    for (id, animation, aware) in &added {
+        let name = animation.map_or_else(|| short_id(id), |a| a.character.clone());
+        let suffix = if aware.is_some() { " (aware)" } else { "" };
+        feed.log(time.elapsed_seconds(), Some(id), format!("{} arrived in town{}", name, suffix));
+    }
+}
+
+fn publish_state_changes(
+    time: Res<Time>,
+    feed: Res<SpectatorFeed>,
+    mut last_states: Local<HashMap<Entity, String>>,
+    states: Query<(Entity, &NpcId, &NPCState, Option<&CharacterAnimation>), Changed<NPCState>>,
+) {
+    for (entity, id, state, animation) in &states {
+        let current = format!("{:?}", state.current_state());
+        let previous = last_states.insert(entity, current.clone());
+        if previous.as_deref() == Some(current.as_str()) || previous.is_none() {
+            continue;
+        }
+
+        let name = animation.map_or_else(|| short_id(id), |a| a.character.clone());
+        feed.log(time.elapsed_seconds(), Some(id), format!("{} is now {}", name, current));
+    }
+}
+
+fn publish_speech(
+    time: Res<Time>,
+    feed: Res<SpectatorFeed>,
+    mut speech: EventReader<SpeechEvent>,
+    speakers: Query<(&NpcId, Option<&CharacterAnimation>)>,
+) {
+    for event in speech.read() {
+        let Ok((id, animation)) = speakers.get(event.speaker) else {
+            continue;
+        };
+        let name = animation.map_or_else(|| short_id(id), |a| a.character.clone());
+        feed.log(time.elapsed_seconds(), Some(id), format!("{}: \"{}\"", name, event.text));
+    }
+}
+
+fn short_id(id: &NpcId) -> String {
+    id.0.to_string()[..8].to_string()
+}
\ No newline at end of file
@@ -0,0 +1,344 @@
+use std::collections::VecDeque;
+
+use ai_core::personality::emotions::Emotion;
+
+/// Characters per bubble line before wrapping.
+pub const LINE_WIDTH: usize = 24;
+/// Lines per bubble; longer text is cut with an ellipsis.
+pub const MAX_LINES: usize = 4;
+/// Emotion intensity needed before an emote is shown at all.
+pub const EMOTE_THRESHOLD: f32 = 0.4;
+
+const MIN_DURATION: f32 = 2.0;
+const MAX_DURATION: f32 = 8.0;
+const SECONDS_PER_CHAR: f32 = 0.06;
+const FADE_TIME: f32 = 0.5;
+const EMOTE_DURATION: f32 = 3.0;
+const MAX_QUEUED: usize = 3;
+
+/// Everything shown above one NPC's head. Pure state so it can be driven and
+/// checked without a renderer; the Bevy overlay only reads from it.
+#[derive(Debug, Clone, Default)]
+pub struct BubbleState {
+    current: Option<SpeechBubble>,
+    queued: VecDeque<String>,
+    emote: Option<Emote>,
+    aware: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpeechBubble {
+    lines: Vec<String>,
+    remaining: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Emote {
+    icon: EmoteIcon,
+    intensity: f32,
+    remaining: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmoteIcon {
+    Happy,
+    Sad,
+    Angry,
+    Scared,
+    Surprised,
+    Fond,
+    Disgusted,
+    Expectant,
+    Confused,
+    Curious,
+    Proud,
+    Ashamed,
+}
+
+impl BubbleState {
+    /// Queues a line of speech behind whatever is already showing. If the NPC
+    /// talks faster than anyone could read, the oldest queued lines are dropped.
+    pub fn say(&mut self, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+
+        if self.current.is_none() {
+            self.current = Some(SpeechBubble::new(text));
+        } else {
+            self.queued.push_back(text.to_string());
+            while self.queued.len() > MAX_QUEUED {
+                self.queued.pop_front();
+            }
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        if let Some(bubble) = &mut self.current {
+            bubble.remaining -= delta_time;
+            if bubble.remaining <= 0.0 {
+                self.current = self.queued.pop_front().map(|text| SpeechBubble::new(&text));
+            }
+        }
+
+        if let Some(emote) = &mut self.emote {
+            emote.remaining -= delta_time;
+            if emote.remaining <= 0.0 {
+                self.emote = None;
+            }
+        }
+    }
+
+    /// Shows the dominant emotion if it's strong enough. Re-reporting the same
+    /// emotion keeps it up; weak emotions let the current emote run out.
+    pub fn set_emotion(&mut self, emotion: Emotion, intensity: f32) {
+        if intensity < EMOTE_THRESHOLD {
+            return;
+        }
+
+        let icon = EmoteIcon::from(emotion);
+        self.emote = Some(Emote {
+            icon,
+            intensity: intensity.clamp(0.0, 1.0),
+            remaining: EMOTE_DURATION,
+        });
+    }
+
+    pub fn set_aware(&mut self, aware: bool) {
+        self.aware = aware;
+    }
+
+    pub fn is_aware(&self) -> bool {
+        self.aware
+    }
+
+    pub fn speech(&self) -> Option<&SpeechBubble> {
+        self.current.as_ref()
+    }
+
+    pub fn emote(&self) -> Option<&Emote> {
+        self.emote.as_ref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.current.is_none() && self.emote.is_none() && !self.aware
+    }
+}
+
+impl SpeechBubble {
+    pub fn new(text: &str) -> Self {
+        Self {
+            lines: wrap_text(text, LINE_WIDTH, MAX_LINES),
+            remaining: display_duration(text),
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// 1.0 while readable, fading to 0.0 over the last half second.
+    pub fn opacity(&self) -> f32 {
+        (self.remaining / FADE_TIME).clamp(0.0, 1.0)
+    }
+}
+
+impl Emote {
+    pub fn icon(&self) -> EmoteIcon {
+        self.icon
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    pub fn opacity(&self) -> f32 {
+        (self.remaining / FADE_TIME).clamp(0.0, 1.0)
+    }
+}
+
+impl EmoteIcon {
+    /// Short text glyph drawn above the head; keeps to ASCII so the default
+    /// font can render it.
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            EmoteIcon::Happy => ":)",
+            EmoteIcon::Sad => ":(",
+            EmoteIcon::Angry => ">:(",
+            EmoteIcon::Scared => "!!",
+            EmoteIcon::Surprised => "!",
+            EmoteIcon::Fond => "<3",
+            EmoteIcon::Disgusted => "x_x",
+            EmoteIcon::Expectant => "...",
+            EmoteIcon::Confused => "?",
+            EmoteIcon::Curious => "?!",
+            EmoteIcon::Proud => "B)",
+            EmoteIcon::Ashamed => "._.",
+        }
+    }
+}
+
+impl From<Emotion> for EmoteIcon {
+    fn from(emotion: Emotion) -> Self {
+        match emotion {
+            Emotion::Joy => EmoteIcon::Happy,
+            Emotion::Sadness => EmoteIcon::Sad,
+            Emotion::Anger => EmoteIcon::Angry,
+            Emotion::Fear => EmoteIcon::Scared,
+            Emotion::Surprise => EmoteIcon::Surprised,
+            Emotion::Trust => EmoteIcon::Fond,
+            Emotion::Disgust => EmoteIcon::Disgusted,
+            Emotion::Anticipation => EmoteIcon::Expectant,
+            Emotion::Confusion => EmoteIcon::Confused,
+            Emotion::Curiosity => EmoteIcon::Curious,
+            Emotion::Hope => EmoteIcon::Expectant,
+            Emotion::Pride => EmoteIcon::Proud,
+            Emotion::Shame => EmoteIcon::Ashamed,
+            Emotion::Gratitude | Emotion::Admiration => EmoteIcon::Fond,
+            Emotion::Reproach => EmoteIcon::Angry,
+        }
+    }
+}
+
+/// How long a line stays up: long enough to read, never so long it clutters.
+pub fn display_duration(text: &str) -> f32 {
+    (MIN_DURATION + text.chars().count() as f32 * SECONDS_PER_CHAR).clamp(MIN_DURATION, MAX_DURATION)
+}
+
+/// Greedy word wrap to `width` characters. Words longer than a line are split,
+/// and text past `max_lines` is cut with an ellipsis on the last line.
+pub fn wrap_text(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        // Hard-split words that can't fit on any line
+        while word.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+
+        let word: String = word.into_iter().collect();
+        let needed = if current.is_empty() {
+            word.chars().count()
+        } else {
+            current.chars().count() + 1 + word.chars().count()
+        };
+
+        if needed > width && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if max_lines > 0 && lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            let keep = width.saturating_sub(3);
+            let truncated: String = last.chars().take(keep).collect();
+            *last = format!("{}...", truncated.trim_end());
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_at_word_boundaries() {
+        let lines = wrap_text("the baker burned the bread again", 12, 0);
+        assert_eq!(lines, vec!["the baker", "burned the", "bread again"]);
+    }
+
+    #[test]
+    fn splits_words_longer_than_a_line() {
+        let lines = wrap_text("abcdefghij", 4, 0);
+        assert_eq!(lines, vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn cuts_overlong_text_with_an_ellipsis() {
+        let lines = wrap_text("one two three four five six", 9, 2);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].ends_with("..."));
+        assert!(lines[1].chars().count() <= 9);
+    }
+
+    #[test]
+    fn speech_expires_and_the_queue_moves_on() {
+        let mut state = BubbleState::default();
+        state.say("Morning!");
+        state.say("Lovely day.");
+
+        state.update(display_duration("Morning!") + 0.01);
+        assert_eq!(state.speech().map(SpeechBubble::text).as_deref(), Some("Lovely day."));
+
+        state.update(display_duration("Lovely day.") + 0.01);
+        assert!(state.speech().is_none());
+    }
+
+    #[test]
+    fn chatter_beyond_the_queue_drops_the_oldest_lines() {
+        let mut state = BubbleState::default();
+        for line in ["a", "b", "c", "d", "e"] {
+            state.say(line);
+        }
+
+        // "a" shows first; "b" fell off the queue of three
+        state.update(MAX_DURATION);
+        assert_eq!(state.speech().map(SpeechBubble::text).as_deref(), Some("c"));
+    }
+
+    #[test]
+    fn display_time_grows_with_length_within_bounds() {
+        assert_eq!(display_duration("Hi"), MIN_DURATION + 2.0 * SECONDS_PER_CHAR);
+        assert_eq!(display_duration(&"a".repeat(500)), MAX_DURATION);
+    }
+
+    #[test]
+    fn emotes_need_intensity_and_run_out() {
+        let mut state = BubbleState::default();
+        state.set_emotion(Emotion::Joy, EMOTE_THRESHOLD - 0.1);
+        assert!(state.emote().is_none());
+
+        state.set_emotion(Emotion::Joy, 0.9);
+        assert_eq!(state.emote().map(Emote::icon), Some(EmoteIcon::Happy));
+
+        state.update(EMOTE_DURATION - FADE_TIME / 2.0);
+        let opacity = state.emote().map(Emote::opacity).unwrap();
+        assert!(opacity > 0.0 && opacity < 1.0);
+
+        state.update(FADE_TIME);
+        assert!(state.emote().is_none());
+    }
+
+    #[test]
+    fn repeating_an_emotion_keeps_the_emote_up() {
+        let mut state = BubbleState::default();
+        state.set_emotion(Emotion::Fear, 0.8);
+        state.update(EMOTE_DURATION - 0.5);
+        state.set_emotion(Emotion::Fear, 0.8);
+        state.update(1.0);
+        assert_eq!(state.emote().map(Emote::icon), Some(EmoteIcon::Scared));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod bubbles;
+#[cfg(feature = "render")]
+pub mod overlay;
\ No newline at end of file
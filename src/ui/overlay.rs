@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use ai_core::consciousness::ConsciousnessState;
+use ai_core::ecs::{Aware, NpcId};
+use ai_core::personality::emotions::EmotionalState;
+
+use super::bubbles::BubbleState;
+
+const SPEECH_OFFSET: f32 = 28.0;
+const EMOTE_OFFSET: f32 = 22.0;
+const AWARE_OFFSET: f32 = 20.0;
+const FONT_SIZE: f32 = 10.0;
+const AWARE_GLYPH: &str = "*";
+
+/// Draws speech bubbles, emotes and awareness markers above NPCs.
+pub struct BubblePlugin;
+
+impl Plugin for BubblePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpeechEvent>()
+            .add_systems(Update, (
+                attach_bubbles,
+                receive_speech,
+                update_emotes,
+                tick_bubbles,
+                draw_bubbles,
+            ).chain());
+    }
+}
+
+/// Something an NPC says out loud, shown above its head.
+#[derive(Event, Debug, Clone)]
+pub struct SpeechEvent {
+    pub speaker: Entity,
+    pub text: String,
+}
+
+#[derive(Component, Debug, Clone, Default)]
+pub struct Bubbles(pub BubbleState);
+
+/// Which text child of an NPC a bubble part is drawn with.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum BubblePart {
+    Speech,
+    Emote,
+    Aware,
+}
+
+fn attach_bubbles(
+    mut commands: Commands,
+    npcs: Query<Entity, (With<NpcId>, Without<Bubbles>)>,
+) {
+    for entity in &npcs {
+        commands.entity(entity)
+            .insert(Bubbles::default())
+            .with_children(|parent| {
+                spawn_part(parent, BubblePart::Speech, Vec3::new(0.0, SPEECH_OFFSET, 10.0), Anchor::BottomCenter);
+                spawn_part(parent, BubblePart::Emote, Vec3::new(-12.0, EMOTE_OFFSET, 10.0), Anchor::BottomRight);
+                spawn_part(parent, BubblePart::Aware, Vec3::new(12.0, AWARE_OFFSET, 10.0), Anchor::BottomLeft);
+            });
+    }
+}
+
+fn spawn_part(parent: &mut ChildBuilder, part: BubblePart, offset: Vec3, anchor: Anchor) {
+    let color = match part {
+        BubblePart::Speech => Color::WHITE,
+        BubblePart::Emote => Color::YELLOW,
+        BubblePart::Aware => Color::CYAN,
+    };
+
+    parent.spawn((
+        part,
+        Text2dBundle {
+            text: Text::from_section("", TextStyle {
+                font_size: FONT_SIZE,
+                color,
+                ..default()
+            })
+            .with_alignment(TextAlignment::Center),
+            text_anchor: anchor,
+            transform: Transform::from_translation(offset),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+    ));
+}
+
+fn receive_speech(mut events: EventReader<SpeechEvent>, mut bubbles: Query<&mut Bubbles>) {
+    for event in events.read() {
+        if let Ok(mut bubbles) = bubbles.get_mut(event.speaker) {
+            bubbles.0.say(&event.text);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_emotes(
+    mut npcs: Query<(&mut Bubbles, Option<&EmotionalState>, Option<&ConsciousnessState>, Option<&Aware>)>,
+) {
+    for (mut bubbles, emotions, consciousness, aware) in &mut npcs {
+        if let Some(emotions) = emotions {
+            let emotion = emotions.get_dominant_emotion();
+            bubbles.0.set_emotion(emotion, emotions.get_intensity(emotion));
+        }
+
+        // Spectators see the marker once an NPC has fully woken up, or if it
+        // spawned aware
+        let is_aware = aware.is_some() || consciousness.is_some_and(|c| c.is_fully_aware());
+        if bubbles.0.is_aware() != is_aware {
+            bubbles.0.set_aware(is_aware);
+        }
+    }
+}
+
+fn tick_bubbles(time: Res<Time>, mut bubbles: Query<&mut Bubbles>) {
+    let delta_time = time.delta_seconds();
+    for mut bubbles in &mut bubbles {
+        bubbles.0.update(delta_time);
+    }
+}
+
+fn draw_bubbles(
+    bubbles: Query<&Bubbles, Changed<Bubbles>>,
+    mut parts: Query<(&Parent, &BubblePart, &mut Text, &mut Visibility)>,
+) {
+    for (parent, part, mut text, mut visibility) in &mut parts {
+        let Ok(Bubbles(state)) = bubbles.get(parent.get()) else {
+            continue;
+        };
+
+        let shown = match part {
+            BubblePart::Speech => state.speech().map(|s| (s.text(), s.opacity())),
+            BubblePart::Emote => state.emote().map(|e| (e.icon().glyph().to_string(), e.opacity())),
+            BubblePart::Aware => state.is_aware().then(|| (AWARE_GLYPH.to_string(), 1.0)),
+        };
+
+        match shown {
+            Some((value, opacity)) => {
+                let section = &mut text.sections[0];
+                if section.value != value {
+                    section.value = value;
+                }
+                section.style.color.set_a(opacity);
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
\ No newline at end of file